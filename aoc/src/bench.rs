@@ -0,0 +1,125 @@
+use std::time::Instant;
+
+use aoc_common::{
+    aoc_error::AocError, day::Day, day_config::DayConfig, normalization::Normalization,
+};
+use serde::Serialize;
+
+use super::{
+    cli::BenchArgs,
+    days,
+    phase::Phase,
+    report_format::ReportFormat,
+    timing_stats::{as_nanos, TimingStats},
+};
+
+#[derive(Serialize)]
+struct BenchRecord {
+    day: usize,
+    name: &'static str,
+    phase: String,
+    iterations: usize,
+    min_ns: u64,
+    median_ns: u64,
+    max_ns: u64,
+}
+
+pub fn bench(args: &BenchArgs) -> Result<bool, AocError> {
+    let days = args
+        .day
+        .select_with_input(days::all_days(), args.input.as_deref())?;
+
+    let mut records = Vec::new();
+    let mut success = true;
+
+    for (day, input) in days {
+        // Input is read only once so that stdin can be used as well
+        match args.day.load_config(&day, &args.config).and_then(|config| {
+            input
+                .read_normalized(Normalization::from_strict(args.strict))
+                .and_then(|content| bench_day(&day, &content, &config, args.iterations))
+        }) {
+            Ok(day_records) => records.extend(day_records),
+            Err(err) => {
+                eprintln!("Day {}: {}", day.get_number(), err);
+                success = false;
+            }
+        }
+    }
+
+    match args.format {
+        ReportFormat::Json => print_json(&records)?,
+        ReportFormat::Csv => print_csv(&records),
+    }
+
+    Ok(success)
+}
+
+fn bench_day(
+    day: &Day,
+    content: &str,
+    config: &DayConfig,
+    iterations: usize,
+) -> Result<Vec<BenchRecord>, AocError> {
+    let lines = content.lines().collect::<Vec<_>>();
+    let mut samples = Phase::ALL.map(|phase| (phase, Vec::with_capacity(iterations)));
+
+    for _ in 0..iterations {
+        let mut solver = day.create_solver();
+        solver.configure(config)?;
+
+        for (phase, durations) in samples.iter_mut() {
+            let start = Instant::now();
+
+            match phase {
+                Phase::Parse => solver.parse(&lines)?,
+                Phase::Solve(part) => {
+                    solver.solve(*part)?;
+                }
+            }
+
+            durations.push(start.elapsed());
+        }
+    }
+
+    Ok(samples
+        .iter()
+        .filter_map(|(phase, durations)| {
+            TimingStats::from_samples(durations).map(|stats| BenchRecord {
+                day: day.get_number(),
+                name: day.get_name(),
+                phase: phase.to_string(),
+                iterations,
+                min_ns: as_nanos(stats.min),
+                median_ns: as_nanos(stats.median),
+                max_ns: as_nanos(stats.max),
+            })
+        })
+        .collect())
+}
+
+fn print_json(records: &[BenchRecord]) -> Result<(), AocError> {
+    let json = serde_json::to_string_pretty(records).map_err(|err| {
+        AocError::SolverError(format!("Failed to serialize benchmark report [{}]", err))
+    })?;
+
+    println!("{}", json);
+    Ok(())
+}
+
+fn print_csv(records: &[BenchRecord]) {
+    println!("day,name,phase,iterations,min_ns,median_ns,max_ns");
+
+    for record in records {
+        println!(
+            "{},{},{},{},{},{},{}",
+            record.day,
+            record.name,
+            record.phase,
+            record.iterations,
+            record.min_ns,
+            record.median_ns,
+            record.max_ns
+        );
+    }
+}
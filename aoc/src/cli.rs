@@ -0,0 +1,215 @@
+use std::path::PathBuf;
+
+use aoc_common::{config_args::ConfigArgs, part::Part, render::render_format::RenderFormat};
+use clap::{builder::RangedU64ValueParser, Args, Parser, Subcommand};
+
+use super::{
+    day_selection::DaySelection, output_format::OutputFormat, report_format::ReportFormat,
+};
+
+#[derive(Parser)]
+#[command(
+    name = "aoc",
+    about = "Advent of Code 2024 solvers",
+    after_help = "Set AOC_LOG, e.g. 'debug' or 'day_18_ram_run=debug', to log solver steps"
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Solve one or all days and print answers as a table or JSON
+    Run(RunArgs),
+
+    /// Solve examples of one or all days and compare them with expected answers
+    Test(TestArgs),
+
+    /// Measure parse, part 1 and part 2 durations over several iterations
+    Bench(BenchArgs),
+
+    /// Solve many inputs and compare them with answers from a TOML manifest
+    Verify(VerifyArgs),
+
+    /// Generate a synthetic input of a day from a seed
+    Generate(GenerateArgs),
+
+    /// Solve a day and print answers with checked witnesses, e.g. paths or operators, as JSON
+    Explain(ExplainArgs),
+
+    /// Draw the grid of a day with overlays, e.g. paths or regions, in a terminal or as images
+    Render(RenderArgs),
+
+    /// Create a new day crate from templates and register it in the workspace and the runner
+    Scaffold(ScaffoldArgs),
+}
+
+#[derive(Args)]
+pub struct RunArgs {
+    /// Day number or 'all'
+    pub day: DaySelection,
+
+    /// Solve only the given part (1 or 2)
+    #[arg(long)]
+    pub part: Option<Part>,
+
+    /// Print a table of answers or a JSON object per line with status and timings of every day
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+
+    /// Puzzle input file for a single day, '-' reads from stdin [default: $AOC_INPUT]
+    #[arg(long, value_name = "PATH")]
+    pub input: Option<String>,
+
+    /// Report irregular input, e.g. CRLF line endings or a BOM, instead of normalizing it
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Solve days concurrently on a thread pool, a panicking day does not stop the others
+    #[arg(long)]
+    pub parallel: bool,
+
+    /// Count of threads of the parallel run [default: count of CPUs]
+    #[arg(long, requires = "parallel", value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    pub jobs: Option<usize>,
+
+    /// Report a day of the parallel run as timed out when it runs longer than given seconds
+    #[arg(long, value_name = "SECONDS", requires = "parallel", value_parser = RangedU64ValueParser::<u64>::new().range(1..))]
+    pub timeout: Option<u64>,
+
+    #[command(flatten)]
+    pub config: ConfigArgs,
+}
+
+#[derive(Args)]
+pub struct TestArgs {
+    /// Day number or 'all'
+    pub day: DaySelection,
+}
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Day number or 'all'
+    pub day: DaySelection,
+
+    /// Number of iterations per day
+    #[arg(long, default_value_t = 10, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    pub iterations: usize,
+
+    /// Format of the report
+    #[arg(long, value_enum, default_value_t = ReportFormat::Json)]
+    pub format: ReportFormat,
+
+    /// Puzzle input file for a single day, '-' reads from stdin [default: $AOC_INPUT]
+    #[arg(long, value_name = "PATH")]
+    pub input: Option<String>,
+
+    /// Report irregular input, e.g. CRLF line endings or a BOM, instead of normalizing it
+    #[arg(long)]
+    pub strict: bool,
+
+    #[command(flatten)]
+    pub config: ConfigArgs,
+}
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    /// TOML manifest with expected answers in '[day_NN.<input name>]' tables
+    #[arg(value_name = "MANIFEST")]
+    pub manifest: PathBuf,
+
+    /// Directory with '<day_NN>/<input name>.txt' inputs [default: manifest directory]
+    #[arg(long, value_name = "DIR")]
+    pub inputs: Option<PathBuf>,
+
+    /// Report irregular input, e.g. CRLF line endings or a BOM, instead of normalizing it
+    #[arg(long)]
+    pub strict: bool,
+}
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Day number
+    pub day: usize,
+
+    /// Size of the input, e.g. count of lines or side of a grid [default: official input size]
+    #[arg(long, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    pub size: Option<usize>,
+
+    /// Seed of the random generator, the same seed and size produce the same input
+    #[arg(long, default_value_t = 0)]
+    pub seed: u64,
+
+    /// Write the input to a file instead of stdout
+    #[arg(long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct ExplainArgs {
+    /// Day number
+    pub day: usize,
+
+    /// Explain only the given part (1 or 2)
+    #[arg(long)]
+    pub part: Option<Part>,
+
+    /// Puzzle input file, '-' reads from stdin [default: $AOC_INPUT]
+    #[arg(long, value_name = "PATH")]
+    pub input: Option<String>,
+
+    /// Report irregular input, e.g. CRLF line endings or a BOM, instead of normalizing it
+    #[arg(long)]
+    pub strict: bool,
+
+    #[command(flatten)]
+    pub config: ConfigArgs,
+}
+
+#[derive(Args)]
+pub struct RenderArgs {
+    /// Day number
+    pub day: usize,
+
+    /// Render only the given part (1 or 2)
+    #[arg(long)]
+    pub part: Option<Part>,
+
+    /// Format of frames
+    #[arg(long, value_enum, default_value_t = RenderFormat::Ansi)]
+    pub format: RenderFormat,
+
+    /// Size of a tile in pixels of images
+    #[arg(long, default_value_t = 4, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    pub scale: usize,
+
+    /// Write every frame to a separate file in the directory instead of stdout
+    #[arg(long, value_name = "DIR")]
+    pub output: Option<PathBuf>,
+
+    /// Puzzle input file, '-' reads from stdin [default: $AOC_INPUT]
+    #[arg(long, value_name = "PATH")]
+    pub input: Option<String>,
+
+    /// Report irregular input, e.g. CRLF line endings or a BOM, instead of normalizing it
+    #[arg(long)]
+    pub strict: bool,
+
+    #[command(flatten)]
+    pub config: ConfigArgs,
+}
+
+#[derive(Args)]
+pub struct ScaffoldArgs {
+    /// Day number
+    #[arg(value_parser = RangedU64ValueParser::<usize>::new().range(1..=25))]
+    pub day: usize,
+
+    /// Puzzle name, e.g. 'Keypad Conundrum', words form the crate name 'day_NN_keypad_conundrum'
+    pub name: String,
+
+    /// Root of the workspace with the 'aoc' crate
+    #[arg(long, value_name = "DIR", default_value = ".")]
+    pub workspace: PathBuf,
+}
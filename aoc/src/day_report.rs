@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use aoc_common::{aoc_error::AocError, day::Day, part::Part};
+
+use super::{day_status::DayStatus, part_answer::PartAnswer};
+
+pub struct DayReport {
+    pub number: usize,
+    pub name: &'static str,
+    pub description: String,
+    pub parse_duration: Duration,
+    pub answers: Vec<PartAnswer>,
+    pub status: DayStatus,
+}
+
+impl DayReport {
+    pub fn title(&self) -> &str {
+        // Description has a format '--- Day 1: Historian Hysteria ---'
+        let trimmed = self.description.trim_matches(|c| c == '-' || c == ' ');
+
+        match trimmed.split_once(": ") {
+            Some((_, title)) => title,
+            None => trimmed,
+        }
+    }
+
+    /// Report of a day which did not finish, every requested part shares the error
+    pub fn interrupted(day: &Day, parts: &[Part], status: DayStatus, error: AocError) -> Self {
+        Self {
+            number: day.get_number(),
+            name: day.get_name(),
+            description: day.create_solver().description(),
+            parse_duration: Duration::ZERO,
+            answers: parts
+                .iter()
+                .map(|&part| PartAnswer {
+                    part,
+                    answer: Err(error.clone()),
+                    duration: None,
+                })
+                .collect(),
+            status,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title() {
+        let report = DayReport {
+            number: 1,
+            name: "day_01_historian_hysteria",
+            description: "--- Day 1: Historian Hysteria ---".to_string(),
+            parse_duration: Duration::ZERO,
+            answers: vec![],
+            status: DayStatus::Passed,
+        };
+
+        assert_eq!(report.title(), "Historian Hysteria");
+    }
+}
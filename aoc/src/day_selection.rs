@@ -0,0 +1,127 @@
+use std::str::FromStr;
+
+use aoc_common::{
+    aoc_error::AocError,
+    config_args::ConfigArgs,
+    day::Day,
+    day_config::DayConfig,
+    input_source::{InputSource, INPUT_ENV},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DaySelection {
+    All,
+    Single(usize),
+}
+
+impl DaySelection {
+    pub fn select(&self, days: Vec<Day>) -> Result<Vec<Day>, AocError> {
+        match self {
+            DaySelection::All => Ok(days),
+            DaySelection::Single(number) => {
+                let selected = days
+                    .into_iter()
+                    .filter(|day| day.get_number() == *number)
+                    .collect::<Vec<_>>();
+
+                if selected.is_empty() {
+                    return Err(AocError::ArgumentError(format!(
+                        "Day {} is not available",
+                        number
+                    )));
+                }
+
+                Ok(selected)
+            }
+        }
+    }
+
+    /// Select days together with their input, override is accepted only for a single day
+    pub fn select_with_input(
+        &self,
+        days: Vec<Day>,
+        input: Option<&str>,
+    ) -> Result<Vec<(Day, InputSource)>, AocError> {
+        let days = self.select(days)?;
+
+        match self {
+            DaySelection::Single(_) => Ok(days
+                .into_iter()
+                .map(|day| {
+                    let source = InputSource::resolve(input, day.default_input());
+                    (day, source)
+                })
+                .collect()),
+            DaySelection::All => {
+                // A single input cannot be valid for every day
+                if input.is_some() || InputSource::from_env().is_some() {
+                    return Err(AocError::ArgumentError(format!(
+                        "Input override (--input or {}) requires a single day",
+                        INPUT_ENV
+                    )));
+                }
+
+                Ok(days
+                    .into_iter()
+                    .map(|day| {
+                        let source = day.default_input();
+                        (day, source)
+                    })
+                    .collect())
+            }
+        }
+    }
+
+    /// Load configuration of a selected day, `--set` overrides are accepted only for a single day
+    pub fn load_config(&self, day: &Day, args: &ConfigArgs) -> Result<DayConfig, AocError> {
+        if *self == DaySelection::All && !args.overrides.is_empty() {
+            return Err(AocError::ArgumentError(
+                "Config override (--set) requires a single day, use --config for all days"
+                    .to_string(),
+            ));
+        }
+
+        args.load(day.get_number())
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(DaySelection::All);
+        }
+
+        s.parse::<usize>()
+            .map(DaySelection::Single)
+            .map_err(|_| format!("Day should be a number or 'all' but '{}' found", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::{super::days, *};
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("all".parse::<DaySelection>(), Ok(DaySelection::All));
+        assert_eq!("7".parse::<DaySelection>(), Ok(DaySelection::Single(7)));
+        assert!("seven".parse::<DaySelection>().is_err());
+    }
+
+    #[test]
+    fn test_select_with_input_empty_env() {
+        // Empty `AOC_INPUT` is not an override, the same as for a single day
+        env::set_var(INPUT_ENV, "");
+        let selected = DaySelection::All.select_with_input(days::all_days(), None);
+        env::remove_var(INPUT_ENV);
+
+        assert!(selected.is_ok(), "Result: {:?}", selected.err());
+        assert!(DaySelection::All
+            .select_with_input(days::all_days(), Some("input.txt"))
+            .is_err());
+    }
+}
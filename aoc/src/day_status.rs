@@ -0,0 +1,33 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+/// Outcome of a whole day, parts with errors make the day failed
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DayStatus {
+    Passed,
+    Failed,
+    Panicked,
+    TimedOut,
+}
+
+impl DayStatus {
+    pub const ALL: [DayStatus; 4] = [
+        DayStatus::Passed,
+        DayStatus::Failed,
+        DayStatus::Panicked,
+        DayStatus::TimedOut,
+    ];
+}
+
+impl Display for DayStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DayStatus::Passed => write!(f, "passed"),
+            DayStatus::Failed => write!(f, "failed"),
+            DayStatus::Panicked => write!(f, "panicked"),
+            DayStatus::TimedOut => write!(f, "timed out"),
+        }
+    }
+}
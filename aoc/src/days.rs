@@ -0,0 +1,26 @@
+use aoc_common::day::Day;
+
+pub fn all_days() -> Vec<Day> {
+    vec![
+        day_01_historian_hysteria::day(),
+        day_02_rednosed_reports::day(),
+        day_03_mull_it_over::day(),
+        day_04_ceres_search::day(),
+        day_05_print_queue::day(),
+        day_06_guard_gallivant::day(),
+        day_07_bridge_repair::day(),
+        day_08_resonant_collinearity::day(),
+        day_09_disk_fragmenter::day(),
+        day_10_hoof_it::day(),
+        day_11_plutonian_pebbles::day(),
+        day_12_garden_groups::day(),
+        day_13_claw_contraption::day(),
+        day_14_restroom_redoubt::day(),
+        day_15_warehouse_woes::day(),
+        day_16_reindeer_maze::day(),
+        day_17_chronospatial_computer::day(),
+        day_18_ram_run::day(),
+        day_19_linen_layout::day(),
+        day_20_race_condition::day(),
+    ]
+}
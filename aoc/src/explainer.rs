@@ -0,0 +1,61 @@
+use aoc_common::{
+    aoc_error::AocError, explanation::Explanation, normalization::Normalization, part::Part,
+};
+use serde::Serialize;
+
+use super::{cli::ExplainArgs, day_selection::DaySelection, days};
+
+#[derive(Serialize)]
+struct DayExplanation {
+    day: usize,
+    name: &'static str,
+    description: String,
+    parts: Vec<PartExplanation>,
+}
+
+#[derive(Serialize)]
+struct PartExplanation {
+    part: usize,
+    #[serde(flatten)]
+    explanation: Explanation,
+}
+
+pub fn explain(args: &ExplainArgs) -> Result<bool, AocError> {
+    let selection = DaySelection::Single(args.day);
+    let (day, input) = selection
+        .select_with_input(days::all_days(), args.input.as_deref())?
+        .remove(0);
+
+    let mut solver = day.create_solver();
+    solver.configure(&selection.load_config(&day, &args.config)?)?;
+    input.parse_into(solver.as_mut(), Normalization::from_strict(args.strict))?;
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let parts = parts
+        .into_iter()
+        .map(|part| {
+            solver.explain(part).map(|explanation| PartExplanation {
+                part: part.get_number(),
+                explanation,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let explanation = DayExplanation {
+        day: day.get_number(),
+        name: day.get_name(),
+        description: solver.description(),
+        parts,
+    };
+
+    let json = serde_json::to_string(&explanation).map_err(|err| {
+        AocError::SolverError(format!("Failed to serialize explanation [{}]", err))
+    })?;
+    println!("{}", json);
+
+    Ok(true)
+}
@@ -0,0 +1,26 @@
+use std::fs;
+
+use aoc_common::aoc_error::AocError;
+use rand::SeedableRng;
+
+use super::{
+    cli::GenerateArgs,
+    generators::{self, InputRng},
+};
+
+pub fn generate(args: &GenerateArgs) -> Result<bool, AocError> {
+    let generator = generators::find(args.day)
+        .ok_or_else(|| AocError::ArgumentError(format!("Day {} is not available", args.day)))?;
+
+    let size = args.size.unwrap_or(generator.default_size);
+    let content = (generator.generate)(&mut InputRng::seed_from_u64(args.seed), size);
+
+    match &args.output {
+        Some(path) => fs::write(path, content).map_err(|err| {
+            AocError::InputError(format!("Failed to write '{}' [{}]", path.display(), err))
+        })?,
+        None => print!("{}", content),
+    }
+
+    Ok(true)
+}
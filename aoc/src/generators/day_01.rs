@@ -0,0 +1,24 @@
+use rand::{seq::SliceRandom, Rng};
+
+use super::{to_input, InputRng};
+
+/// Two columns of location IDs, `size` is the count of lines
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let left = (0..size)
+        .map(|_| rng.gen_range(10_000..100_000))
+        .collect::<Vec<usize>>();
+
+    // Part of the right list repeats locations from the left one so the similarity is not zero
+    let right = (0..size)
+        .map(|_| match rng.gen_bool(0.3) {
+            true => *left.choose(rng).unwrap(),
+            false => rng.gen_range(10_000..100_000),
+        })
+        .collect::<Vec<usize>>();
+
+    to_input(
+        left.iter()
+            .zip(&right)
+            .map(|(left, right)| format!("{}   {}", left, right)),
+    )
+}
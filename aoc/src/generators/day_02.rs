@@ -0,0 +1,32 @@
+use rand::Rng;
+
+use super::{to_input, InputRng};
+
+/// Reports of 5 to 8 levels, `size` is the count of reports
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    to_input((0..size).map(|_| {
+        let len = rng.gen_range(5..=8);
+        let direction = if rng.gen_bool(0.5) { 1 } else { -1 };
+
+        let mut levels = vec![rng.gen_range(1..=99_isize)];
+        for _ in 1..len {
+            // Mostly safe steps with an occasional bad level
+            let step = match rng.gen_bool(0.9) {
+                true => direction * rng.gen_range(1..=3),
+                false => rng.gen_range(-6..=6),
+            };
+
+            levels.push(levels[levels.len() - 1] + step);
+        }
+
+        // Levels are positive numbers
+        let lowest = *levels.iter().min().unwrap();
+        let shift = if lowest < 1 { 1 - lowest } else { 0 };
+
+        levels
+            .iter()
+            .map(|level| (level + shift).to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }))
+}
@@ -0,0 +1,53 @@
+use rand::{seq::SliceRandom, Rng};
+
+use super::{to_input, InputRng};
+
+const LINES: usize = 6;
+const NOISE: [&str; 16] = [
+    "who()",
+    "what()",
+    "where(",
+    "select()",
+    "from()",
+    "how()",
+    "when()",
+    "why()",
+    "mul(4*",
+    "mul[3,7]",
+    "mul ( 2 , 4 )",
+    "?(12,34)",
+    "mul(32,64]",
+    "don't",
+    "do",
+    "mul(,5)",
+];
+const SYMBOLS: &[u8] = b"!@#$%^&*()[]{}<>?/\\'~+-:;, ";
+
+/// Corrupted memory spread over six lines, `size` is the count of `mul` instructions
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let mut lines = vec![String::new(); LINES];
+
+    for index in 0..size {
+        let line = &mut lines[index * LINES / size.max(1)];
+
+        // Some noise in between instructions
+        for _ in 0..rng.gen_range(0..4) {
+            match rng.gen_bool(0.5) {
+                true => line.push_str(NOISE.choose(rng).unwrap()),
+                false => line.push(*SYMBOLS.choose(rng).unwrap() as char),
+            }
+        }
+
+        if rng.gen_bool(0.05) {
+            line.push_str(if rng.gen_bool(0.5) { "do()" } else { "don't()" });
+        }
+
+        line.push_str(&format!(
+            "mul({},{})",
+            rng.gen_range(1..1000),
+            rng.gen_range(1..1000)
+        ));
+    }
+
+    to_input(lines.into_iter().filter(|line| !line.is_empty()))
+}
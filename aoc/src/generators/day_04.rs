@@ -0,0 +1,34 @@
+use rand::{seq::SliceRandom, Rng};
+
+use super::{grid_to_input, InputRng};
+
+const WORD: [char; 4] = ['X', 'M', 'A', 'S'];
+
+/// Square word search, `size` is the length of the side
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let mut grid = (0..size)
+        .map(|_| (0..size).map(|_| *WORD.choose(rng).unwrap()).collect())
+        .collect::<Vec<Vec<char>>>();
+
+    // Random letters contain only a few words, hide more of them in every direction
+    let size = size as isize;
+    for _ in 0..size * size / 20 {
+        let (row, col) = (rng.gen_range(0..size), rng.gen_range(0..size));
+        let (row_step, col_step) = (rng.gen_range(-1..=1), rng.gen_range(-1..=1));
+
+        let end = (row + 3 * row_step, col + 3 * col_step);
+        if (row_step, col_step) == (0, 0)
+            || !(0..size).contains(&end.0)
+            || !(0..size).contains(&end.1)
+        {
+            continue;
+        }
+
+        for (index, letter) in WORD.iter().enumerate() {
+            let index = index as isize;
+            grid[(row + index * row_step) as usize][(col + index * col_step) as usize] = *letter;
+        }
+    }
+
+    grid_to_input(&grid)
+}
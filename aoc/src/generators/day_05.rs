@@ -0,0 +1,40 @@
+use rand::{seq::SliceRandom, Rng};
+
+use super::{to_input, InputRng};
+
+const PAGES: usize = 49;
+
+/// Ordering rules of 49 pages followed by updates, `size` is the count of updates
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    // Random order of pages, rules are given for every pair of pages
+    let mut order = (10..100).collect::<Vec<usize>>();
+    order.shuffle(rng);
+    order.truncate(PAGES);
+
+    let mut rules = Vec::with_capacity(PAGES * (PAGES - 1) / 2);
+    for (index, before) in order.iter().enumerate() {
+        for after in &order[index + 1..] {
+            rules.push(format!("{}|{}", before, after));
+        }
+    }
+    rules.shuffle(rng);
+
+    let updates = (0..size).map(|_| {
+        // Updates have an odd count of pages so the middle page is well defined
+        let len = 2 * rng.gen_range(2..=11) + 1;
+        let mut positions = rand::seq::index::sample(rng, PAGES, len).into_vec();
+
+        match rng.gen_bool(0.5) {
+            true => positions.sort(),
+            false => positions.shuffle(rng),
+        }
+
+        positions
+            .iter()
+            .map(|&position| order[position].to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    });
+
+    to_input(rules.into_iter().chain([String::new()]).chain(updates))
+}
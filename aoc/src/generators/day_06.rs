@@ -0,0 +1,50 @@
+use std::collections::HashSet;
+
+use rand::Rng;
+
+use super::{grid_to_input, InputRng};
+
+/// Lab map with obstructions and the guard facing up, `size` is the length of the side
+///
+/// Maps where the guard would walk in a loop are generated again, so the guard always leaves.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    loop {
+        let mut grid = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.gen_bool(0.05) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect::<Vec<Vec<char>>>();
+
+        let guard = (rng.gen_range(0..size), rng.gen_range(0..size));
+        grid[guard.0][guard.1] = '^';
+
+        if leaves_map(&grid, guard) {
+            return grid_to_input(&grid);
+        }
+    }
+}
+
+fn leaves_map(grid: &[Vec<char>], guard: (usize, usize)) -> bool {
+    let size = grid.len() as isize;
+    let (mut row, mut col) = (guard.0 as isize, guard.1 as isize);
+    let (mut row_step, mut col_step) = (-1, 0);
+    let mut visited = HashSet::new();
+
+    while visited.insert((row, col, row_step, col_step)) {
+        let (next_row, next_col) = (row + row_step, col + col_step);
+
+        if !(0..size).contains(&next_row) || !(0..size).contains(&next_col) {
+            return true;
+        }
+
+        match grid[next_row as usize][next_col as usize] {
+            // Turn right
+            '#' => (row_step, col_step) = (col_step, -row_step),
+            _ => (row, col) = (next_row, next_col),
+        }
+    }
+
+    false
+}
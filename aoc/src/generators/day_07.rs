@@ -0,0 +1,44 @@
+use rand::Rng;
+
+use super::{to_input, InputRng};
+
+// All values computed by the solver stay below 10^DIGITS
+const DIGITS: usize = 16;
+
+/// Calibration equations of 2 to 12 numbers, `size` is the count of equations
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    to_input((0..size).map(|_| {
+        let count = rng.gen_range(2..=12);
+
+        let mut budget = DIGITS;
+        let numbers = (0..count)
+            .map(|index| {
+                // Leave at least one digit for every remaining number
+                let digits = rng.gen_range(1..=3.min(budget - (count - index - 1)));
+                budget -= digits;
+
+                rng.gen_range(10_usize.pow(digits as u32 - 1)..10_usize.pow(digits as u32))
+            })
+            .collect::<Vec<_>>();
+
+        // About half of the equations can be made true
+        let calibration =
+            match rng.gen_bool(0.5) {
+                true => numbers[1..].iter().fold(numbers[0], |value, &number| {
+                    match rng.gen_range(0..3) {
+                        0 => value + number,
+                        1 => value * number,
+                        _ => value * 10_usize.pow(number.to_string().len() as u32) + number,
+                    }
+                }),
+                false => rng.gen_range(1..10_usize.pow(DIGITS as u32 / 2)),
+            };
+
+        let numbers = numbers
+            .iter()
+            .map(|number| number.to_string())
+            .collect::<Vec<_>>();
+
+        format!("{}: {}", calibration, numbers.join(" "))
+    }))
+}
@@ -0,0 +1,24 @@
+use rand::{seq::SliceRandom, Rng};
+
+use super::{grid_to_input, InputRng};
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Map of antennas, `size` is the length of the side
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let mut grid = vec![vec!['.'; size]; size];
+
+    // About four antennas per frequency
+    let frequencies = (size / 4).clamp(1, FREQUENCIES.len());
+    let frequencies = FREQUENCIES
+        .choose_multiple(rng, frequencies)
+        .copied()
+        .collect::<Vec<_>>();
+
+    for _ in 0..frequencies.len() * 4 {
+        let (row, col) = (rng.gen_range(0..size), rng.gen_range(0..size));
+        grid[row][col] = *frequencies.choose(rng).unwrap() as char;
+    }
+
+    grid_to_input(&grid)
+}
@@ -0,0 +1,17 @@
+use rand::Rng;
+
+use super::{to_input, InputRng};
+
+/// Dense disk map, `size` is the count of digits
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    // Files have at least one block, free space may be empty
+    let digits = (0..size)
+        .map(|index| match index % 2 {
+            0 => rng.gen_range(1..=9_u32),
+            _ => rng.gen_range(0..=9_u32),
+        })
+        .map(|digit| char::from_digit(digit, 10).unwrap())
+        .collect::<String>();
+
+    to_input([digits])
+}
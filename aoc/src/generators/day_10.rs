@@ -0,0 +1,37 @@
+use rand::Rng;
+
+use super::{grid_to_input, InputRng};
+
+/// Topographic map, `size` is the length of the side
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    // Hills with a peak of height 9 and slopes going down by one, so there are plenty of trails
+    let peaks = (0..(size * size / 60).max(1))
+        .map(|_| (rng.gen_range(0..size), rng.gen_range(0..size)))
+        .collect::<Vec<_>>();
+
+    let grid = (0..size)
+        .map(|row| {
+            (0..size)
+                .map(|col| {
+                    let distance = peaks
+                        .iter()
+                        .map(|&(peak_row, peak_col)| {
+                            row.abs_diff(peak_row) + col.abs_diff(peak_col)
+                        })
+                        .min()
+                        .unwrap();
+
+                    // Plains far from hills have random heights
+                    let height = match distance {
+                        0..=9 => 9 - distance as u32,
+                        _ => rng.gen_range(0..=9),
+                    };
+
+                    char::from_digit(height, 10).unwrap()
+                })
+                .collect()
+        })
+        .collect::<Vec<Vec<char>>>();
+
+    grid_to_input(&grid)
+}
@@ -0,0 +1,15 @@
+use rand::Rng;
+
+use super::{to_input, InputRng};
+
+/// Engraved stones in a single line, `size` is the count of stones
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let stones = (0..size)
+        .map(|_| {
+            let digits = rng.gen_range(1..=7);
+            rng.gen_range(0..10_usize.pow(digits)).to_string()
+        })
+        .collect::<Vec<_>>();
+
+    to_input([stones.join(" ")])
+}
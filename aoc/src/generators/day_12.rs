@@ -0,0 +1,25 @@
+use rand::Rng;
+
+use super::{grid_to_input, InputRng};
+
+/// Garden plots, `size` is the length of the side
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let mut grid = vec![vec!['A'; size]; size];
+
+    for row in 0..size {
+        for col in 0..size {
+            // Plots mostly continue a neighboring region so regions have interesting shapes
+            grid[row][col] = match (rng.gen_bool(0.85), row, col) {
+                (true, 0, 0) | (false, _, _) => rng.gen_range('A'..='Z'),
+                (true, 0, _) => grid[row][col - 1],
+                (true, _, 0) => grid[row - 1][col],
+                (true, _, _) => match rng.gen_bool(0.5) {
+                    true => grid[row][col - 1],
+                    false => grid[row - 1][col],
+                },
+            };
+        }
+    }
+
+    grid_to_input(&grid)
+}
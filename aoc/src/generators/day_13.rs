@@ -0,0 +1,30 @@
+use rand::Rng;
+
+use super::{to_input, InputRng};
+
+/// Claw machines separated by an empty line, `size` is the count of machines
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    to_input((0..size).flat_map(|index| {
+        let a = (rng.gen_range(10..100), rng.gen_range(10..100));
+        let b = (rng.gen_range(10..100), rng.gen_range(10..100));
+
+        // Part 1 needs at least one prize which can be won
+        let prize = match index == 0 || rng.gen_bool(0.5) {
+            true => {
+                let (a_count, b_count) = (rng.gen_range(1..=100), rng.gen_range(1..=100));
+                (a_count * a.0 + b_count * b.0, a_count * a.1 + b_count * b.1)
+            }
+            false => (rng.gen_range(1000..20_000), rng.gen_range(1000..20_000)),
+        };
+
+        let separator = (index + 1 < size).then(String::new);
+
+        [
+            format!("Button A: X+{}, Y+{}", a.0, a.1),
+            format!("Button B: X+{}, Y+{}", b.0, b.1),
+            format!("Prize: X={}, Y={}", prize.0, prize.1),
+        ]
+        .into_iter()
+        .chain(separator)
+    }))
+}
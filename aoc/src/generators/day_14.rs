@@ -0,0 +1,19 @@
+use rand::Rng;
+
+use super::{to_input, InputRng};
+
+const WIDTH: isize = 101;
+const HEIGHT: isize = 103;
+
+/// Robots in the 101x103 space, `size` is the count of robots
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    to_input((0..size).map(|_| {
+        format!(
+            "p={},{} v={},{}",
+            rng.gen_range(0..WIDTH),
+            rng.gen_range(0..HEIGHT),
+            rng.gen_range(-WIDTH + 1..WIDTH),
+            rng.gen_range(-HEIGHT + 1..HEIGHT)
+        )
+    }))
+}
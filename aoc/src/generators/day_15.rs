@@ -0,0 +1,42 @@
+use rand::{seq::SliceRandom, Rng};
+
+use super::{grid_to_input, to_input, InputRng};
+
+const MOVES_PER_LINE: usize = 1000;
+
+/// Warehouse surrounded by walls followed by robot moves, `size` is the length of the side
+///
+/// Count of moves grows with the area of the warehouse, i.e. `8 * size * size`.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let size = size.max(3);
+
+    let mut grid = (0..size)
+        .map(|row| {
+            (0..size)
+                .map(|col| match (row, col) {
+                    (0, _) | (_, 0) => '#',
+                    _ if row == size - 1 || col == size - 1 => '#',
+                    _ => match rng.gen_range(0..100) {
+                        0..5 => '#',
+                        5..35 => 'O',
+                        _ => '.',
+                    },
+                })
+                .collect()
+        })
+        .collect::<Vec<Vec<char>>>();
+
+    grid[rng.gen_range(1..size - 1)][rng.gen_range(1..size - 1)] = '@';
+
+    let moves = (0..8 * size * size)
+        .map(|_| *['<', '>', '^', 'v'].choose(rng).unwrap())
+        .collect::<Vec<_>>();
+
+    grid_to_input(&grid)
+        + "\n"
+        + &to_input(
+            moves
+                .chunks(MOVES_PER_LINE)
+                .map(|line| line.iter().collect()),
+        )
+}
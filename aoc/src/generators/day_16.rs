@@ -0,0 +1,25 @@
+use rand::Rng;
+
+use super::{grid_to_input, maze, InputRng};
+
+/// Maze with the start in the bottom left and the end in the top right corner, `size` is the
+/// length of the side rounded up to an odd number
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let mut open = maze::carve(rng, size);
+    let side = open.len();
+
+    // Remove some inner walls so there are more best paths
+    for (row, line) in open.iter_mut().enumerate().take(side - 1).skip(1) {
+        for (col, tile) in line.iter_mut().enumerate().take(side - 1).skip(1) {
+            if (row + col) % 2 == 1 && rng.gen_bool(0.1) {
+                *tile = true;
+            }
+        }
+    }
+
+    let mut grid = maze::to_grid(&open);
+    grid[side - 2][1] = 'S';
+    grid[1][side - 2] = 'E';
+
+    grid_to_input(&grid)
+}
@@ -0,0 +1,35 @@
+use rand::Rng;
+
+use super::{to_input, InputRng};
+
+// Count of 3-bit digits which fit into register A
+const MAX_OUTPUTS: usize = 21;
+
+/// Program which prints a value for every 3 bits of register A and halts, `size` is the count
+/// of printed values (at most 21)
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let outputs = size.clamp(1, MAX_OUTPUTS) as u32;
+    let a = rng.gen_range(8_u64.pow(outputs - 1)..8_u64.pow(outputs) - 1);
+
+    // bst A, bxl k, cdv B, then bxl k and bxc in any order, adv 3, out B, jnz 0
+    let mut xor = [vec![1, rng.gen_range(0..8)], vec![4, rng.gen_range(0..8)]];
+    if rng.gen_bool(0.5) {
+        xor.swap(0, 1);
+    }
+
+    let program = [vec![2, 4, 1, rng.gen_range(0..8), 7, 5]]
+        .into_iter()
+        .chain(xor)
+        .chain([vec![0, 3, 5, 5, 3, 0]])
+        .flatten()
+        .map(|value: u8| value.to_string())
+        .collect::<Vec<_>>();
+
+    to_input([
+        format!("Register A: {}", a),
+        "Register B: 0".to_string(),
+        "Register C: 0".to_string(),
+        String::new(),
+        format!("Program: {}", program.join(",")),
+    ])
+}
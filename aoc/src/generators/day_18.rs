@@ -0,0 +1,84 @@
+use std::collections::VecDeque;
+
+use rand::seq::SliceRandom;
+
+use super::{to_input, InputRng};
+
+const SIDE: usize = 71;
+const CORRUPTED_BYTES: usize = 1024;
+
+/// Bytes falling into the 71x71 memory space, `size` is the count of bytes
+///
+/// The first 1024 bytes never block the exit and bytes continue at least until one of them
+/// does, so both parts have an answer.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let start = (0, 0);
+    let exit = (SIDE - 1, SIDE - 1);
+
+    let mut bytes = (0..SIDE)
+        .flat_map(|x| (0..SIDE).map(move |y| (x, y)))
+        .filter(|&position| position != start && position != exit)
+        .collect::<Vec<_>>();
+
+    loop {
+        bytes.shuffle(rng);
+
+        let blocking = first_blocking(&bytes);
+        if blocking >= CORRUPTED_BYTES {
+            bytes.truncate(size.max(blocking + 1));
+            break;
+        }
+    }
+
+    to_input(bytes.iter().map(|(x, y)| format!("{},{}", x, y)))
+}
+
+// Index of the first byte which cuts off the exit, bytes are added one by one
+fn first_blocking(bytes: &[(usize, usize)]) -> usize {
+    // Binary search over the count of fallen bytes
+    let (mut low, mut high) = (0, bytes.len());
+
+    while low < high {
+        let middle = (low + high) / 2;
+
+        if is_reachable(&bytes[..=middle]) {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+
+    low
+}
+
+fn is_reachable(bytes: &[(usize, usize)]) -> bool {
+    let mut blocked = vec![vec![false; SIDE]; SIDE];
+    for &(x, y) in bytes {
+        blocked[x][y] = true;
+    }
+
+    let mut remaining = VecDeque::from([(0, 0)]);
+    blocked[0][0] = true;
+
+    while let Some((x, y)) = remaining.pop_front() {
+        if (x, y) == (SIDE - 1, SIDE - 1) {
+            return true;
+        }
+
+        let neighbors = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+
+        for (x, y) in neighbors {
+            if x < SIDE && y < SIDE && !blocked[x][y] {
+                blocked[x][y] = true;
+                remaining.push_back((x, y));
+            }
+        }
+    }
+
+    false
+}
@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use rand::{seq::SliceRandom, Rng};
+
+use super::{to_input, InputRng};
+
+const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+const TOWELS: usize = 447;
+
+/// Available towel patterns followed by designs, `size` is the count of designs
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    // One color does not have its own towel, so not every design is possible
+    let missing = *COLORS.choose(rng).unwrap();
+
+    let mut towels = COLORS
+        .iter()
+        .filter(|&&color| color != missing)
+        .map(|color| color.to_string())
+        .collect::<Vec<_>>();
+    let mut unique = towels.iter().cloned().collect::<HashSet<_>>();
+
+    while towels.len() < TOWELS {
+        let len = rng.gen_range(2..=8);
+        let towel = random_stripes(rng, len);
+
+        if unique.insert(towel.clone()) {
+            towels.push(towel);
+        }
+    }
+    towels.shuffle(rng);
+
+    let designs = (0..size).map(|_| {
+        let len = rng.gen_range(20..=60);
+
+        // About half of the designs are composed from available towels
+        match rng.gen_bool(0.5) {
+            true => {
+                let mut design = String::new();
+                while design.len() < len {
+                    design.push_str(towels.choose(rng).unwrap());
+                }
+                design
+            }
+            false => random_stripes(rng, len),
+        }
+    });
+
+    to_input(
+        [towels.join(", "), String::new()]
+            .into_iter()
+            .chain(designs),
+    )
+}
+
+fn random_stripes(rng: &mut InputRng, len: usize) -> String {
+    (0..len).map(|_| *COLORS.choose(rng).unwrap()).collect()
+}
@@ -0,0 +1,47 @@
+use std::collections::VecDeque;
+
+use super::{grid_to_input, maze, InputRng};
+
+/// Race track with a single path from the start to the end, `size` is the length of the side
+/// rounded up to an odd number
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let open = maze::carve(rng, size);
+    let side = open.len();
+
+    // Maze is a tree, the path to the farthest tile from the start is the race track
+    let start = (1, 1);
+    let mut previous = vec![vec![None; side]; side];
+    let mut remaining = VecDeque::from([start]);
+    let mut end = start;
+
+    previous[start.0][start.1] = Some(start);
+
+    while let Some((row, col)) = remaining.pop_front() {
+        end = (row, col);
+
+        for (next_row, next_col) in [
+            (row - 1, col),
+            (row + 1, col),
+            (row, col - 1),
+            (row, col + 1),
+        ] {
+            if open[next_row][next_col] && previous[next_row][next_col].is_none() {
+                previous[next_row][next_col] = Some((row, col));
+                remaining.push_back((next_row, next_col));
+            }
+        }
+    }
+
+    let mut grid = vec![vec!['#'; side]; side];
+    let mut current = end;
+
+    while current != start {
+        grid[current.0][current.1] = '.';
+        current = previous[current.0][current.1].unwrap();
+    }
+
+    grid[start.0][start.1] = 'S';
+    grid[end.0][end.1] = 'E';
+
+    grid_to_input(&grid)
+}
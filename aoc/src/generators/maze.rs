@@ -0,0 +1,49 @@
+use rand::seq::SliceRandom;
+
+use super::InputRng;
+
+/// Perfect maze carved by a randomized depth first search, `true` marks an open tile
+///
+/// Open cells have odd coordinates and the border is always a wall, therefore `side` is
+/// rounded up to an odd number of at least 5.
+pub fn carve(rng: &mut InputRng, side: usize) -> Vec<Vec<bool>> {
+    let side = side.max(5) | 1;
+    let mut open = vec![vec![false; side]; side];
+
+    open[1][1] = true;
+    let mut stack = vec![(1, 1)];
+
+    while let Some(&(row, col)) = stack.last() {
+        let mut neighbors = [(-2, 0), (2, 0), (0, -2), (0, 2)]
+            .iter()
+            .map(|(row_step, col_step)| (row as isize + row_step, col as isize + col_step))
+            .filter(|&(row, col)| row > 0 && col > 0 && row < side as isize && col < side as isize)
+            .map(|(row, col)| (row as usize, col as usize))
+            .filter(|&(row, col)| !open[row][col])
+            .collect::<Vec<_>>();
+
+        match neighbors.choose_mut(rng) {
+            Some(&mut (next_row, next_col)) => {
+                // Open the wall in between and the next cell
+                open[(row + next_row) / 2][(col + next_col) / 2] = true;
+                open[next_row][next_col] = true;
+                stack.push((next_row, next_col));
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    open
+}
+
+pub fn to_grid(open: &[Vec<bool>]) -> Vec<Vec<char>> {
+    open.iter()
+        .map(|row| {
+            row.iter()
+                .map(|&open| if open { '.' } else { '#' })
+                .collect()
+        })
+        .collect()
+}
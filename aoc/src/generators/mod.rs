@@ -0,0 +1,136 @@
+//! Seeded generators of synthetic puzzle inputs
+//!
+//! Every generator produces an input in the format of the official one. The meaning of `size`
+//! depends on the day and it is described at each `generate` function. The same seed and size
+//! always produce the same input.
+
+use rand_chacha::ChaCha8Rng;
+
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_08;
+mod day_09;
+mod day_10;
+mod day_11;
+mod day_12;
+mod day_13;
+mod day_14;
+mod day_15;
+mod day_16;
+mod day_17;
+mod day_18;
+mod day_19;
+mod day_20;
+mod maze;
+
+pub type InputRng = ChaCha8Rng;
+
+#[derive(Clone, Copy)]
+pub struct Generator {
+    // Size which corresponds to the official input
+    pub default_size: usize,
+    pub generate: fn(&mut InputRng, usize) -> String,
+}
+
+pub fn find(day: usize) -> Option<Generator> {
+    let (default_size, generate): (usize, fn(&mut InputRng, usize) -> String) = match day {
+        1 => (1000, day_01::generate),
+        2 => (1000, day_02::generate),
+        3 => (700, day_03::generate),
+        4 => (140, day_04::generate),
+        5 => (200, day_05::generate),
+        6 => (130, day_06::generate),
+        7 => (850, day_07::generate),
+        8 => (50, day_08::generate),
+        9 => (19999, day_09::generate),
+        10 => (45, day_10::generate),
+        11 => (8, day_11::generate),
+        12 => (140, day_12::generate),
+        13 => (320, day_13::generate),
+        14 => (500, day_14::generate),
+        15 => (50, day_15::generate),
+        16 => (141, day_16::generate),
+        17 => (16, day_17::generate),
+        18 => (3450, day_18::generate),
+        19 => (400, day_19::generate),
+        20 => (141, day_20::generate),
+        _ => return None,
+    };
+
+    Some(Generator {
+        default_size,
+        generate,
+    })
+}
+
+// Joins generated lines into the content of an input file
+fn to_input(lines: impl IntoIterator<Item = String>) -> String {
+    lines
+        .into_iter()
+        .map(|line| line + "\n")
+        .collect::<String>()
+}
+
+fn grid_to_input(grid: &[Vec<char>]) -> String {
+    to_input(grid.iter().map(|row| row.iter().collect::<String>()))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::part::Part;
+    use rand::SeedableRng;
+
+    use crate::days;
+
+    use super::*;
+
+    fn generate(day: usize, seed: u64, size: usize) -> String {
+        let generator = find(day).expect("Generator not found");
+        (generator.generate)(&mut InputRng::seed_from_u64(seed), size)
+    }
+
+    #[test]
+    fn test_generate_is_reproducible() {
+        for day in days::all_days() {
+            let number = day.get_number();
+
+            assert_eq!(
+                generate(number, 7, 15),
+                generate(number, 7, 15),
+                "Day {}",
+                number
+            );
+            assert_ne!(
+                generate(number, 7, 15),
+                generate(number, 8, 15),
+                "Day {}",
+                number
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_is_solvable() {
+        for day in days::all_days() {
+            let number = day.get_number();
+            let content = generate(number, 42, 15);
+            let lines = content.lines().collect::<Vec<_>>();
+
+            let mut solver = day.create_solver();
+            solver
+                .parse(&lines)
+                .unwrap_or_else(|err| panic!("Day {}: {}\n{}", number, err, content));
+
+            for part in Part::ALL {
+                solver
+                    .solve(part)
+                    .unwrap_or_else(|err| panic!("Day {} {}: {}\n{}", number, part, err, content));
+            }
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::ops::Range;
+
+use aoc_common::{aoc_error::AocError, parse_error::ParseError};
+use serde::Serialize;
+
+use super::{
+    day_report::DayReport, day_status::DayStatus, part_answer::PartAnswer, part_status::PartStatus,
+    timing_stats::as_nanos,
+};
+
+#[derive(Serialize)]
+struct DayRecord<'a> {
+    day: usize,
+    name: &'a str,
+    description: &'a str,
+    status: DayStatus,
+    parse_ns: u64,
+    parts: Vec<PartRecord>,
+}
+
+#[derive(Serialize)]
+struct PartRecord {
+    part: usize,
+    status: PartStatus,
+    answer: Option<String>,
+    error: Option<String>,
+    location: Option<LocationRecord>,
+    duration_ns: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct LocationRecord {
+    input: Option<String>,
+    line: Option<usize>,
+    // 1-based columns, end is exclusive
+    columns: Option<Range<usize>>,
+}
+
+impl<'a> From<&'a DayReport> for DayRecord<'a> {
+    fn from(report: &'a DayReport) -> Self {
+        Self {
+            day: report.number,
+            name: report.name,
+            description: &report.description,
+            status: report.status,
+            parse_ns: as_nanos(report.parse_duration),
+            parts: report.answers.iter().map(PartRecord::from).collect(),
+        }
+    }
+}
+
+impl From<&PartAnswer> for PartRecord {
+    fn from(answer: &PartAnswer) -> Self {
+        Self {
+            part: answer.part.get_number(),
+            status: answer.status(),
+            answer: answer.answer.as_ref().ok().cloned(),
+            error: answer.answer.as_ref().err().map(|err| err.to_string()),
+            location: match &answer.answer {
+                Err(AocError::ParseError(err)) => Some(LocationRecord::from(err)),
+                _ => None,
+            },
+            duration_ns: answer.duration.map(as_nanos),
+        }
+    }
+}
+
+impl From<&ParseError> for LocationRecord {
+    fn from(err: &ParseError) -> Self {
+        Self {
+            input: err.get_input().map(str::to_string),
+            line: err.get_line(),
+            columns: err.get_columns().cloned(),
+        }
+    }
+}
+
+/// One compact JSON object per day and line, so that reports can be read as a stream
+pub fn to_json_lines(reports: &[DayReport]) -> Result<String, AocError> {
+    reports
+        .iter()
+        .map(|report| {
+            serde_json::to_string(&DayRecord::from(report)).map_err(|err| {
+                AocError::SolverError(format!("Failed to serialize day report [{}]", err))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|lines| lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_common::{
+        day_config::DayConfig, input_source::InputSource, normalization::Normalization, part::Part,
+    };
+    use serde_json::{json, Value};
+
+    use super::{super::days, super::runner, *};
+
+    fn parse_lines(json: &str) -> Vec<Value> {
+        json.lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_to_json() {
+        let report = DayReport {
+            number: 15,
+            name: "day_15_warehouse_woes",
+            description: "--- Day 15: Warehouse Woes ---".to_string(),
+            parse_duration: Duration::from_nanos(100),
+            answers: vec![
+                PartAnswer {
+                    part: Part::Part1,
+                    answer: Ok("42".to_string()),
+                    duration: Some(Duration::from_nanos(200)),
+                },
+                PartAnswer {
+                    part: Part::Part2,
+                    answer: Ok("Not solved".to_string()),
+                    duration: Some(Duration::from_nanos(300)),
+                },
+            ],
+            status: DayStatus::Passed,
+        };
+
+        let json = parse_lines(&to_json_lines(&[report]).unwrap());
+
+        assert_eq!(
+            json,
+            vec![json!({
+                "day": 15,
+                "name": "day_15_warehouse_woes",
+                "description": "--- Day 15: Warehouse Woes ---",
+                "status": "passed",
+                "parse_ns": 100,
+                "parts": [
+                    {
+                        "part": 1,
+                        "status": "ok",
+                        "answer": "42",
+                        "error": null,
+                        "location": null,
+                        "duration_ns": 200
+                    },
+                    {
+                        "part": 2,
+                        "status": "not_solved",
+                        "answer": "Not solved",
+                        "error": null,
+                        "location": null,
+                        "duration_ns": 300
+                    }
+                ]
+            })]
+        );
+    }
+
+    #[test]
+    fn test_to_json_error() {
+        let report = DayReport {
+            number: 1,
+            name: "day_01_historian_hysteria",
+            description: "--- Day 1: Historian Hysteria ---".to_string(),
+            parse_duration: Duration::from_nanos(100),
+            answers: vec![PartAnswer {
+                part: Part::Part1,
+                answer: Err(AocError::SolverError("Invalid content".to_string())),
+                duration: None,
+            }],
+            status: DayStatus::Failed,
+        };
+
+        let json = parse_lines(&to_json_lines(&[report]).unwrap());
+
+        assert_eq!(json[0]["status"], json!("failed"));
+        assert_eq!(
+            json[0]["parts"][0],
+            json!({
+                "part": 1,
+                "status": "error",
+                "answer": null,
+                "error": "Invalid content",
+                "location": null,
+                "duration_ns": null
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_json_lines_per_day() {
+        let report = |number| DayReport {
+            number,
+            name: "day_01_historian_hysteria",
+            description: "--- Day 1: Historian Hysteria ---".to_string(),
+            parse_duration: Duration::from_nanos(100),
+            answers: vec![],
+            status: DayStatus::Passed,
+        };
+
+        let json = to_json_lines(&[report(1), report(2)]).unwrap();
+
+        assert_eq!(json.lines().count(), 2);
+        assert_eq!(
+            parse_lines(&json)
+                .iter()
+                .map(|record| record["day"].clone())
+                .collect::<Vec<_>>(),
+            vec![json!(1), json!(2)]
+        );
+    }
+
+    #[test]
+    fn test_to_json_lines_not_solved_day() {
+        let day = days::all_days()
+            .into_iter()
+            .find(|day| day.get_number() == 14)
+            .unwrap();
+        let report = runner::solve_day(
+            &day,
+            &day.default_input(),
+            &DayConfig::default(),
+            &[Part::Part2],
+            Normalization::Tolerant,
+        );
+
+        let json = parse_lines(&to_json_lines(&[report]).unwrap());
+
+        assert_eq!(json[0]["parts"][0]["status"], json!("not_solved"));
+    }
+
+    #[test]
+    fn test_to_json_lines_parse_error_location() {
+        let day = days::all_days()
+            .into_iter()
+            .find(|day| day.get_number() == 2)
+            .unwrap();
+        let report = runner::solve_day(
+            &day,
+            &InputSource::Embedded("1 2 3\n4 x5 6\n"),
+            &DayConfig::default(),
+            &[Part::Part1],
+            Normalization::Tolerant,
+        );
+
+        let json = parse_lines(&to_json_lines(&[report]).unwrap());
+
+        assert_eq!(json[0]["parts"][0]["status"], json!("error"));
+        assert_eq!(
+            json[0]["parts"][0]["location"],
+            json!({
+                "input": "<embedded input>",
+                "line": 2,
+                "columns": { "start": 3, "end": 5 }
+            })
+        );
+    }
+}
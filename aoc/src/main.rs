@@ -0,0 +1,54 @@
+use std::process::ExitCode;
+
+use aoc_common::logging;
+use clap::Parser;
+use cli::{Cli, Command};
+
+mod bench;
+mod cli;
+mod day_report;
+mod day_selection;
+mod day_status;
+mod days;
+mod explainer;
+mod generator;
+mod generators;
+mod json_report;
+mod manifest;
+mod output_format;
+mod parallel_runner;
+mod part_answer;
+mod part_status;
+mod phase;
+mod report_format;
+mod runner;
+mod scaffolder;
+mod table;
+mod tester;
+mod timing_stats;
+mod verifier;
+mod visualizer;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = logging::init().and_then(|_| match cli.command {
+        Command::Run(args) => runner::run(&args),
+        Command::Test(args) => tester::test(&args),
+        Command::Bench(args) => bench::bench(&args),
+        Command::Verify(args) => verifier::verify(&args),
+        Command::Generate(args) => generator::generate(&args),
+        Command::Explain(args) => explainer::explain(&args),
+        Command::Render(args) => visualizer::render(&args),
+        Command::Scaffold(args) => scaffolder::scaffold(&args),
+    });
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{aoc_error::AocError, part::Part};
+use serde::Deserialize;
+
+/// Expected answers of one input, parts without a value are not verified
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExpectedAnswers {
+    part_1: Option<String>,
+    part_2: Option<String>,
+}
+
+/// Single input to verify together with its expected answers
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifestEntry {
+    pub day: usize,
+    pub name: String,
+    pub input: PathBuf,
+    pub expected: Vec<(Part, String)>,
+}
+
+/// TOML manifest with expected answers keyed by day and input name
+///
+/// ```toml
+/// [day_01.alice]
+/// part_1 = "1189304"
+/// part_2 = "24349736"
+/// ```
+///
+/// Input of the entry above is read from `<inputs>/day_01/alice.txt`.
+pub struct Manifest;
+
+impl Manifest {
+    pub fn load(path: &Path, inputs: &Path) -> Result<Vec<ManifestEntry>, AocError> {
+        let content = fs::read_to_string(path).map_err(|err| {
+            AocError::InputError(format!("Failed to read '{}' [{}]", path.display(), err))
+        })?;
+
+        Self::parse(&content, inputs)
+            .map_err(|err| AocError::InputError(format!("Invalid '{}' [{}]", path.display(), err)))
+    }
+
+    fn parse(content: &str, inputs: &Path) -> Result<Vec<ManifestEntry>, String> {
+        let days: BTreeMap<String, BTreeMap<String, ExpectedAnswers>> =
+            toml::from_str(content).map_err(|err| err.to_string().trim_end().to_string())?;
+
+        let mut entries = Vec::new();
+
+        for (day_key, answers) in days {
+            let day = Self::parse_day(&day_key)?;
+
+            for (name, expected) in answers {
+                let expected = [
+                    (Part::Part1, expected.part_1),
+                    (Part::Part2, expected.part_2),
+                ]
+                .into_iter()
+                .filter_map(|(part, answer)| answer.map(|answer| (part, answer)))
+                .collect();
+
+                entries.push(ManifestEntry {
+                    day,
+                    input: inputs.join(&day_key).join(format!("{}.txt", name)),
+                    name,
+                    expected,
+                });
+            }
+        }
+
+        Ok(entries)
+    }
+
+    fn parse_day(key: &str) -> Result<usize, String> {
+        key.strip_prefix("day_")
+            .and_then(|number| number.parse::<usize>().ok())
+            .ok_or_else(|| format!("Expected day key 'day_NN' but '{}' found", key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let content = r#"
+            [day_01.alice]
+            part_1 = "11"
+            part_2 = "31"
+
+            [day_01.bob]
+            part_1 = "12"
+
+            [day_17.alice]
+            part_1 = "4,6,3,5,6,3,5,2,1,0"
+        "#;
+
+        let entries = Manifest::parse(content, Path::new("inputs")).unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                ManifestEntry {
+                    day: 1,
+                    name: "alice".to_string(),
+                    input: PathBuf::from("inputs/day_01/alice.txt"),
+                    expected: vec![
+                        (Part::Part1, "11".to_string()),
+                        (Part::Part2, "31".to_string())
+                    ],
+                },
+                ManifestEntry {
+                    day: 1,
+                    name: "bob".to_string(),
+                    input: PathBuf::from("inputs/day_01/bob.txt"),
+                    expected: vec![(Part::Part1, "12".to_string())],
+                },
+                ManifestEntry {
+                    day: 17,
+                    name: "alice".to_string(),
+                    input: PathBuf::from("inputs/day_17/alice.txt"),
+                    expected: vec![(Part::Part1, "4,6,3,5,6,3,5,2,1,0".to_string())],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let inputs = Path::new("inputs");
+
+        assert!(Manifest::parse("[first.alice]\npart_1 = \"1\"", inputs).is_err());
+        assert!(Manifest::parse("[day_01.alice]\npart_3 = \"1\"", inputs).is_err());
+        assert!(Manifest::parse("[day_01.alice]\npart_1 = 1", inputs).is_err());
+    }
+}
@@ -0,0 +1,274 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc::{self, RecvTimeoutError},
+    time::{Duration, Instant},
+};
+
+use aoc_common::{
+    aoc_error::AocError, day::Day, day_config::DayConfig, input_source::InputSource,
+    normalization::Normalization, part::Part,
+};
+use rayon::ThreadPoolBuilder;
+
+use super::{day_report::DayReport, day_status::DayStatus, runner};
+
+enum Event {
+    Started(usize, Instant),
+    Finished(usize, Result<DayReport, String>),
+}
+
+/// Solve days concurrently on a thread pool, reports are returned in the order of days
+///
+/// A panic of a day is caught and reported. A thread cannot be stopped, so a day running longer
+/// than the timeout is reported as timed out while it keeps its thread until the process exits.
+pub fn solve_days(
+    days: Vec<(Day, InputSource, DayConfig)>,
+    parts: &[Part],
+    normalization: Normalization,
+    threads: Option<usize>,
+    timeout: Option<Duration>,
+) -> Result<Vec<DayReport>, AocError> {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(threads.unwrap_or_default())
+        .build()
+        .map_err(|err| AocError::ArgumentError(format!("Failed to create threads [{}]", err)))?;
+
+    let (sender, receiver) = mpsc::channel();
+
+    for (index, (day, input, config)) in days.iter().cloned().enumerate() {
+        let sender = sender.clone();
+        let parts = parts.to_vec();
+
+        pool.spawn(move || {
+            let _ = sender.send(Event::Started(index, Instant::now()));
+
+            let report = panic::catch_unwind(AssertUnwindSafe(|| {
+                runner::solve_day(&day, &input, &config, &parts, normalization)
+            }))
+            .map_err(|payload| panic_message(payload.as_ref()));
+
+            let _ = sender.send(Event::Finished(index, report));
+        });
+    }
+    drop(sender);
+
+    let mut started = vec![None; days.len()];
+    let mut reports: Vec<Option<DayReport>> = days.iter().map(|_| None).collect();
+    // Days which timed out but still occupy a thread
+    let mut stuck = 0;
+
+    while reports.iter().any(|report| report.is_none()) {
+        let now = Instant::now();
+
+        let deadlines = (0..days.len())
+            .filter(|&index| reports[index].is_none())
+            .filter_map(|index| Some((index, started[index]? + timeout?)))
+            .collect::<Vec<_>>();
+
+        for &(index, deadline) in &deadlines {
+            if deadline <= now {
+                let error = AocError::SolverError(format!(
+                    "Timed out after {} s",
+                    timeout.unwrap_or_default().as_secs_f64()
+                ));
+                reports[index] = Some(DayReport::interrupted(
+                    &days[index].0,
+                    parts,
+                    DayStatus::TimedOut,
+                    error,
+                ));
+                stuck += 1;
+            }
+        }
+
+        // Days waiting for a thread would never start if all threads are stuck
+        let running = deadlines.iter().any(|&(_, deadline)| deadline > now);
+        if stuck >= pool.current_num_threads() && !running {
+            for index in 0..days.len() {
+                if reports[index].is_none() {
+                    let error = AocError::SolverError(
+                        "Not started, all threads are taken by timed out days".to_string(),
+                    );
+                    reports[index] = Some(DayReport::interrupted(
+                        &days[index].0,
+                        parts,
+                        DayStatus::TimedOut,
+                        error,
+                    ));
+                }
+            }
+            break;
+        }
+
+        let event = match deadlines
+            .iter()
+            .map(|&(_, deadline)| deadline)
+            .filter(|&deadline| deadline > now)
+            .min()
+        {
+            Some(deadline) => receiver.recv_timeout(deadline - now),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match event {
+            Ok(Event::Started(index, instant)) => started[index] = Some(instant),
+            Ok(Event::Finished(index, report)) => match &reports[index] {
+                // A timed out day finished after all and released its thread
+                Some(_) => stuck -= 1,
+                None => {
+                    reports[index] = Some(report.unwrap_or_else(|message| {
+                        let error = AocError::SolverError(format!("Panicked [{}]", message));
+                        DayReport::interrupted(&days[index].0, parts, DayStatus::Panicked, error)
+                    }))
+                }
+            },
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    Ok(reports.into_iter().flatten().collect())
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, thread};
+
+    use aoc_common::{day_solver::DaySolver, explanation::Explanation, render::frame::Frame};
+
+    use super::*;
+
+    enum Behavior {
+        Solve,
+        Panic,
+        Sleep,
+    }
+
+    struct TestSolver {
+        behavior: Behavior,
+    }
+
+    impl DaySolver for TestSolver {
+        fn description(&self) -> String {
+            "--- Day 1: Test ---".to_string()
+        }
+
+        fn configure(&mut self, _config: &DayConfig) -> Result<(), AocError> {
+            Ok(())
+        }
+
+        fn parse(&mut self, _lines: &[&str]) -> Result<(), AocError> {
+            Ok(())
+        }
+
+        fn solve(&self, _part: Part) -> Result<String, AocError> {
+            match self.behavior {
+                Behavior::Solve => Ok("42".to_string()),
+                Behavior::Panic => panic!("Invalid digit"),
+                Behavior::Sleep => {
+                    thread::sleep(Duration::from_secs(2));
+                    Ok("42".to_string())
+                }
+            }
+        }
+
+        fn explain(&self, _part: Part) -> Result<Explanation, AocError> {
+            Err(AocError::ArgumentError("Not explainable".to_string()))
+        }
+
+        fn render(&self, _part: Part) -> Result<Vec<Frame>, AocError> {
+            Err(AocError::ArgumentError("Not renderable".to_string()))
+        }
+    }
+
+    fn day(number: usize, behavior: fn() -> Box<dyn DaySolver>) -> (Day, InputSource, DayConfig) {
+        let input = InputSource::File(PathBuf::from(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/Cargo.toml"
+        )));
+
+        (
+            Day::new(number, "test", "", None, behavior),
+            input,
+            DayConfig::default(),
+        )
+    }
+
+    fn solving() -> Box<dyn DaySolver> {
+        Box::new(TestSolver {
+            behavior: Behavior::Solve,
+        })
+    }
+
+    fn panicking() -> Box<dyn DaySolver> {
+        Box::new(TestSolver {
+            behavior: Behavior::Panic,
+        })
+    }
+
+    fn sleeping() -> Box<dyn DaySolver> {
+        Box::new(TestSolver {
+            behavior: Behavior::Sleep,
+        })
+    }
+
+    fn statuses(reports: &[DayReport]) -> Vec<(usize, DayStatus)> {
+        reports
+            .iter()
+            .map(|report| (report.number, report.status))
+            .collect()
+    }
+
+    #[test]
+    fn test_solve_days() {
+        let reports = solve_days(
+            vec![day(1, solving), day(2, panicking), day(3, sleeping)],
+            &[Part::Part1],
+            Normalization::Tolerant,
+            Some(3),
+            Some(Duration::from_millis(200)),
+        )
+        .unwrap();
+
+        assert_eq!(
+            statuses(&reports),
+            vec![
+                (1, DayStatus::Passed),
+                (2, DayStatus::Panicked),
+                (3, DayStatus::TimedOut)
+            ]
+        );
+        assert_eq!(
+            reports[1].answers[0].answer,
+            Err(AocError::SolverError(
+                "Panicked [Invalid digit]".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_solve_days_all_threads_stuck() {
+        let reports = solve_days(
+            vec![day(1, sleeping), day(2, solving)],
+            &[Part::Part1],
+            Normalization::Tolerant,
+            Some(1),
+            Some(Duration::from_millis(100)),
+        )
+        .unwrap();
+
+        assert_eq!(
+            statuses(&reports),
+            vec![(1, DayStatus::TimedOut), (2, DayStatus::TimedOut)]
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use aoc_common::{aoc_error::AocError, part::Part};
+
+use super::part_status::{PartStatus, NOT_SOLVED};
+
+pub struct PartAnswer {
+    pub part: Part,
+    pub answer: Result<String, AocError>,
+    // None if the part was not run at all, e.g. input could not be parsed
+    pub duration: Option<Duration>,
+}
+
+impl PartAnswer {
+    pub fn status(&self) -> PartStatus {
+        match &self.answer {
+            Ok(answer) if answer == NOT_SOLVED => PartStatus::NotSolved,
+            Ok(_) => PartStatus::Ok,
+            Err(_) => PartStatus::Error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(answer: Result<String, AocError>) -> PartAnswer {
+        PartAnswer {
+            part: Part::Part1,
+            answer,
+            duration: None,
+        }
+    }
+
+    #[test]
+    fn test_status() {
+        assert_eq!(answer(Ok("42".to_string())).status(), PartStatus::Ok);
+        assert_eq!(
+            answer(Ok(NOT_SOLVED.to_string())).status(),
+            PartStatus::NotSolved
+        );
+        assert_eq!(
+            answer(Err(AocError::SolverError("failed".to_string()))).status(),
+            PartStatus::Error
+        );
+    }
+}
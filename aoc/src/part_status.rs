@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+/// Answer returned by days which do not have a solution for a part yet
+pub const NOT_SOLVED: &str = "Not solved";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartStatus {
+    Ok,
+    Error,
+    NotSolved,
+}
@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+use aoc_common::part::Part;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [
+        Phase::Parse,
+        Phase::Solve(Part::Part1),
+        Phase::Solve(Part::Part2),
+    ];
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve(Part::Part1) => write!(f, "part_1"),
+            Phase::Solve(Part::Part2) => write!(f, "part_2"),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+}
@@ -0,0 +1,157 @@
+use std::time::{Duration, Instant};
+
+use aoc_common::{
+    aoc_error::AocError, day::Day, day_config::DayConfig, input_source::InputSource,
+    normalization::Normalization, part::Part,
+};
+
+use super::{
+    cli::RunArgs, day_report::DayReport, day_status::DayStatus, days, json_report,
+    output_format::OutputFormat, parallel_runner, part_answer::PartAnswer, table::Table,
+};
+
+pub fn run(args: &RunArgs) -> Result<bool, AocError> {
+    let days = args
+        .day
+        .select_with_input(days::all_days(), args.input.as_deref())?;
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let configs = days
+        .iter()
+        .map(|(day, _)| args.day.load_config(day, &args.config))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let normalization = Normalization::from_strict(args.strict);
+
+    let reports = match args.parallel {
+        true => parallel_runner::solve_days(
+            days.into_iter()
+                .zip(configs)
+                .map(|((day, input), config)| (day, input, config))
+                .collect(),
+            &parts,
+            normalization,
+            args.jobs,
+            args.timeout.map(Duration::from_secs),
+        )?,
+        false => days
+            .iter()
+            .zip(&configs)
+            .map(|((day, input), config)| solve_day(day, input, config, &parts, normalization))
+            .collect(),
+    };
+
+    match args.format {
+        OutputFormat::Text => print_reports(&reports, &parts),
+        OutputFormat::Json => println!("{}", json_report::to_json_lines(&reports)?),
+    }
+
+    Ok(reports
+        .iter()
+        .all(|report| report.status == DayStatus::Passed))
+}
+
+pub fn solve_day(
+    day: &Day,
+    input: &InputSource,
+    config: &DayConfig,
+    parts: &[Part],
+    normalization: Normalization,
+) -> DayReport {
+    let mut solver = day.create_solver();
+    let description = solver.description();
+
+    let start = Instant::now();
+    let parsed = solver
+        .configure(config)
+        .and_then(|_| input.parse_into(solver.as_mut(), normalization));
+    let parse_duration = start.elapsed();
+
+    // If configuration or input is invalid all requested parts share the same error
+    let answers: Vec<PartAnswer> = match parsed {
+        Ok(()) => parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = solver.solve(part);
+
+                PartAnswer {
+                    part,
+                    answer,
+                    duration: Some(start.elapsed()),
+                }
+            })
+            .collect(),
+        Err(err) => parts
+            .iter()
+            .map(|&part| PartAnswer {
+                part,
+                answer: Err(err.clone()),
+                duration: None,
+            })
+            .collect(),
+    };
+
+    let status = match answers.iter().all(|answer| answer.answer.is_ok()) {
+        true => DayStatus::Passed,
+        false => DayStatus::Failed,
+    };
+
+    DayReport {
+        number: day.get_number(),
+        name: day.get_name(),
+        description,
+        parse_duration,
+        status,
+        answers,
+    }
+}
+
+fn print_reports(reports: &[DayReport], parts: &[Part]) {
+    let mut headers = vec!["Day".to_string(), "Puzzle".to_string()];
+    headers.extend(parts.iter().map(|part| part.to_string()));
+    headers.push("Status".to_string());
+
+    let mut table = Table::new(headers);
+    let mut errors = Vec::new();
+
+    for report in reports {
+        let mut row = vec![report.number.to_string(), report.title().to_string()];
+
+        for answer in &report.answers {
+            match &answer.answer {
+                Ok(answer) => row.push(answer.clone()),
+                Err(err) => {
+                    row.push("ERROR".to_string());
+                    errors.push(format!("Day {} {}: {}", report.number, answer.part, err));
+                }
+            }
+        }
+
+        row.push(report.status.to_string());
+        table.push_row(row);
+    }
+
+    print!("{}", table);
+
+    let summary = DayStatus::ALL
+        .iter()
+        .map(|status| {
+            let count = reports
+                .iter()
+                .filter(|report| report.status == *status)
+                .count();
+            format!("{} {}", count, status)
+        })
+        .collect::<Vec<_>>();
+    println!("Days: {}", summary.join(", "));
+
+    // Errors may be long, print them below the table
+    for error in errors {
+        eprintln!("{}", error);
+    }
+}
@@ -0,0 +1,404 @@
+use std::{
+    fs,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::aoc_error::AocError;
+
+use super::cli::ScaffoldArgs;
+
+// Files of a new day crate, placeholders are replaced by the crate name, day number and title
+const TEMPLATES: [(&str, &str); 9] = [
+    (
+        "Cargo.toml",
+        include_str!("../templates/day/Cargo.toml.template"),
+    ),
+    (
+        "src/lib.rs",
+        include_str!("../templates/day/lib.rs.template"),
+    ),
+    (
+        "src/main.rs",
+        include_str!("../templates/day/main.rs.template"),
+    ),
+    (
+        "src/puzzle/mod.rs",
+        include_str!("../templates/day/mod.rs.template"),
+    ),
+    (
+        "src/puzzle/parser.rs",
+        include_str!("../templates/day/parser.rs.template"),
+    ),
+    (
+        "src/puzzle/solver.rs",
+        include_str!("../templates/day/solver.rs.template"),
+    ),
+    ("resources/input.txt", ""),
+    ("resources/example.txt", ""),
+    (
+        "resources/example.expected",
+        include_str!("../templates/day/example.expected.template"),
+    ),
+];
+
+pub fn scaffold(args: &ScaffoldArgs) -> Result<bool, AocError> {
+    let directory = scaffold_into(&args.workspace, args.day, &args.name)?;
+    println!("Created '{}'", directory.display());
+
+    Ok(true)
+}
+
+/// Create the day crate and register it in the workspace, the runner dependencies and `days.rs`
+fn scaffold_into(workspace: &Path, number: usize, name: &str) -> Result<PathBuf, AocError> {
+    let (crate_name, title) = crate_name_and_title(number, name)?;
+
+    let directory = workspace.join(&crate_name);
+    if directory.exists() {
+        return Err(AocError::ArgumentError(format!(
+            "'{}' already exists",
+            directory.display()
+        )));
+    }
+
+    // Registrations are prepared first so that an invalid file stops before anything is written
+    let registrations = [
+        (workspace.join("Cargo.toml"), register_member as Register),
+        (workspace.join("aoc/Cargo.toml"), |manifest, crate_name| {
+            register_dependency(manifest, crate_name)
+                .and_then(|manifest| register_feature(&manifest, crate_name))
+        }),
+        (workspace.join("aoc/src/days.rs"), register_day),
+    ]
+    .into_iter()
+    .map(|(path, register)| {
+        let original = read(&path)?;
+        register(&original, &crate_name)
+            .map(|content| (path.clone(), original, content))
+            .map_err(|err| AocError::InputError(format!("Invalid '{}' [{}]", path.display(), err)))
+    })
+    .collect::<Result<Vec<_>, _>>()?;
+
+    let written = TEMPLATES
+        .iter()
+        .try_for_each(|(file, template)| {
+            let content = template
+                .replace("__CRATE__", &crate_name)
+                .replace("__NUMBER__", &number.to_string())
+                .replace("__TITLE__", &title);
+
+            write(&directory.join(file), &content)
+        })
+        .and_then(|_| {
+            registrations
+                .iter()
+                .try_for_each(|(path, _, content)| write(path, content))
+        });
+
+    // A failed write is rolled back so that the command can be run again
+    if let Err(err) = written {
+        for (path, original, _) in &registrations {
+            let _ = fs::write(path, original);
+        }
+        let _ = fs::remove_dir_all(&directory);
+
+        return Err(err);
+    }
+
+    Ok(directory)
+}
+
+type Register = fn(&str, &str) -> Result<String, String>;
+
+/// Crate name `day_NN_words_of_name` and puzzle title with capitalized words
+fn crate_name_and_title(number: usize, name: &str) -> Result<(String, String), AocError> {
+    if !(1..=25).contains(&number) {
+        return Err(AocError::ArgumentError(format!(
+            "Day should be between 1 and 25 but '{}' found",
+            number
+        )));
+    }
+
+    let words = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>();
+
+    if words.is_empty() {
+        return Err(AocError::ArgumentError(format!(
+            "Name should contain at least one letter or digit but '{}' found",
+            name
+        )));
+    }
+
+    let crate_name = format!("day_{:02}_{}", number, words.join("_").to_lowercase());
+    let title = words
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    Ok((crate_name, title))
+}
+
+fn register_member(manifest: &str, crate_name: &str) -> Result<String, String> {
+    const MEMBERS: &str = "members = [";
+
+    let start = manifest
+        .find(MEMBERS)
+        .ok_or_else(|| format!("'{}' not found", MEMBERS))?;
+    let end = manifest[start..]
+        .find(']')
+        .map(|end| start + end)
+        .ok_or_else(|| "Members are not terminated by ']'".to_string())?;
+
+    let mut members = manifest[start + MEMBERS.len()..end]
+        .split(',')
+        .map(|member| member.trim())
+        .filter(|member| !member.is_empty())
+        .collect::<Vec<_>>();
+
+    // Only one crate may exist per day
+    let prefix = format!("\"{}", &crate_name[..7]);
+    if let Some(member) = members.iter().find(|member| member.starts_with(&prefix)) {
+        return Err(format!("Day is already registered as {}", member));
+    }
+
+    let member = format!("\"{}\"", crate_name);
+    members.push(&member);
+    members.sort();
+
+    Ok(format!(
+        "{}{}\n    {}\n{}",
+        &manifest[..start],
+        MEMBERS,
+        members.join(",\n    "),
+        &manifest[end..]
+    ))
+}
+
+fn register_dependency(manifest: &str, crate_name: &str) -> Result<String, String> {
+    let lines = manifest.lines().collect::<Vec<_>>();
+
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == "[dependencies]")
+        .ok_or_else(|| "'[dependencies]' not found".to_string())?
+        + 1;
+    let end = lines[start..]
+        .iter()
+        .position(|line| line.trim().is_empty() || line.starts_with('['))
+        .map_or(lines.len(), |end| start + end);
+
+    let dependency = format!("{} = {{ path = \"../{}\" }}", crate_name, crate_name);
+    Ok(insert_sorted(&lines, start..end, &dependency))
+}
+
+// The runner forwards its feature `embedded-input` to every day
+fn register_feature(manifest: &str, crate_name: &str) -> Result<String, String> {
+    const FEATURE: &str = "embedded-input = [";
+
+    let lines = manifest.lines().collect::<Vec<_>>();
+
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == FEATURE)
+        .ok_or_else(|| format!("'{}' not found", FEATURE))?
+        + 1;
+    let end = lines[start..]
+        .iter()
+        .position(|line| line.trim() == "]")
+        .map(|end| start + end)
+        .ok_or_else(|| "Feature 'embedded-input' is not terminated by ']'".to_string())?;
+
+    let feature = format!("    \"{}/embedded-input\",", crate_name);
+    Ok(insert_sorted(&lines, start..end, &feature))
+}
+
+fn register_day(days: &str, crate_name: &str) -> Result<String, String> {
+    let lines = days.lines().collect::<Vec<_>>();
+
+    let is_day = |line: &&str| line.trim().starts_with("day_") && line.ends_with("::day(),");
+    let start = lines
+        .iter()
+        .position(is_day)
+        .ok_or_else(|| "No day found".to_string())?;
+    let end = lines.len() - lines.iter().rev().position(is_day).unwrap_or_default();
+
+    let day = format!("        {}::day(),", crate_name);
+    Ok(insert_sorted(&lines, start..end, &day))
+}
+
+// Insert the line into sorted lines of the range, lines are compared without indentation
+fn insert_sorted(lines: &[&str], range: Range<usize>, line: &str) -> String {
+    let index = lines[range.clone()]
+        .iter()
+        .position(|current| current.trim() > line.trim())
+        .map_or(range.end, |index| range.start + index);
+
+    let mut lines = lines.to_vec();
+    lines.insert(index, line);
+
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+fn read(path: &Path) -> Result<String, AocError> {
+    fs::read_to_string(path).map_err(|err| {
+        AocError::InputError(format!("Failed to read '{}' [{}]", path.display(), err))
+    })
+}
+
+fn write(path: &Path, content: &str) -> Result<(), AocError> {
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, content))
+        .map_err(|err| {
+            AocError::InputError(format!("Failed to write '{}' [{}]", path.display(), err))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const DAYS: &str = "pub fn all_days() -> Vec<Day> {
+    vec![
+        day_01_historian_hysteria::day(),
+        day_20_race_condition::day(),
+    ]
+}
+";
+
+    #[test]
+    fn test_crate_name_and_title() {
+        assert_eq!(
+            crate_name_and_title(21, "Keypad Conundrum").ok(),
+            Some((
+                "day_21_keypad_conundrum".to_string(),
+                "Keypad Conundrum".to_string()
+            ))
+        );
+        assert_eq!(
+            crate_name_and_title(3, "ram_run").ok(),
+            Some(("day_03_ram_run".to_string(), "Ram Run".to_string()))
+        );
+        assert!(crate_name_and_title(26, "Name").is_err());
+        assert!(crate_name_and_title(21, " - ").is_err());
+    }
+
+    #[test]
+    fn test_register_member() {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_20_race_condition\"\n]";
+
+        assert_eq!(
+            register_member(manifest, "day_03_mull_it_over"),
+            Ok(
+                "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_03_mull_it_over\",\n    \
+                \"day_20_race_condition\"\n]"
+                    .to_string()
+            )
+        );
+        assert!(register_member(manifest, "day_20_other").is_err());
+        assert!(register_member("[workspace]\n", "day_03_mull_it_over").is_err());
+    }
+
+    #[test]
+    fn test_register_dependency() {
+        let manifest = "[dependencies]\naoc_common = { path = \"../aoc_common\" }\n\
+            rand = { version = \"0.8.*\" }\n";
+
+        assert_eq!(
+            register_dependency(manifest, "day_21_keypad_conundrum"),
+            Ok(
+                "[dependencies]\naoc_common = { path = \"../aoc_common\" }\n\
+                day_21_keypad_conundrum = { path = \"../day_21_keypad_conundrum\" }\n\
+                rand = { version = \"0.8.*\" }\n"
+                    .to_string()
+            )
+        );
+        assert!(register_dependency("[package]\n", "day_21_keypad_conundrum").is_err());
+    }
+
+    #[test]
+    fn test_register_feature() {
+        let manifest =
+            "[features]\nembedded-input = [\n    \"day_18_ram_run/embedded-input\",\n]\n";
+
+        assert_eq!(
+            register_feature(manifest, "day_21_keypad_conundrum"),
+            Ok(
+                "[features]\nembedded-input = [\n    \"day_18_ram_run/embedded-input\",\n    \
+                \"day_21_keypad_conundrum/embedded-input\",\n]\n"
+                    .to_string()
+            )
+        );
+        assert!(register_feature("[features]\n", "day_21_keypad_conundrum").is_err());
+    }
+
+    #[test]
+    fn test_register_day() {
+        let days = register_day(DAYS, "day_21_keypad_conundrum").unwrap();
+        assert!(days.contains(
+            "day_20_race_condition::day(),\n        day_21_keypad_conundrum::day(),\n    ]"
+        ));
+
+        let days = register_day(DAYS, "day_02_rednosed_reports").unwrap();
+        assert!(days.contains(
+            "day_01_historian_hysteria::day(),\n        day_02_rednosed_reports::day(),\n"
+        ));
+    }
+
+    #[test]
+    fn test_scaffold_into() {
+        let workspace = env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&workspace);
+
+        write(
+            &workspace.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\"\n]\n",
+        )
+        .unwrap();
+        write(
+            &workspace.join("aoc/Cargo.toml"),
+            "[dependencies]\naoc_common = { path = \"../aoc_common\" }\n\n\
+                [features]\nembedded-input = [\n]\n",
+        )
+        .unwrap();
+        write(&workspace.join("aoc/src/days.rs"), DAYS).unwrap();
+
+        let directory = scaffold_into(&workspace, 21, "Keypad Conundrum").unwrap();
+        assert_eq!(directory, workspace.join("day_21_keypad_conundrum"));
+
+        let solver = read(&directory.join("src/puzzle/solver.rs")).unwrap();
+        assert!(solver.contains("\"--- Day 21: Keypad Conundrum ---\""));
+        assert!(solver.contains("impl Parsable for Solver"));
+        assert!(!solver.contains("__"));
+
+        let lib = read(&directory.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("export_day!(21, Solver, parsable);"));
+
+        let parser = read(&directory.join("src/puzzle/parser.rs")).unwrap();
+        assert!(parser.contains("Result<Vec<String>, ParseError>"));
+        assert!(parser.contains(".with_line(index)"));
+
+        let main = read(&directory.join("src/main.rs")).unwrap();
+        assert!(main.contains("day_21_keypad_conundrum::day()"));
+        assert!(directory.join("resources/input.txt").exists());
+
+        let runner = read(&workspace.join("aoc/Cargo.toml")).unwrap();
+        assert!(runner.contains("    \"day_21_keypad_conundrum/embedded-input\",\n]"));
+
+        // The same day cannot be created twice
+        assert!(scaffold_into(&workspace, 21, "Keypad Conundrum").is_err());
+
+        fs::remove_dir_all(&workspace).unwrap();
+    }
+}
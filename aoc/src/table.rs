@@ -0,0 +1,92 @@
+use std::fmt::Display;
+
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: Vec<String>) -> Self {
+        Self {
+            headers,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push_row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    fn column_widths(&self) -> Vec<usize> {
+        self.headers
+            .iter()
+            .enumerate()
+            .map(|(column, header)| {
+                self.rows
+                    .iter()
+                    .filter_map(|row| row.get(column))
+                    .map(|cell| cell.chars().count())
+                    .chain([header.chars().count()])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    fn format_row(row: &[String], widths: &[usize]) -> String {
+        widths
+            .iter()
+            .enumerate()
+            .map(|(column, width)| {
+                let cell = row.get(column).map(|cell| cell.as_str()).unwrap_or("");
+                format!(" {:<width$} ", cell, width = width)
+            })
+            .collect::<Vec<_>>()
+            .join("|")
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let widths = self.column_widths();
+
+        writeln!(f, "{}", Self::format_row(&self.headers, &widths))?;
+
+        let separator = widths
+            .iter()
+            .map(|width| "-".repeat(width + 2))
+            .collect::<Vec<_>>()
+            .join("+");
+        writeln!(f, "{}", separator)?;
+
+        for row in &self.rows {
+            writeln!(f, "{}", Self::format_row(row, &widths))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let mut table = Table::new(vec!["Day".to_string(), "Answer".to_string()]);
+        table.push_row(vec!["1".to_string(), "42".to_string()]);
+        table.push_row(vec!["20".to_string(), "1030809".to_string()]);
+
+        assert_eq!(
+            table.to_string(),
+            [
+                " Day | Answer  ",
+                "-----+---------",
+                " 1   | 42      ",
+                " 20  | 1030809 ",
+                ""
+            ]
+            .join("\n")
+        );
+    }
+}
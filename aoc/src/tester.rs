@@ -0,0 +1,40 @@
+use aoc_common::{aoc_error::AocError, example_tester::ExampleTester};
+
+use super::{cli::TestArgs, days, table::Table};
+
+pub fn test(args: &TestArgs) -> Result<bool, AocError> {
+    let days = args.day.select(days::all_days())?;
+
+    let mut table = Table::new(
+        ["Day", "Example", "Part", "Expected", "Actual", "Status"]
+            .iter()
+            .map(|header| header.to_string())
+            .collect(),
+    );
+    let mut success = true;
+
+    for day in days {
+        for result in ExampleTester::new(day).run()? {
+            let actual = match &result.actual {
+                Ok(actual) => actual.clone(),
+                Err(err) => err.to_string(),
+            };
+            let status = if result.is_ok() { "OK" } else { "FAILED" };
+
+            success &= result.is_ok();
+
+            table.push_row(vec![
+                day.get_number().to_string(),
+                result.example.clone(),
+                result.part.to_string(),
+                result.expected.clone(),
+                actual,
+                status.to_string(),
+            ]);
+        }
+    }
+
+    print!("{}", table);
+
+    Ok(success)
+}
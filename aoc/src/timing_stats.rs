@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimingStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl TimingStats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        // For even count of samples the median is the mean of the two middle ones
+        let middle = sorted.len() / 2;
+        let median = match sorted.len() % 2 {
+            0 => (sorted[middle - 1] + sorted[middle]) / 2,
+            _ => sorted[middle],
+        };
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        })
+    }
+}
+
+pub fn as_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_samples() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(TimingStats::from_samples(&[]), None);
+        assert_eq!(
+            TimingStats::from_samples(&[ms(5), ms(1), ms(3)]),
+            Some(TimingStats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5)
+            })
+        );
+        assert_eq!(
+            TimingStats::from_samples(&[ms(4), ms(1), ms(2), ms(8)]),
+            Some(TimingStats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(8)
+            })
+        );
+    }
+}
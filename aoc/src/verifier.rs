@@ -0,0 +1,172 @@
+use std::path::Path;
+
+use aoc_common::{
+    aoc_error::AocError, day_config::DayConfig, input_source::InputSource,
+    normalization::Normalization, part::Part,
+};
+
+use super::{
+    cli::VerifyArgs,
+    day_selection::DaySelection,
+    days,
+    manifest::{Manifest, ManifestEntry},
+    runner,
+};
+
+struct Mismatch {
+    day: usize,
+    name: String,
+    part: Part,
+    expected: String,
+    actual: Result<String, AocError>,
+}
+
+pub fn verify(args: &VerifyArgs) -> Result<bool, AocError> {
+    // Inputs are stored next to the manifest unless specified otherwise
+    let inputs = match &args.inputs {
+        Some(inputs) => inputs.as_path(),
+        None => args.manifest.parent().unwrap_or(Path::new("")),
+    };
+
+    let entries = Manifest::load(&args.manifest, inputs)?;
+
+    let mut checked = 0;
+    let mut mismatches = Vec::new();
+
+    for entry in &entries {
+        checked += entry.expected.len();
+        mismatches.extend(verify_entry(entry, Normalization::from_strict(args.strict)));
+    }
+
+    print_mismatches(&mismatches);
+
+    println!(
+        "Verified {} answers of {} inputs: {} passed, {} failed",
+        checked,
+        entries.len(),
+        checked - mismatches.len(),
+        mismatches.len()
+    );
+
+    Ok(mismatches.is_empty())
+}
+
+fn verify_entry(entry: &ManifestEntry, normalization: Normalization) -> Vec<Mismatch> {
+    let mismatch = |(part, expected): &(Part, String), actual| Mismatch {
+        day: entry.day,
+        name: entry.name.clone(),
+        part: *part,
+        expected: expected.clone(),
+        actual,
+    };
+
+    // A day without a solver fails its answers, the rest of the manifest is still verified
+    let day = match DaySelection::Single(entry.day).select(days::all_days()) {
+        Ok(days) => days[0],
+        Err(err) => {
+            return entry
+                .expected
+                .iter()
+                .map(|expected| mismatch(expected, Err(err.clone())))
+                .collect()
+        }
+    };
+
+    let parts = entry
+        .expected
+        .iter()
+        .map(|(part, _)| *part)
+        .collect::<Vec<_>>();
+
+    let report = runner::solve_day(
+        &day,
+        &InputSource::File(entry.input.clone()),
+        &DayConfig::default(),
+        &parts,
+        normalization,
+    );
+
+    entry
+        .expected
+        .iter()
+        .zip(report.answers)
+        .filter(
+            |((_, expected), answer)| !matches!(&answer.answer, Ok(actual) if actual == expected),
+        )
+        .map(|(expected, answer)| mismatch(expected, answer.answer))
+        .collect()
+}
+
+// Mismatches are printed as unified diff hunks so the output can be read by the usual tools
+fn print_mismatches(mismatches: &[Mismatch]) {
+    if mismatches.is_empty() {
+        return;
+    }
+
+    println!("--- expected");
+    println!("+++ actual");
+
+    for mismatch in mismatches {
+        println!(
+            "@@ day_{:02}/{} {} @@",
+            mismatch.day, mismatch.name, mismatch.part
+        );
+        println!("-{}", mismatch.expected);
+
+        match &mismatch.actual {
+            Ok(actual) => println!("+{}", actual),
+            Err(err) => {
+                for line in format!("error: {}", err).lines() {
+                    println!("+{}", line);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn entry(day: usize, expected: &[(Part, &str)]) -> ManifestEntry {
+        ManifestEntry {
+            day,
+            name: "input.txt".to_string(),
+            input: PathBuf::from(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../day_01_historian_hysteria/resources/example.txt"
+            )),
+            expected: expected
+                .iter()
+                .map(|(part, answer)| (*part, answer.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_verify_entry() {
+        let mismatches = verify_entry(
+            &entry(1, &[(Part::Part1, "11"), (Part::Part2, "32")]),
+            Normalization::Tolerant,
+        );
+
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].part, Part::Part2);
+        assert_eq!(mismatches[0].actual, Ok("31".to_string()));
+    }
+
+    #[test]
+    fn test_verify_entry_missing_day() {
+        let mismatches = verify_entry(
+            &entry(25, &[(Part::Part1, "1"), (Part::Part2, "2")]),
+            Normalization::Tolerant,
+        );
+
+        assert_eq!(mismatches.len(), 2);
+        assert!(mismatches
+            .iter()
+            .all(|mismatch| matches!(mismatch.actual, Err(AocError::ArgumentError(_)))));
+    }
+}
@@ -0,0 +1,93 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+};
+
+use aoc_common::{
+    aoc_error::AocError,
+    normalization::Normalization,
+    part::Part,
+    render::{frame::Frame, render_format::RenderFormat, renderer},
+};
+
+use super::{cli::RenderArgs, day_selection::DaySelection, days};
+
+pub fn render(args: &RenderArgs) -> Result<bool, AocError> {
+    let selection = DaySelection::Single(args.day);
+    let (day, input) = selection
+        .select_with_input(days::all_days(), args.input.as_deref())?
+        .remove(0);
+
+    let mut solver = day.create_solver();
+    solver.configure(&selection.load_config(&day, &args.config)?)?;
+    input.parse_into(solver.as_mut(), Normalization::from_strict(args.strict))?;
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let mut frames = Vec::new();
+    for part in parts {
+        frames.extend(solver.render(part)?.into_iter().map(|frame| (part, frame)));
+    }
+
+    match &args.output {
+        Some(directory) => {
+            fs::create_dir_all(directory).map_err(|err| {
+                AocError::InputError(format!(
+                    "Failed to create '{}' [{}]",
+                    directory.display(),
+                    err
+                ))
+            })?;
+
+            for (index, (part, frame)) in frames.iter().enumerate() {
+                let path = directory.join(format!(
+                    "day_{:02}_part_{}_{:04}.{}",
+                    day.get_number(),
+                    part.get_number(),
+                    index + 1,
+                    args.format.get_extension()
+                ));
+
+                let file = File::create(&path).map_err(|err| {
+                    AocError::InputError(format!("Failed to create '{}' [{}]", path.display(), err))
+                })?;
+
+                write_frame(frame, args, &mut BufWriter::new(file))?;
+            }
+        }
+        None => {
+            // Concatenated PNG images cannot be decoded, unlike text or a PPM stream
+            if args.format == RenderFormat::Png && frames.len() > 1 {
+                return Err(AocError::ArgumentError(format!(
+                    "{} PNG frames have to be written to a directory with --output",
+                    frames.len()
+                )));
+            }
+
+            let mut stdout = io::stdout().lock();
+            for (_, frame) in frames.iter() {
+                // Plain text does not contain the title, unlike the ANSI format
+                if args.format == RenderFormat::Text {
+                    writeln!(stdout, "{}", frame.get_title()).map_err(|err| {
+                        AocError::SolverError(format!("Failed to write a frame [{}]", err))
+                    })?;
+                }
+
+                write_frame(frame, args, &mut stdout)?;
+            }
+        }
+    }
+
+    Ok(true)
+}
+
+fn write_frame(frame: &Frame, args: &RenderArgs, writer: &mut dyn Write) -> Result<(), AocError> {
+    renderer::render(frame, args.format, args.scale, writer)?;
+
+    writer
+        .flush()
+        .map_err(|err| AocError::SolverError(format!("Failed to write a frame [{}]", err)))
+}
@@ -0,0 +1,48 @@
+use std::fmt::Display;
+
+use super::parse_error::ParseError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AocError {
+    ArgumentError(String),
+    InputError(String),
+    ParseError(ParseError),
+    SolverError(String),
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::ArgumentError(message) => write!(f, "Invalid argument: {}", message),
+            AocError::InputError(message) => write!(f, "Invalid input: {}", message),
+            AocError::ParseError(err) => match err.get_input() {
+                Some(input) => write!(f, "Invalid input: Failed to parse '{}'\n{}", input, err),
+                None => write!(f, "Invalid input: {}", err),
+            },
+            AocError::SolverError(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_parse_error() {
+        let error = ParseError::new("Invalid number")
+            .with_span("1 x", 2..3)
+            .with_line(0);
+
+        assert_eq!(
+            AocError::ParseError(error.clone()).to_string(),
+            format!("Invalid input: {}", error)
+        );
+        assert_eq!(
+            AocError::ParseError(error.clone().with_input("input.txt")).to_string(),
+            format!("Invalid input: Failed to parse 'input.txt'\n{}", error)
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use super::{aoc_error::AocError, day_config::DayConfig};
+
+// Command line arguments overriding puzzle constants of days
+#[derive(Args)]
+pub struct ConfigArgs {
+    /// TOML file with '[day_NN]' tables overriding puzzle constants
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Override a puzzle constant, e.g. 'rows=7', applied after the config file
+    #[arg(long = "set", value_name = "KEY=VALUE")]
+    pub overrides: Vec<String>,
+}
+
+impl ConfigArgs {
+    pub fn load(&self, day: usize) -> Result<DayConfig, AocError> {
+        let mut config = match &self.config {
+            Some(path) => DayConfig::load(path, day)?,
+            None => DayConfig::default(),
+        };
+
+        for assignment in &self.overrides {
+            config.set(assignment)?;
+        }
+
+        Ok(config)
+    }
+}
@@ -0,0 +1,18 @@
+use serde::de::DeserializeOwned;
+
+use super::{aoc_error::AocError, day_config::DayConfig};
+
+/// Solver with puzzle constants which can be overridden
+///
+/// Days exported by `export_day!(N, Solver, configurable)` deserialize their `DayConfig` into
+/// `Config`, fields which are not set keep their defaults.
+pub trait Configurable {
+    type Config: DeserializeOwned;
+
+    fn set_config(&mut self, config: Self::Config);
+}
+
+pub fn configure<T: Configurable>(solver: &mut T, config: &DayConfig) -> Result<(), AocError> {
+    solver.set_config(config.to_config()?);
+    Ok(())
+}
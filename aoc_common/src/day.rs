@@ -0,0 +1,60 @@
+use std::path::{Path, PathBuf};
+
+use super::{day_solver::DaySolver, input_source::InputSource};
+
+pub type DaySolverFactory = fn() -> Box<dyn DaySolver>;
+
+#[derive(Clone, Copy)]
+pub struct Day {
+    number: usize,
+    name: &'static str,
+    resources: &'static str,
+    embedded_input: Option<&'static str>,
+    factory: DaySolverFactory,
+}
+
+impl Day {
+    pub fn new(
+        number: usize,
+        name: &'static str,
+        resources: &'static str,
+        embedded_input: Option<&'static str>,
+        factory: DaySolverFactory,
+    ) -> Self {
+        Self {
+            number,
+            name,
+            resources,
+            embedded_input,
+            factory,
+        }
+    }
+
+    pub fn get_number(&self) -> usize {
+        self.number
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn get_resources(&self) -> &'static Path {
+        Path::new(self.resources)
+    }
+
+    pub fn resource_file(&self, file_name: &str) -> PathBuf {
+        PathBuf::from(self.resources).join(file_name)
+    }
+
+    /// Input embedded at compile time by feature `embedded-input`, otherwise `resources/input.txt`
+    pub fn default_input(&self) -> InputSource {
+        match self.embedded_input {
+            Some(content) => InputSource::Embedded(content),
+            None => InputSource::File(self.resource_file("input.txt")),
+        }
+    }
+
+    pub fn create_solver(&self) -> Box<dyn DaySolver> {
+        (self.factory)()
+    }
+}
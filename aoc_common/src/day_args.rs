@@ -0,0 +1,18 @@
+use clap::Parser;
+
+use super::config_args::ConfigArgs;
+
+// Command line arguments shared by every day binary
+#[derive(Parser)]
+pub struct DayArgs {
+    /// Puzzle input file, '-' reads from stdin [default: $AOC_INPUT or the input of the day]
+    #[arg(long, value_name = "PATH")]
+    pub input: Option<String>,
+
+    /// Report irregular input, e.g. CRLF line endings or a BOM, instead of normalizing it
+    #[arg(long)]
+    pub strict: bool,
+
+    #[command(flatten)]
+    pub config: ConfigArgs,
+}
@@ -0,0 +1,142 @@
+use std::{fs, path::Path};
+
+use serde::de::DeserializeOwned;
+use toml::{Table, Value};
+
+use super::aoc_error::AocError;
+
+/// Overrides of puzzle constants of a single day
+///
+/// Values are kept as a TOML table and deserialized by the day into its own `Config`, keys which
+/// are not set keep the day's defaults. A config file contains one table per day:
+///
+/// ```toml
+/// [day_18]
+/// rows = 7
+/// cols = 7
+/// corrupted_bytes = 12
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DayConfig {
+    values: Table,
+}
+
+impl DayConfig {
+    /// Load table `[day_NN]` from the config file, a missing table means no overrides
+    pub fn load(path: &Path, day: usize) -> Result<Self, AocError> {
+        let content = fs::read_to_string(path).map_err(|err| {
+            AocError::InputError(format!("Failed to read '{}' [{}]", path.display(), err))
+        })?;
+
+        Self::parse(&content, day)
+            .map_err(|err| AocError::InputError(format!("Invalid '{}' [{}]", path.display(), err)))
+    }
+
+    fn parse(content: &str, day: usize) -> Result<Self, String> {
+        let mut days: Table =
+            toml::from_str(content).map_err(|err| err.to_string().trim_end().to_string())?;
+
+        let key = format!("day_{:02}", day);
+
+        match days.remove(&key) {
+            None => Ok(Self::default()),
+            Some(Value::Table(values)) => Ok(Self { values }),
+            Some(_) => Err(format!("'{}' has to be a table", key)),
+        }
+    }
+
+    /// Set a single `key=value` override, the value uses TOML syntax, e.g. `rows=7`
+    pub fn set(&mut self, assignment: &str) -> Result<(), AocError> {
+        let (key, value) = assignment.split_once('=').ok_or_else(|| {
+            AocError::ArgumentError(format!("Expected 'KEY=VALUE' but '{}' found", assignment))
+        })?;
+
+        let table: Table =
+            toml::from_str(&format!("{} = {}", key.trim(), value.trim())).map_err(|_| {
+                AocError::ArgumentError(format!(
+                    "Expected 'KEY=VALUE' with a TOML value but '{}' found",
+                    assignment
+                ))
+            })?;
+
+        self.values.extend(table);
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Deserialize overrides into the day's `Config`, unknown keys are reported as errors
+    pub fn to_config<T: DeserializeOwned>(&self) -> Result<T, AocError> {
+        Value::Table(self.values.clone())
+            .try_into()
+            .map_err(|err: toml::de::Error| {
+                AocError::ArgumentError(format!(
+                    "Invalid configuration [{}]",
+                    err.message().trim_end()
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct Config {
+        rows: usize,
+        cols: usize,
+    }
+
+    impl Default for Config {
+        fn default() -> Self {
+            Self { rows: 71, cols: 71 }
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let content = "[day_18]\nrows = 7\n\n[day_20]\nmin_saving = 50\n";
+
+        let config = DayConfig::parse(content, 18).unwrap();
+        assert_eq!(config.to_config(), Ok(Config { rows: 7, cols: 71 }));
+
+        assert_eq!(DayConfig::parse(content, 1), Ok(DayConfig::default()));
+        assert!(DayConfig::parse("day_18 = 7", 18).is_err());
+        assert!(DayConfig::parse("[day_18", 18).is_err());
+    }
+
+    #[test]
+    fn test_set() {
+        let mut config = DayConfig::default();
+        assert_eq!(config.to_config(), Ok(Config::default()));
+
+        assert!(config.set("rows=7").is_ok());
+        assert!(config.set(" cols = 9 ").is_ok());
+        assert!(config.set("rows=11").is_ok());
+        assert_eq!(config.to_config(), Ok(Config { rows: 11, cols: 9 }));
+
+        assert!(config.set("rows").is_err());
+        assert!(config.set("rows=").is_err());
+        assert!(config.set("rows=7 cols=7").is_err());
+    }
+
+    #[test]
+    fn test_to_config_invalid() {
+        let mut config = DayConfig::default();
+        config.set("rowz=7").unwrap();
+        assert!(matches!(
+            config.to_config::<Config>(),
+            Err(AocError::ArgumentError(message)) if message.contains("rowz")
+        ));
+
+        let mut config = DayConfig::default();
+        config.set("rows=\"7\"").unwrap();
+        assert!(config.to_config::<Config>().is_err());
+    }
+}
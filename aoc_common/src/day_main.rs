@@ -0,0 +1,37 @@
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use super::{
+    aoc_error::AocError, day::Day, day_args::DayArgs, input_source::InputSource, logging,
+    normalization::Normalization, part::Part,
+};
+
+/// Entry point of a day binary
+pub fn run(day: Day) -> ExitCode {
+    let args = DayArgs::parse();
+
+    match logging::init().and_then(|_| solve(&day, &args)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn solve(day: &Day, args: &DayArgs) -> Result<(), AocError> {
+    let input = InputSource::resolve(args.input.as_deref(), day.default_input());
+
+    let mut solver = day.create_solver();
+    println!("{}", solver.description());
+
+    solver.configure(&args.config.load(day.get_number())?)?;
+    input.parse_into(solver.as_mut(), Normalization::from_strict(args.strict))?;
+
+    for part in Part::ALL {
+        println!("{}: {}", part, solver.solve(part)?);
+    }
+
+    Ok(())
+}
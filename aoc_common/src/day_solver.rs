@@ -0,0 +1,23 @@
+use super::{
+    aoc_error::AocError, day_config::DayConfig, explanation::Explanation, part::Part,
+    render::frame::Frame,
+};
+
+/// Version independent view of a single day's `PuzzleSolver`
+///
+/// Days depend on different releases of the `advent-of-code` crate, so their `PuzzleSolver`
+/// traits are distinct types. The `export_day!` macro implements this trait for every day's
+/// `Solver` so all of them can be driven from one place.
+///
+/// Solving a part must not change the parsed state, so one parsed solver can be shared between
+/// threads and its parts run in any order or repeatedly.
+///
+/// Configuration has to be set before the input is parsed.
+pub trait DaySolver: Send + Sync {
+    fn description(&self) -> String;
+    fn configure(&mut self, config: &DayConfig) -> Result<(), AocError>;
+    fn parse(&mut self, lines: &[&str]) -> Result<(), AocError>;
+    fn solve(&self, part: Part) -> Result<String, AocError>;
+    fn explain(&self, part: Part) -> Result<Explanation, AocError>;
+    fn render(&self, part: Part) -> Result<Vec<Frame>, AocError>;
+}
@@ -0,0 +1,157 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use super::{aoc_error::AocError, day_config::DayConfig, part::Part};
+
+/// Example input `example*.txt` with expected answers stored in `example*.expected`
+///
+/// The expected file contains lines in the same format the day binary prints, i.e. `Part 1: 11`.
+/// Empty lines and lines starting with `#` are ignored, parts without a line are not checked.
+/// Lines `Config: key=value` override puzzle constants which differ from the real input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    name: String,
+    input: PathBuf,
+    config: DayConfig,
+    expected: Vec<(Part, String)>,
+}
+
+impl Example {
+    pub fn discover(directory: &Path) -> Result<Vec<Example>, AocError> {
+        let entries = fs::read_dir(directory).map_err(|err| {
+            AocError::InputError(format!(
+                "Failed to read directory '{}' [{}]",
+                directory.display(),
+                err
+            ))
+        })?;
+
+        let mut inputs = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension().is_some_and(|ext| ext == "txt")
+                    && path
+                        .file_stem()
+                        .and_then(|stem| stem.to_str())
+                        .is_some_and(|stem| stem.starts_with("example"))
+            })
+            .collect::<Vec<_>>();
+        inputs.sort();
+
+        inputs.into_iter().map(Self::load).collect()
+    }
+
+    fn load(input: PathBuf) -> Result<Example, AocError> {
+        let expected_file = input.with_extension("expected");
+        let content = fs::read_to_string(&expected_file).map_err(|err| {
+            AocError::InputError(format!(
+                "Failed to read '{}' [{}]",
+                expected_file.display(),
+                err
+            ))
+        })?;
+
+        let (config, expected) = Self::parse_expected(&content).map_err(|err| {
+            AocError::InputError(format!("Invalid '{}' [{}]", expected_file.display(), err))
+        })?;
+
+        let name = input
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        Ok(Example {
+            name,
+            input,
+            config,
+            expected,
+        })
+    }
+
+    fn parse_expected(content: &str) -> Result<(DayConfig, Vec<(Part, String)>), String> {
+        let mut config = DayConfig::default();
+        let mut expected = Vec::new();
+
+        for line in content
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        {
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| format!("Expected 'Part N: answer' but '{}' found", line))?;
+
+            if key.trim() == "Config" {
+                config.set(value).map_err(|err| err.to_string())?;
+                continue;
+            }
+
+            let part = key
+                .trim()
+                .strip_prefix("Part ")
+                .ok_or_else(|| format!("Expected 'Part N: answer' but '{}' found", line))?
+                .parse::<Part>()?;
+
+            expected.push((part, value.trim().to_string()));
+        }
+
+        Ok((config, expected))
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_input(&self) -> &Path {
+        &self.input
+    }
+
+    pub fn get_config(&self) -> &DayConfig {
+        &self.config
+    }
+
+    pub fn get_expected(&self) -> &[(Part, String)] {
+        &self.expected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expected() {
+        let content = "# Comment\n\nPart 1: 11\nPart 2: 4,6,3\n";
+
+        assert_eq!(
+            Example::parse_expected(content),
+            Ok((
+                DayConfig::default(),
+                vec![
+                    (Part::Part1, "11".to_string()),
+                    (Part::Part2, "4,6,3".to_string())
+                ]
+            ))
+        );
+        assert!(Example::parse_expected("Part 3: 11").is_err());
+        assert!(Example::parse_expected("11").is_err());
+    }
+
+    #[test]
+    fn test_parse_expected_config() {
+        let content = "Config: rows=7\nConfig: cols=7\nPart 1: 22\n";
+
+        let mut config = DayConfig::default();
+        config.set("rows=7").unwrap();
+        config.set("cols=7").unwrap();
+
+        assert_eq!(
+            Example::parse_expected(content),
+            Ok((config, vec![(Part::Part1, "22".to_string())]))
+        );
+        assert!(Example::parse_expected("Config: rows").is_err());
+    }
+}
@@ -0,0 +1,174 @@
+use std::{panic, thread};
+
+use super::{
+    aoc_error::AocError, day::Day, day_solver::DaySolver, example::Example,
+    input_source::InputSource, normalization::Normalization, part::Part,
+};
+
+pub struct ExampleResult {
+    pub example: String,
+    pub part: Part,
+    pub expected: String,
+    pub actual: Result<String, AocError>,
+}
+
+impl ExampleResult {
+    pub fn is_ok(&self) -> bool {
+        matches!(&self.actual, Ok(actual) if *actual == self.expected)
+    }
+}
+
+/// Runs the whole `parse -> part 1/part 2` pipeline over the examples of a day
+///
+/// Every part is solved twice, the second time concurrently on the same solver, so a part that
+/// changes the parsed state or depends on the other part is reported as a failure. Explainable
+/// days can also check that witnesses of the examples give the expected answers.
+pub struct ExampleTester {
+    day: Day,
+}
+
+impl ExampleTester {
+    pub fn new(day: Day) -> Self {
+        Self { day }
+    }
+
+    pub fn run(&self) -> Result<Vec<ExampleResult>, AocError> {
+        let examples = Example::discover(self.day.get_resources())?;
+
+        Ok(examples
+            .iter()
+            .flat_map(|example| self.run_example(example))
+            .collect())
+    }
+
+    pub fn explain(&self) -> Result<Vec<ExampleResult>, AocError> {
+        let examples = Example::discover(self.day.get_resources())?;
+
+        Ok(examples
+            .iter()
+            .flat_map(|example| self.explain_example(example))
+            .collect())
+    }
+
+    fn prepare(solver: &mut dyn DaySolver, example: &Example) -> Result<(), AocError> {
+        solver.configure(example.get_config())?;
+        InputSource::File(example.get_input().to_path_buf())
+            .parse_into(solver, Normalization::Tolerant)
+    }
+
+    fn explain_example(&self, example: &Example) -> Vec<ExampleResult> {
+        let mut solver = self.day.create_solver();
+        let parsed = Self::prepare(solver.as_mut(), example);
+
+        example
+            .get_expected()
+            .iter()
+            .map(|(part, expected)| ExampleResult {
+                example: example.get_name().to_string(),
+                part: *part,
+                expected: expected.clone(),
+                actual: parsed
+                    .clone()
+                    .and_then(|_| solver.explain(*part))
+                    .map(|explanation| explanation.answer),
+            })
+            .collect()
+    }
+
+    fn run_example(&self, example: &Example) -> Vec<ExampleResult> {
+        let mut solver = self.day.create_solver();
+        let parsed = Self::prepare(solver.as_mut(), example);
+
+        let solver = solver.as_ref();
+        let parts = example
+            .get_expected()
+            .iter()
+            .map(|(part, _)| *part)
+            .collect::<Vec<_>>();
+
+        let first = parts
+            .iter()
+            .map(|&part| parsed.clone().and_then(|_| solver.solve(part)))
+            .collect::<Vec<_>>();
+
+        let second = match parsed {
+            Ok(_) => Self::solve_concurrently(solver, &parts),
+            Err(_) => first.clone(),
+        };
+
+        example
+            .get_expected()
+            .iter()
+            .zip(first.into_iter().zip(second))
+            .map(|((part, expected), (first, second))| ExampleResult {
+                example: example.get_name().to_string(),
+                part: *part,
+                expected: expected.clone(),
+                actual: Self::compare_runs(first, second),
+            })
+            .collect()
+    }
+
+    fn solve_concurrently(solver: &dyn DaySolver, parts: &[Part]) -> Vec<Result<String, AocError>> {
+        thread::scope(|scope| {
+            let handles = parts
+                .iter()
+                .map(|&part| scope.spawn(move || solver.solve(part)))
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|payload| panic::resume_unwind(payload))
+                })
+                .collect()
+        })
+    }
+
+    fn compare_runs(
+        first: Result<String, AocError>,
+        second: Result<String, AocError>,
+    ) -> Result<String, AocError> {
+        if first == second {
+            return first;
+        }
+
+        let describe = |result: &Result<String, AocError>| match result {
+            Ok(answer) => answer.clone(),
+            Err(err) => err.to_string(),
+        };
+
+        Err(AocError::SolverError(format!(
+            "Repeated run returned '{}' after '{}'",
+            describe(&second),
+            describe(&first)
+        )))
+    }
+
+    pub fn test_examples(&self) {
+        Self::assert_results(self.run());
+    }
+
+    pub fn test_explain(&self) {
+        Self::assert_results(self.explain());
+    }
+
+    fn assert_results(results: Result<Vec<ExampleResult>, AocError>) {
+        let results = results.unwrap_or_else(|err| panic!("{}", err));
+
+        let failures = results
+            .iter()
+            .filter(|result| !result.is_ok())
+            .map(|result| {
+                format!(
+                    "{} {}: expected '{}', got {:?}",
+                    result.example, result.part, result.expected, result.actual
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,41 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use super::{aoc_error::AocError, day_solver::DaySolver, explanation::Explanation, part::Part};
+
+/// Solver which returns the evidence of its answers
+///
+/// A witness is e.g. a path, a list of operators or matched positions. `check_witness` computes
+/// the answer from the witness and the parsed input only, so answers can be audited rather than
+/// trusted.
+pub trait Explainable {
+    type Witness: Serialize;
+    type Error: Display;
+
+    fn witness(&self, part: Part) -> Result<Self::Witness, Self::Error>;
+    fn check_witness(&self, part: Part, witness: &Self::Witness) -> Result<String, Self::Error>;
+}
+
+/// Solve the part and return the answer together with its checked witness
+pub fn explain<T>(solver: &T, part: Part) -> Result<Explanation, AocError>
+where
+    T: DaySolver + Explainable,
+{
+    let answer = solver.solve(part)?;
+    let witness = solver
+        .witness(part)
+        .map_err(|err| AocError::SolverError(err.to_string()))?;
+
+    let checked = solver
+        .check_witness(part, &witness)
+        .map_err(|err| AocError::SolverError(format!("Invalid witness of {} [{}]", part, err)))?;
+    if checked != answer {
+        return Err(AocError::SolverError(format!(
+            "Witness of {} gives '{}' but the answer is '{}'",
+            part, checked, answer
+        )));
+    }
+
+    Explanation::new(answer, &witness)
+}
@@ -0,0 +1,21 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use super::aoc_error::AocError;
+
+/// Answer of a part together with the witness it was checked against
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Explanation {
+    pub answer: String,
+    pub witness: Value,
+}
+
+impl Explanation {
+    pub fn new<T: Serialize>(answer: String, witness: &T) -> Result<Self, AocError> {
+        let witness = serde_json::to_value(witness).map_err(|err| {
+            AocError::SolverError(format!("Failed to serialize witness [{}]", err))
+        })?;
+
+        Ok(Self { answer, witness })
+    }
+}
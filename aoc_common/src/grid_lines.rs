@@ -0,0 +1,70 @@
+use super::parse_error::ParseError;
+
+/// Check that all lines of a grid have the same length as the first one
+pub fn check_rectangular(lines: &[&str]) -> Result<(), ParseError> {
+    let Some(first) = lines.first() else {
+        return Ok(());
+    };
+    let cols = first.chars().count();
+
+    for (index, line) in lines.iter().enumerate() {
+        let len = line.chars().count();
+
+        if len != cols {
+            // Point at the excess characters or just behind the end of a short line
+            let start = line.char_indices().nth(cols).map_or(line.len(), |(i, _)| i);
+
+            return Err(
+                ParseError::new(format!("Row has {} columns but {} expected", len, cols))
+                    .with_span(line, start..line.len())
+                    .with_line(index),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that all characters of a grid are accepted by `is_valid`
+pub fn check_characters<F>(lines: &[&str], is_valid: F) -> Result<(), ParseError>
+where
+    F: Fn(char) -> bool,
+{
+    for (index, line) in lines.iter().enumerate() {
+        if let Some((position, c)) = line.char_indices().find(|(_, c)| !is_valid(*c)) {
+            return Err(ParseError::new(format!("Invalid character '{}'", c))
+                .with_span(line, position..position + c.len_utf8())
+                .with_line(index));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_rectangular() {
+        assert!(check_rectangular(&[]).is_ok());
+        assert!(check_rectangular(&["abc", "def"]).is_ok());
+
+        let err = check_rectangular(&["abc", "defg"]).unwrap_err();
+        assert_eq!(err.get_line(), Some(2));
+        assert_eq!(err.get_columns(), Some(&(4..5)));
+
+        let err = check_rectangular(&["abc", "de"]).unwrap_err();
+        assert_eq!(err.get_line(), Some(2));
+        assert_eq!(err.get_columns(), Some(&(3..4)));
+    }
+
+    #[test]
+    fn test_check_characters() {
+        assert!(check_characters(&["#.#", "..."], |c| "#.".contains(c)).is_ok());
+
+        let err = check_characters(&["#.#", "..x"], |c| "#.".contains(c)).unwrap_err();
+        assert_eq!(err.get_line(), Some(2));
+        assert_eq!(err.get_columns(), Some(&(3..4)));
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use super::{aoc_error::AocError, day_solver::DaySolver, normalization::Normalization};
+
+/// Environment variable used to override the puzzle input when `--input` is not given
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Embedded(&'static str),
+}
+
+impl InputSource {
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Resolve input in order: command line argument, `AOC_INPUT`, default input of the day
+    pub fn resolve(arg: Option<&str>, default: InputSource) -> Self {
+        Self::resolve_with(arg, env::var(INPUT_ENV).ok(), default)
+    }
+
+    fn resolve_with(arg: Option<&str>, env_value: Option<String>, default: InputSource) -> Self {
+        match arg {
+            Some(arg) => Self::from_arg(arg),
+            None => Self::from_env_value(env_value).unwrap_or(default),
+        }
+    }
+
+    /// Input given by `AOC_INPUT`, an empty value is the same as an unset one
+    pub fn from_env() -> Option<Self> {
+        Self::from_env_value(env::var(INPUT_ENV).ok())
+    }
+
+    fn from_env_value(value: Option<String>) -> Option<Self> {
+        value
+            .filter(|value| !value.is_empty())
+            .map(|value| Self::from_arg(&value))
+    }
+
+    pub fn read(&self) -> Result<String, AocError> {
+        match self {
+            InputSource::File(path) => fs::read_to_string(path).map_err(|err| {
+                AocError::InputError(format!("Failed to read '{}' [{}]", path.display(), err))
+            }),
+            InputSource::Stdin => {
+                let mut content = String::new();
+                io::stdin().read_to_string(&mut content).map_err(|err| {
+                    AocError::InputError(format!("Failed to read stdin [{}]", err))
+                })?;
+                Ok(content)
+            }
+            InputSource::Embedded(content) => Ok(content.to_string()),
+        }
+    }
+
+    /// Read the input and normalize it, irregularities of the strict mode are listed in the error
+    pub fn read_normalized(&self, normalization: Normalization) -> Result<String, AocError> {
+        normalization
+            .apply(&self.read()?)
+            .map_err(|irregularities| {
+                AocError::InputError(format!(
+                    "Irregular '{}'\n{}",
+                    self,
+                    irregularities
+                        .iter()
+                        .map(|irregularity| format!("- {}", irregularity))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                ))
+            })
+    }
+
+    /// Read the input and parse it by the solver, errors are given the input name
+    pub fn parse_into(
+        &self,
+        solver: &mut dyn DaySolver,
+        normalization: Normalization,
+    ) -> Result<(), AocError> {
+        let content = self.read_normalized(normalization)?;
+        let lines = content.lines().collect::<Vec<_>>();
+
+        solver.parse(&lines).map_err(|err| match err {
+            AocError::ParseError(err) => AocError::ParseError(err.with_input(self)),
+            err => AocError::InputError(format!("Failed to parse '{}'\n{}", self, err)),
+        })
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Embedded(_) => write!(f, "<embedded input>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("example.txt"),
+            InputSource::File(PathBuf::from("example.txt"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_with() {
+        let default = InputSource::Embedded("1 2\n");
+
+        assert_eq!(
+            InputSource::resolve_with(Some("-"), Some("env.txt".to_string()), default.clone()),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::resolve_with(None, Some("env.txt".to_string()), default.clone()),
+            InputSource::File(PathBuf::from("env.txt"))
+        );
+        assert_eq!(
+            InputSource::resolve_with(None, Some(String::new()), default.clone()),
+            default.clone()
+        );
+        assert_eq!(
+            InputSource::resolve_with(None, None, default.clone()),
+            default
+        );
+    }
+}
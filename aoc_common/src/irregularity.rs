@@ -0,0 +1,33 @@
+use std::fmt::Display;
+
+/// Deviation of an input from clean LF text, line numbers start at 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Irregularity {
+    ByteOrderMark,
+    CarriageReturns { count: usize, first_line: usize },
+    TrailingWhitespace { count: usize, first_line: usize },
+    TrailingBlankLines { count: usize },
+    MissingFinalNewline,
+}
+
+impl Display for Irregularity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Irregularity::ByteOrderMark => write!(f, "byte order mark at the start"),
+            Irregularity::CarriageReturns { count, first_line } => write!(
+                f,
+                "{} line(s) end with CRLF, the first one is line {}",
+                count, first_line
+            ),
+            Irregularity::TrailingWhitespace { count, first_line } => write!(
+                f,
+                "{} line(s) end with whitespace, the first one is line {}",
+                count, first_line
+            ),
+            Irregularity::TrailingBlankLines { count } => {
+                write!(f, "{} blank line(s) at the end", count)
+            }
+            Irregularity::MissingFinalNewline => write!(f, "missing newline at the end"),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+pub mod aoc_error;
+pub mod config_args;
+pub mod configurable;
+pub mod day;
+pub mod day_args;
+pub mod day_config;
+pub mod day_main;
+pub mod day_solver;
+pub mod example;
+pub mod example_tester;
+pub mod explainable;
+pub mod explanation;
+pub mod grid_lines;
+pub mod input_source;
+pub mod irregularity;
+pub mod logging;
+pub mod macros;
+pub mod normalization;
+pub mod parsable;
+pub mod parse_error;
+pub mod part;
+pub mod render;
+pub mod renderable;
+
+// Used by `export_day!` to instrument solvers of days
+#[doc(hidden)]
+pub use tracing;
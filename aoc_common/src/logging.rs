@@ -0,0 +1,33 @@
+use std::{
+    env,
+    io::{self, IsTerminal},
+};
+
+use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter};
+
+use super::aoc_error::AocError;
+
+/// Environment variable with the verbosity of solvers, e.g. `debug` or `day_18_ram_run=debug`
+pub const LOG_ENV: &str = "AOC_LOG";
+
+/// Print spans and events of solvers to stderr, nothing is logged unless `AOC_LOG` is set
+///
+/// Spans `parse`, `part_1` and `part_2` are reported with their duration when they are closed.
+pub fn init() -> Result<(), AocError> {
+    let directives = match env::var(LOG_ENV) {
+        Ok(directives) if !directives.is_empty() => directives,
+        _ => return Ok(()),
+    };
+
+    let filter = EnvFilter::try_new(&directives).map_err(|err| {
+        AocError::ArgumentError(format!("Invalid {} '{}' [{}]", LOG_ENV, directives, err))
+    })?;
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .try_init()
+        .map_err(|err| AocError::ArgumentError(format!("Failed to initialize logging [{}]", err)))
+}
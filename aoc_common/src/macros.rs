@@ -0,0 +1,154 @@
+/// Export a day's `Solver` as `day()` so it can be registered in the workspace runner
+///
+/// The macro is expanded inside the day crate, therefore `advent_of_code` resolves to the release
+/// the day itself depends on. Optional capabilities follow the solver:
+/// - `configurable` - the solver implements `Configurable`, other days reject any configuration
+/// - `parsable` - the solver implements `Parsable`, other days report parse errors as messages
+/// - `explainable` - the solver implements `Explainable`, other days cannot explain answers
+/// - `renderable` - the solver implements `Renderable`, other days cannot be rendered
+///
+/// With the day's feature `embedded-input` the `resources/input.txt` is compiled into the binary
+/// and used when no input is given, so the binary does not need the source tree.
+///
+/// ```ignore
+/// aoc_common::export_day!(18, Solver, configurable, parsable, explainable, renderable);
+/// ```
+#[macro_export]
+macro_rules! export_day {
+    (@configure $solver:ident, $config:ident, $number:expr; configurable $($rest:ident)*) => {
+        $crate::configurable::configure($solver, $config)
+    };
+
+    (@configure $solver:ident, $config:ident, $number:expr; $other:ident $($rest:ident)*) => {
+        $crate::export_day!(@configure $solver, $config, $number; $($rest)*)
+    };
+
+    (@configure $solver:ident, $config:ident, $number:expr;) => {
+        match $config.is_empty() {
+            true => Ok(()),
+            false => Err($crate::aoc_error::AocError::ArgumentError(format!(
+                "Day {} does not have any configuration",
+                $number
+            ))),
+        }
+    };
+
+    (@parse $solver:ident, $lines:ident; parsable $($rest:ident)*) => {
+        $crate::parsable::parse($solver, $lines)
+    };
+
+    (@parse $solver:ident, $lines:ident; $other:ident $($rest:ident)*) => {
+        $crate::export_day!(@parse $solver, $lines; $($rest)*)
+    };
+
+    (@parse $solver:ident, $lines:ident;) => {
+        ::advent_of_code::puzzles::puzzle_solver::PuzzleSolver::parse_input_file($solver, $lines)
+            .map_err(|err| $crate::aoc_error::AocError::SolverError(err.to_string()))
+    };
+
+    (@explain $solver:ident, $part:ident, $number:expr; explainable $($rest:ident)*) => {
+        $crate::explainable::explain($solver, $part)
+    };
+
+    (@explain $solver:ident, $part:ident, $number:expr; $other:ident $($rest:ident)*) => {
+        $crate::export_day!(@explain $solver, $part, $number; $($rest)*)
+    };
+
+    (@explain $solver:ident, $part:ident, $number:expr;) => {
+        Err($crate::aoc_error::AocError::ArgumentError(format!(
+            "Day {} cannot explain its answers",
+            $number
+        )))
+    };
+
+    (@render $solver:ident, $part:ident, $number:expr; renderable $($rest:ident)*) => {
+        $crate::renderable::render($solver, $part)
+    };
+
+    (@render $solver:ident, $part:ident, $number:expr; $other:ident $($rest:ident)*) => {
+        $crate::export_day!(@render $solver, $part, $number; $($rest)*)
+    };
+
+    (@render $solver:ident, $part:ident, $number:expr;) => {
+        Err($crate::aoc_error::AocError::ArgumentError(format!(
+            "Day {} cannot be rendered",
+            $number
+        )))
+    };
+
+    ($number:expr, $solver:ty $(, $option:ident)*) => {
+        impl $crate::day_solver::DaySolver for $solver {
+            fn description(&self) -> String {
+                ::advent_of_code::puzzles::puzzle_solver::PuzzleSolver::get_description(self)
+                    .to_string()
+            }
+
+            fn configure(
+                &mut self,
+                config: &$crate::day_config::DayConfig,
+            ) -> Result<(), $crate::aoc_error::AocError> {
+                $crate::export_day!(@configure self, config, $number; $($option)*)
+            }
+
+            fn parse(&mut self, lines: &[&str]) -> Result<(), $crate::aoc_error::AocError> {
+                let _span = $crate::tracing::info_span!("parse", day = $number).entered();
+
+                $crate::export_day!(@parse self, lines; $($option)*)
+            }
+
+            fn solve(
+                &self,
+                part: $crate::part::Part,
+            ) -> Result<String, $crate::aoc_error::AocError> {
+                let result = match part {
+                    $crate::part::Part::Part1 => {
+                        let _span = $crate::tracing::info_span!("part_1", day = $number).entered();
+                        ::advent_of_code::puzzles::puzzle_solver::PuzzleSolver::part_1(self)
+                    }
+                    $crate::part::Part::Part2 => {
+                        let _span = $crate::tracing::info_span!("part_2", day = $number).entered();
+                        ::advent_of_code::puzzles::puzzle_solver::PuzzleSolver::part_2(self)
+                    }
+                };
+
+                result.map_err(|err| $crate::aoc_error::AocError::SolverError(err.to_string()))
+            }
+
+            fn explain(
+                &self,
+                part: $crate::part::Part,
+            ) -> Result<$crate::explanation::Explanation, $crate::aoc_error::AocError> {
+                $crate::export_day!(@explain self, part, $number; $($option)*)
+            }
+
+            fn render(
+                &self,
+                part: $crate::part::Part,
+            ) -> Result<Vec<$crate::render::frame::Frame>, $crate::aoc_error::AocError> {
+                $crate::export_day!(@render self, part, $number; $($option)*)
+            }
+        }
+
+        pub fn day() -> $crate::day::Day {
+            fn create_solver() -> Box<dyn $crate::day_solver::DaySolver> {
+                Box::new(<$solver as ::advent_of_code::puzzles::puzzle_solver::PuzzleSolver>::new())
+            }
+
+            #[cfg(feature = "embedded-input")]
+            const EMBEDDED_INPUT: Option<&str> = Some(include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/resources/input.txt"
+            )));
+            #[cfg(not(feature = "embedded-input"))]
+            const EMBEDDED_INPUT: Option<&str> = None;
+
+            $crate::day::Day::new(
+                $number,
+                env!("CARGO_PKG_NAME"),
+                concat!(env!("CARGO_MANIFEST_DIR"), "/resources"),
+                EMBEDDED_INPUT,
+                create_solver,
+            )
+        }
+    };
+}
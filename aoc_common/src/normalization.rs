@@ -0,0 +1,184 @@
+use super::irregularity::Irregularity;
+
+const BYTE_ORDER_MARK: char = '\u{feff}';
+
+/// Handling of inputs which are not clean LF text, e.g. files saved on Windows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Normalization {
+    /// Strip a BOM, convert CRLF, trim trailing whitespace and blank lines, end with a newline
+    #[default]
+    Tolerant,
+    /// Report irregularities as an error and keep the input untouched
+    Strict,
+}
+
+impl Normalization {
+    pub fn from_strict(strict: bool) -> Self {
+        match strict {
+            true => Normalization::Strict,
+            false => Normalization::Tolerant,
+        }
+    }
+
+    /// Normalized input or irregularities found by the strict mode
+    pub fn apply(&self, content: &str) -> Result<String, Vec<Irregularity>> {
+        match self {
+            Normalization::Tolerant => Ok(normalize(content)),
+            Normalization::Strict => {
+                let irregularities = find_irregularities(content);
+
+                match irregularities.is_empty() {
+                    true => Ok(content.to_string()),
+                    false => Err(irregularities),
+                }
+            }
+        }
+    }
+}
+
+pub fn find_irregularities(content: &str) -> Vec<Irregularity> {
+    let mut irregularities = Vec::new();
+
+    if content.starts_with(BYTE_ORDER_MARK) {
+        irregularities.push(Irregularity::ByteOrderMark);
+    }
+
+    let lines = split_lines(content);
+
+    let carriage_returns = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.ends_with('\r'))
+        .map(|(index, _)| index + 1)
+        .collect::<Vec<_>>();
+    if let Some(&first_line) = carriage_returns.first() {
+        irregularities.push(Irregularity::CarriageReturns {
+            count: carriage_returns.len(),
+            first_line,
+        });
+    }
+
+    // Whitespace of blank lines at the end is reported with the blank lines
+    let blank_lines = count_trailing_blank_lines(&lines);
+    let trailing_whitespace = lines[..lines.len() - blank_lines]
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            line.len() != line.trim_end().len()
+        })
+        .map(|(index, _)| index + 1)
+        .collect::<Vec<_>>();
+    if let Some(&first_line) = trailing_whitespace.first() {
+        irregularities.push(Irregularity::TrailingWhitespace {
+            count: trailing_whitespace.len(),
+            first_line,
+        });
+    }
+
+    if blank_lines > 0 {
+        irregularities.push(Irregularity::TrailingBlankLines { count: blank_lines });
+    }
+
+    if !content.is_empty() && !content.ends_with('\n') {
+        irregularities.push(Irregularity::MissingFinalNewline);
+    }
+
+    irregularities
+}
+
+pub fn normalize(content: &str) -> String {
+    let mut lines = split_lines(content)
+        .into_iter()
+        .map(|line| line.trim_end())
+        .collect::<Vec<_>>();
+
+    let blank_lines = count_trailing_blank_lines(&lines);
+    lines.truncate(lines.len() - blank_lines);
+
+    lines
+        .into_iter()
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+// Lines without their '\n', a final newline does not start another line
+fn split_lines(content: &str) -> Vec<&str> {
+    let content = content.strip_prefix(BYTE_ORDER_MARK).unwrap_or(content);
+    let content = content.strip_suffix('\n').unwrap_or(content);
+
+    match content.is_empty() {
+        true => Vec::new(),
+        false => content.split('\n').collect(),
+    }
+}
+
+fn count_trailing_blank_lines(lines: &[&str]) -> usize {
+    lines
+        .iter()
+        .rev()
+        .take_while(|line| line.trim().is_empty())
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(""), "");
+        assert_eq!(normalize("\n\n"), "");
+        assert_eq!(normalize("1 2\n3 4\n"), "1 2\n3 4\n");
+        assert_eq!(normalize("1 2\n3 4"), "1 2\n3 4\n");
+        assert_eq!(normalize("\u{feff}1 2\r\n3 4\r\n"), "1 2\n3 4\n");
+        assert_eq!(normalize("#.# \t\n\n<>^v  \n \n\r\n"), "#.#\n\n<>^v\n");
+    }
+
+    #[test]
+    fn test_find_irregularities() {
+        assert!(find_irregularities("").is_empty());
+        assert!(find_irregularities("1 2\n\n3 4\n").is_empty());
+
+        assert_eq!(
+            find_irregularities("\u{feff}1 2\n3 4 \r\n5 6\r\n\n \n"),
+            vec![
+                Irregularity::ByteOrderMark,
+                Irregularity::CarriageReturns {
+                    count: 2,
+                    first_line: 2
+                },
+                Irregularity::TrailingWhitespace {
+                    count: 1,
+                    first_line: 2
+                },
+                Irregularity::TrailingBlankLines { count: 2 },
+            ]
+        );
+        assert_eq!(
+            find_irregularities("1 2\n3 4"),
+            vec![Irregularity::MissingFinalNewline]
+        );
+    }
+
+    #[test]
+    fn test_apply() {
+        let content = "1 2\r\n3 4\r\n";
+
+        assert_eq!(
+            Normalization::from_strict(false).apply(content),
+            Ok("1 2\n3 4\n".to_string())
+        );
+        assert_eq!(
+            Normalization::from_strict(true).apply("1 2\n3 4\n"),
+            Ok("1 2\n3 4\n".to_string())
+        );
+        assert_eq!(
+            Normalization::from_strict(true).apply(content),
+            Err(vec![Irregularity::CarriageReturns {
+                count: 2,
+                first_line: 1
+            }])
+        );
+    }
+}
@@ -0,0 +1,13 @@
+use super::{aoc_error::AocError, parse_error::ParseError};
+
+/// Solver whose parser reports the location of invalid input
+///
+/// Days exported by `export_day!(N, Solver, parsable)` keep the `ParseError` with its line and
+/// span, parse errors of other days are only messages.
+pub trait Parsable {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError>;
+}
+
+pub fn parse<T: Parsable>(solver: &mut T, lines: &[&str]) -> Result<(), AocError> {
+    solver.parse_lines(lines).map_err(AocError::ParseError)
+}
@@ -0,0 +1,217 @@
+use std::{fmt::Display, ops::Range};
+
+/// Parse error with a location in the input
+///
+/// Decoders of a single line usually know the offending span but not the line number, therefore
+/// the location is filled in two steps: `with_span()` by the decoder and `with_line()` by the
+/// caller iterating over lines.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    message: String,
+    // 1-based line number
+    line: Option<usize>,
+    // 1-based columns, end is exclusive
+    columns: Option<Range<usize>>,
+    snippet: Option<String>,
+    // Name of the parsed input, e.g. a file path
+    input: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            line: None,
+            columns: None,
+            snippet: None,
+            input: None,
+        }
+    }
+
+    /// Set 0-based index of the line in the input
+    pub fn with_line(mut self, index: usize) -> Self {
+        self.line = Some(index + 1);
+        self
+    }
+
+    /// Set offending byte range within the line
+    pub fn with_span(mut self, line: &str, bytes: Range<usize>) -> Self {
+        let to_column = |byte: usize| line[..byte.min(line.len())].chars().count() + 1;
+
+        let start = to_column(bytes.start);
+        let end = to_column(bytes.end).max(start + 1);
+
+        self.columns = Some(start..end);
+        self.snippet = Some(line.to_string());
+        self
+    }
+
+    /// Set offending part of the line, `part` is expected to be a slice of `line`
+    pub fn with_substring(self, line: &str, part: &str) -> Self {
+        let line_start = line.as_ptr() as usize;
+        let part_start = part.as_ptr() as usize;
+
+        let start = match part_start.checked_sub(line_start) {
+            Some(offset) if offset + part.len() <= line.len() => offset,
+            // Not a slice of the line, fallback to search
+            _ => line.find(part).unwrap_or(0),
+        };
+
+        self.with_span(line, start..start + part.len())
+    }
+
+    /// Set the whole line as the offending span
+    pub fn with_whole_line(self, line: &str) -> Self {
+        self.with_span(line, 0..line.len())
+    }
+
+    /// Set name of the input the line belongs to
+    pub fn with_input(mut self, input: impl Display) -> Self {
+        self.input = Some(input.to_string());
+        self
+    }
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn get_line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn get_columns(&self) -> Option<&Range<usize>> {
+        self.columns.as_ref()
+    }
+
+    pub fn get_snippet(&self) -> Option<&str> {
+        self.snippet.as_deref()
+    }
+
+    pub fn get_input(&self) -> Option<&str> {
+        self.input.as_deref()
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+
+        let columns = match &self.columns {
+            Some(columns) if columns.len() > 1 => {
+                format!("columns {}-{}", columns.start, columns.end - 1)
+            }
+            Some(columns) => format!("column {}", columns.start),
+            None => String::new(),
+        };
+
+        match (self.line, columns.is_empty()) {
+            (Some(line), false) => write!(f, "\n --> line {}, {}", line, columns)?,
+            (Some(line), true) => write!(f, "\n --> line {}", line)?,
+            (None, false) => write!(f, "\n --> {}", columns)?,
+            (None, true) => return Ok(()),
+        }
+
+        // Caret excerpt, e.g.
+        //   |
+        // 3 | ..#.x..
+        //   |     ^
+        if let (Some(snippet), Some(columns)) = (&self.snippet, &self.columns) {
+            let number = self.line.map(|line| line.to_string()).unwrap_or_default();
+            let gutter = " ".repeat(number.len());
+
+            write!(f, "\n{} |", gutter)?;
+            write!(f, "\n{} | {}", number, snippet)?;
+            write!(
+                f,
+                "\n{} | {}{}",
+                gutter,
+                " ".repeat(columns.start - 1),
+                "^".repeat(columns.len())
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_span() {
+        let error = ParseError::new("Invalid number").with_span("12 x4 56", 3..5);
+
+        assert_eq!(error.get_columns(), Some(&(4..6)));
+        assert_eq!(error.get_snippet(), Some("12 x4 56"));
+        assert_eq!(error.get_line(), None);
+    }
+
+    #[test]
+    fn test_with_substring() {
+        let line = "7 6 x 2 1";
+        let part = line.split(' ').nth(2).unwrap();
+
+        let error = ParseError::new("Invalid number").with_substring(line, part);
+        assert_eq!(error.get_columns(), Some(&(5..6)));
+
+        let error = ParseError::new("Invalid number").with_substring(line, "2 1");
+        assert_eq!(error.get_columns(), Some(&(7..10)));
+    }
+
+    #[test]
+    fn test_with_span_empty() {
+        let error = ParseError::new("Empty line").with_whole_line("");
+
+        assert_eq!(error.get_columns(), Some(&(1..2)));
+    }
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::new("Invalid character 'x'")
+            .with_span("..#.x..", 4..5)
+            .with_line(11);
+
+        assert_eq!(
+            error.to_string(),
+            [
+                "Invalid character 'x'",
+                " --> line 12, column 5",
+                "   |",
+                "12 | ..#.x..",
+                "   |     ^",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_display_columns() {
+        let error = ParseError::new("Invalid number")
+            .with_span("12 x4 56", 3..5)
+            .with_line(0);
+
+        assert_eq!(
+            error.to_string(),
+            [
+                "Invalid number",
+                " --> line 1, columns 4-5",
+                "  |",
+                "1 | 12 x4 56",
+                "  |    ^^",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_display_without_location() {
+        assert_eq!(ParseError::new("Empty input").to_string(), "Empty input");
+        assert_eq!(
+            ParseError::new("Missing line").with_line(2).to_string(),
+            "Missing line\n --> line 3"
+        );
+    }
+}
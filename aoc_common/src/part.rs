@@ -0,0 +1,51 @@
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    Part1,
+    Part2,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::Part1, Part::Part2];
+
+    pub fn get_number(&self) -> usize {
+        match self {
+            Part::Part1 => 1,
+            Part::Part2 => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::Part1 => write!(f, "Part 1"),
+            Part::Part2 => write!(f, "Part 2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Part::Part1),
+            "2" => Ok(Part::Part2),
+            other => Err(format!("Part should be 1 or 2 but '{}' found", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("1".parse::<Part>(), Ok(Part::Part1));
+        assert_eq!("2".parse::<Part>(), Ok(Part::Part2));
+        assert!("3".parse::<Part>().is_err());
+    }
+}
@@ -0,0 +1,56 @@
+/// RGB color of a tile or an overlay
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+// Colors of letters and regions, picked to be distinguishable on a dark background
+const PALETTE: [Color; 8] = [
+    Color::new(230, 25, 75),
+    Color::new(60, 180, 75),
+    Color::new(255, 225, 25),
+    Color::new(67, 99, 216),
+    Color::new(245, 130, 49),
+    Color::new(145, 30, 180),
+    Color::new(66, 212, 244),
+    Color::new(240, 50, 230),
+];
+
+impl Color {
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// Color from the palette, the palette is repeated for larger indexes
+    pub fn from_palette(index: usize) -> Self {
+        PALETTE[index % PALETTE.len()]
+    }
+
+    /// Color of a tile of the puzzle input
+    pub fn from_tile(tile: char) -> Self {
+        match tile {
+            '.' => Color::new(24, 24, 24),
+            '#' => Color::new(128, 128, 128),
+            '0'..='9' => Color::new(0, 40 + 21 * (tile as u8 - b'0'), 0),
+            'O' | '[' | ']' => Color::new(160, 110, 50),
+            '@' | 'S' | 'E' | '^' | '>' | 'v' | '<' => Color::new(240, 240, 240),
+            other => Color::from_palette(other as usize),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_tile() {
+        assert_eq!(Color::from_tile('#'), Color::new(128, 128, 128));
+        assert_eq!(Color::from_tile('0'), Color::new(0, 40, 0));
+        assert_eq!(Color::from_tile('9'), Color::new(0, 229, 0));
+        assert_eq!(Color::from_tile('A'), Color::from_tile('A'));
+        assert_ne!(Color::from_tile('A'), Color::from_tile('B'));
+    }
+}
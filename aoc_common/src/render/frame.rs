@@ -0,0 +1,92 @@
+use super::layer::Layer;
+
+/// Picture of a grid, tiles with optional overlays, coordinates are `(x, y)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    title: String,
+    rows: usize,
+    cols: usize,
+    tiles: Vec<char>,
+    layers: Vec<Option<Layer>>,
+}
+
+impl Frame {
+    pub fn new<F>(title: &str, rows: usize, cols: usize, tile: F) -> Self
+    where
+        F: Fn(isize, isize) -> char,
+    {
+        let tiles = (0..rows as isize)
+            .flat_map(|y| (0..cols as isize).map(move |x| (x, y)))
+            .map(|(x, y)| tile(x, y))
+            .collect();
+
+        Self {
+            title: title.to_string(),
+            rows,
+            cols,
+            tiles,
+            layers: vec![None; rows * cols],
+        }
+    }
+
+    /// Draw the layer over the tiles, points outside of the frame are skipped
+    pub fn add_layer<I>(&mut self, layer: Layer, points: I)
+    where
+        I: IntoIterator<Item = (isize, isize)>,
+    {
+        for (x, y) in points {
+            if let Some(index) = self.get_index(x, y) {
+                self.layers[index] = Some(layer);
+            }
+        }
+    }
+
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn get_rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn get_cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get_tile(&self, x: isize, y: isize) -> Option<char> {
+        self.get_index(x, y).map(|index| self.tiles[index])
+    }
+
+    pub fn get_layer(&self, x: isize, y: isize) -> Option<Layer> {
+        self.get_index(x, y).and_then(|index| self.layers[index])
+    }
+
+    fn get_index(&self, x: isize, y: isize) -> Option<usize> {
+        let (x, y) = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+
+        match x < self.cols && y < self.rows {
+            true => Some(y * self.cols + x),
+            false => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_layer() {
+        let mut frame = Frame::new("test", 2, 3, |x, y| if x == y { '#' } else { '.' });
+
+        frame.add_layer(Layer::Path, [(0, 1), (2, 1), (3, 1), (-1, 0)]);
+        frame.add_layer(Layer::Marker, [(2, 1)]);
+
+        assert_eq!(frame.get_tile(1, 1), Some('#'));
+        assert_eq!(frame.get_tile(2, 1), Some('.'));
+        assert_eq!(frame.get_tile(3, 1), None);
+        assert_eq!(frame.get_layer(0, 1), Some(Layer::Path));
+        assert_eq!(frame.get_layer(2, 1), Some(Layer::Marker));
+        assert_eq!(frame.get_layer(1, 1), None);
+    }
+}
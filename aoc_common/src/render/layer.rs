@@ -0,0 +1,35 @@
+use super::color::Color;
+
+/// Overlay drawn over tiles of a frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layer {
+    /// Tiles of a path, e.g. a walk of a guard or the best path through a maze
+    Path,
+    /// Tiles of a region, regions with different indexes get different colors
+    Region(usize),
+    /// Antinodes of antennas
+    Antinode,
+    /// Any other interesting tiles, e.g. a blocking byte or matched letters
+    Marker,
+}
+
+impl Layer {
+    pub fn get_color(&self) -> Color {
+        match self {
+            Layer::Path => Color::new(255, 215, 0),
+            Layer::Region(index) => Color::from_palette(*index),
+            Layer::Antinode => Color::new(220, 50, 47),
+            Layer::Marker => Color::new(38, 139, 210),
+        }
+    }
+
+    /// Symbol replacing the tile in plain text, None keeps the tile
+    pub fn get_symbol(&self) -> Option<char> {
+        match self {
+            Layer::Path => Some('O'),
+            Layer::Region(_) => None,
+            Layer::Antinode => Some('#'),
+            Layer::Marker => Some('X'),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod color;
+pub mod frame;
+pub mod layer;
+pub mod render_format;
+pub mod renderer;
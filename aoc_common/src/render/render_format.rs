@@ -0,0 +1,23 @@
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum RenderFormat {
+    /// Plain characters, overlays replace tiles with symbols
+    Text,
+    /// Characters colored with ANSI escape codes for a terminal
+    Ansi,
+    /// Binary PPM image
+    Ppm,
+    /// PNG image
+    Png,
+}
+
+impl RenderFormat {
+    pub fn get_extension(&self) -> &'static str {
+        match self {
+            RenderFormat::Text | RenderFormat::Ansi => "txt",
+            RenderFormat::Ppm => "ppm",
+            RenderFormat::Png => "png",
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::io::Write;
+
+use crate::aoc_error::AocError;
+
+use super::{color::Color, frame::Frame, render_format::RenderFormat};
+
+/// Write the frame in the format, `scale` is the size of a tile in pixels of images
+pub fn render(
+    frame: &Frame,
+    format: RenderFormat,
+    scale: usize,
+    writer: &mut dyn Write,
+) -> Result<(), AocError> {
+    let result = match format {
+        RenderFormat::Text => writer.write_all(to_text(frame).as_bytes()),
+        RenderFormat::Ansi => writer.write_all(to_ansi(frame).as_bytes()),
+        RenderFormat::Ppm => write_ppm(frame, scale, writer),
+        RenderFormat::Png => write_png(frame, scale, writer),
+    };
+
+    result.map_err(|err| {
+        AocError::SolverError(format!(
+            "Failed to render '{}' [{}]",
+            frame.get_title(),
+            err
+        ))
+    })
+}
+
+fn to_text(frame: &Frame) -> String {
+    let mut text = String::new();
+
+    for y in 0..frame.get_rows() as isize {
+        for x in 0..frame.get_cols() as isize {
+            let tile = frame.get_tile(x, y).unwrap_or(' ');
+            let symbol = frame.get_layer(x, y).and_then(|layer| layer.get_symbol());
+
+            text.push(symbol.unwrap_or(tile));
+        }
+        text.push('\n');
+    }
+
+    text
+}
+
+fn to_ansi(frame: &Frame) -> String {
+    let mut text = format!("{}\n", frame.get_title());
+
+    for y in 0..frame.get_rows() as isize {
+        for x in 0..frame.get_cols() as isize {
+            let tile = frame.get_tile(x, y).unwrap_or(' ');
+
+            // Overlays color the background and the tile is drawn in black so it stays readable
+            let foreground = match frame.get_layer(x, y) {
+                Some(layer) => {
+                    let background = layer.get_color();
+                    text.push_str(&format!(
+                        "\x1b[48;2;{};{};{}m",
+                        background.red, background.green, background.blue
+                    ));
+
+                    Color::new(0, 0, 0)
+                }
+                None => Color::from_tile(tile),
+            };
+
+            let Color { red, green, blue } = foreground;
+            text.push_str(&format!(
+                "\x1b[38;2;{};{};{}m{}\x1b[0m",
+                red, green, blue, tile
+            ));
+        }
+        text.push('\n');
+    }
+
+    text
+}
+
+// RGB pixels of the frame, every tile is a square of scale x scale pixels
+fn to_pixels(frame: &Frame, scale: usize) -> (usize, usize, Vec<u8>) {
+    let (width, height) = (frame.get_cols() * scale, frame.get_rows() * scale);
+    let mut pixels = Vec::with_capacity(width * height * 3);
+
+    for row in 0..height {
+        for col in 0..width {
+            let (x, y) = ((col / scale) as isize, (row / scale) as isize);
+
+            let color = match frame.get_layer(x, y) {
+                Some(layer) => layer.get_color(),
+                None => Color::from_tile(frame.get_tile(x, y).unwrap_or(' ')),
+            };
+
+            pixels.extend([color.red, color.green, color.blue]);
+        }
+    }
+
+    (width, height, pixels)
+}
+
+fn write_ppm(frame: &Frame, scale: usize, writer: &mut dyn Write) -> std::io::Result<()> {
+    let (width, height, pixels) = to_pixels(frame, scale);
+
+    write!(writer, "P6\n{} {}\n255\n", width, height)?;
+    writer.write_all(&pixels)
+}
+
+fn write_png(frame: &Frame, scale: usize, writer: &mut dyn Write) -> std::io::Result<()> {
+    let (width, height, pixels) = to_pixels(frame, scale);
+
+    let mut encoder = png::Encoder::new(writer, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut png_writer = encoder.write_header()?;
+    png_writer.write_image_data(&pixels)?;
+    png_writer.finish()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::render::layer::Layer;
+
+    use super::*;
+
+    fn create_frame() -> Frame {
+        let mut frame = Frame::new("test", 2, 3, |x, _| if x == 0 { '#' } else { '.' });
+        frame.add_layer(Layer::Path, [(1, 0), (2, 0)]);
+        frame.add_layer(Layer::Region(0), [(1, 1)]);
+
+        frame
+    }
+
+    fn render_to_bytes(format: RenderFormat, scale: usize) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        render(&create_frame(), format, scale, &mut bytes).expect("Failed to render");
+
+        bytes
+    }
+
+    #[test]
+    fn test_render_text() {
+        let text = String::from_utf8(render_to_bytes(RenderFormat::Text, 1)).unwrap();
+        assert_eq!(text, "#OO\n#..\n");
+    }
+
+    #[test]
+    fn test_render_ansi() {
+        let text = String::from_utf8(render_to_bytes(RenderFormat::Ansi, 1)).unwrap();
+
+        assert!(text.starts_with("test\n"));
+        assert_eq!(text.lines().count(), 3);
+        assert_eq!(text.matches("\x1b[48;2;255;215;0m").count(), 2);
+    }
+
+    #[test]
+    fn test_render_ppm() {
+        let bytes = render_to_bytes(RenderFormat::Ppm, 2);
+        let header = b"P6\n6 4\n255\n";
+
+        assert!(bytes.starts_with(header));
+        assert_eq!(bytes.len(), header.len() + 6 * 4 * 3);
+        // Second tile of the first row is covered by the path
+        assert_eq!(&bytes[header.len() + 6..header.len() + 9], &[255, 215, 0]);
+    }
+
+    #[test]
+    fn test_render_png() {
+        let bytes = render_to_bytes(RenderFormat::Png, 2);
+
+        let decoder = png::Decoder::new(bytes.as_slice());
+        let reader = decoder.read_info().expect("Failed to decode PNG");
+        assert_eq!((reader.info().width, reader.info().height), (6, 4));
+    }
+}
@@ -0,0 +1,17 @@
+use std::fmt::Display;
+
+use super::{aoc_error::AocError, part::Part, render::frame::Frame};
+
+/// Solver which draws its grid with overlays, e.g. paths, regions or antinodes
+pub trait Renderable {
+    type Error: Display;
+
+    fn frames(&self, part: Part) -> Result<Vec<Frame>, Self::Error>;
+}
+
+/// Frames of the part, errors of the day are reported as solver errors
+pub fn render<T: Renderable>(solver: &T, part: Part) -> Result<Vec<Frame>, AocError> {
+    solver
+        .frames(part)
+        .map_err(|err| AocError::SolverError(err.to_string()))
+}
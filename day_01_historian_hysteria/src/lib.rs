@@ -0,0 +1,19 @@
+pub mod puzzle;
+
+pub use advent_of_code::puzzles::puzzle_solver::PuzzleSolver;
+pub use puzzle::{
+    aggregation::Aggregation,
+    config::Config,
+    distance::Distance,
+    input_format::InputFormat,
+    location_lists::LocationLists,
+    locations::{Location, Locations},
+    metric::Metric,
+    occurrence::Occurrence,
+    pair::Pair,
+    pairing_report::PairingReport,
+    parser::Parser,
+    solver::Solver,
+};
+
+aoc_common::export_day!(1, Solver, configurable, parsable, explainable);
@@ -0,0 +1,99 @@
+use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use serde::Deserialize;
+
+use super::{distance::Distance, locations::Location, metric::Metric};
+
+/// Combination of distances of all pairs into a single distance of two lists
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Aggregation {
+    #[default]
+    Sum,
+    Max,
+    Mean,
+}
+
+impl Aggregation {
+    /// Distance of paired locations, exact for integer metrics unless the mean is taken
+    pub fn distance(
+        &self,
+        metric: Metric,
+        pairs: impl Iterator<Item = (Location, Location)>,
+    ) -> Result<Distance, PuzzleError> {
+        match self {
+            Aggregation::Sum if metric.is_exact() => pairs
+                .map(|(a, b)| metric.exact_distance(a, b))
+                .try_fold(0_u128, |sum, distance| sum.checked_add(distance?))
+                .map(Distance::Exact)
+                .ok_or_else(|| {
+                    PuzzleError::GenericError("Sum of distances overflows u128".to_string())
+                }),
+            Aggregation::Max if metric.is_exact() => Ok(Distance::Exact(
+                pairs
+                    .filter_map(|(a, b)| metric.exact_distance(a, b))
+                    .max()
+                    .unwrap_or_default(),
+            )),
+            _ => Ok(Distance::Approximate(
+                self.aggregate(pairs.map(|(a, b)| metric.distance(a, b))),
+            )),
+        }
+    }
+
+    /// Aggregate distances, empty lists have zero distance
+    pub fn aggregate(&self, distances: impl Iterator<Item = f64>) -> f64 {
+        match self {
+            Aggregation::Sum => distances.sum(),
+            Aggregation::Max => distances.fold(0.0, f64::max),
+            Aggregation::Mean => {
+                let (sum, count) = distances.fold((0.0, 0), |(sum, count), distance| {
+                    (sum + distance, count + 1)
+                });
+
+                match count {
+                    0 => 0.0,
+                    count => sum / count as f64,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aggregate() {
+        let distances = [2.0, 0.0, 4.0];
+
+        assert_eq!(Aggregation::Sum.aggregate(distances.into_iter()), 6.0);
+        assert_eq!(Aggregation::Max.aggregate(distances.into_iter()), 4.0);
+        assert_eq!(Aggregation::Mean.aggregate(distances.into_iter()), 2.0);
+        assert_eq!(Aggregation::Mean.aggregate([].into_iter()), 0.0);
+    }
+
+    #[test]
+    fn test_distance() {
+        let pairs = [(1, 4), (i64::MAX, -1), (-5, -5)];
+        let distance =
+            |aggregation: Aggregation, metric| aggregation.distance(metric, pairs.into_iter());
+
+        assert_eq!(
+            distance(Aggregation::Sum, Metric::Absolute),
+            Ok(Distance::Exact(3 + (1_u128 << 63)))
+        );
+        assert_eq!(
+            distance(Aggregation::Max, Metric::Squared),
+            Ok(Distance::Exact(1_u128 << 126))
+        );
+        assert!(matches!(
+            distance(Aggregation::Mean, Metric::Absolute),
+            Ok(Distance::Approximate(_))
+        ));
+        assert!(distance(Aggregation::Sum, Metric::Squared).is_ok());
+        assert!(Aggregation::Sum
+            .distance(Metric::Squared, [(i64::MIN, i64::MAX); 2].into_iter())
+            .is_err());
+    }
+}
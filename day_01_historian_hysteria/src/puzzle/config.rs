@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+use super::{aggregation::Aggregation, input_format::InputFormat, metric::Metric};
+
+/// Which lists are paired and how their distance is measured, defaults solve the puzzle
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Distance of paired locations in part 1
+    pub metric: Metric,
+    /// Combination of distances of all pairs in part 1
+    pub aggregation: Aggregation,
+    /// Zero based columns of the input compared by both parts
+    pub lists: [usize; 2],
+    /// Layout of the input, detected from the first line by default
+    pub format: InputFormat,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            metric: Metric::Absolute,
+            aggregation: Aggregation::Sum,
+            lists: [0, 1],
+            format: InputFormat::Auto,
+        }
+    }
+}
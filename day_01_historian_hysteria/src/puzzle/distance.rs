@@ -0,0 +1,17 @@
+use std::fmt::Display;
+
+/// Distance of two lists, integer metrics summed or maximized are kept exact
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Distance {
+    Exact(u128),
+    Approximate(f64),
+}
+
+impl Display for Distance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Distance::Exact(distance) => write!(f, "{}", distance),
+            Distance::Approximate(distance) => write!(f, "{}", distance),
+        }
+    }
+}
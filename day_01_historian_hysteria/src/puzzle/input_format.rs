@@ -0,0 +1,69 @@
+use serde::Deserialize;
+
+/// Layout of the location lists in the input
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InputFormat {
+    /// Detect the format from the first line
+    #[default]
+    Auto,
+    /// Columns separated by any whitespace, the format of the puzzle input
+    Whitespace,
+    /// Comma separated columns with an optional header
+    Csv,
+    /// Tab separated columns with an optional header
+    Tsv,
+    /// Array of rows, every row is an array of locations, e.g. `[[3, 4], [4, 3]]`
+    Json,
+}
+
+impl InputFormat {
+    /// Resolve `Auto` from the first non blank line, other formats are kept
+    pub fn detect(&self, lines: &[&str]) -> InputFormat {
+        match self {
+            InputFormat::Auto => {
+                let first = lines
+                    .iter()
+                    .find(|line| !line.trim().is_empty())
+                    .map_or("", |line| line.trim());
+
+                if first.starts_with('[') {
+                    InputFormat::Json
+                } else if first.contains('\t') {
+                    InputFormat::Tsv
+                } else if first.contains(',') {
+                    InputFormat::Csv
+                } else {
+                    InputFormat::Whitespace
+                }
+            }
+            format => *format,
+        }
+    }
+
+    /// Separator of columns, whitespace and JSON have none
+    pub fn separator(&self) -> Option<char> {
+        match self {
+            InputFormat::Csv => Some(','),
+            InputFormat::Tsv => Some('\t'),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        let detect = |lines: &[&str]| InputFormat::Auto.detect(lines);
+
+        assert_eq!(detect(&["3   4", "4   3"]), InputFormat::Whitespace);
+        assert_eq!(detect(&["left,right", "3,4"]), InputFormat::Csv);
+        assert_eq!(detect(&["left\tright", "3\t4"]), InputFormat::Tsv);
+        assert_eq!(detect(&["", "[[3, 4],", "[4, 3]]"]), InputFormat::Json);
+        assert_eq!(detect(&[]), InputFormat::Whitespace);
+        assert_eq!(InputFormat::Csv.detect(&["3   4"]), InputFormat::Csv);
+    }
+}
@@ -0,0 +1,223 @@
+use advent_of_code::puzzles::puzzle_error::PuzzleError;
+
+use super::{
+    aggregation::Aggregation,
+    distance::Distance,
+    locations::{Location, Locations},
+    metric::Metric,
+};
+
+/// Lists of locations from columns of the input, every list has the same length
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LocationLists {
+    lists: Vec<Vec<Location>>,
+    // Lists sorted for pairing, original order is kept for line numbers
+    sorted: Vec<Vec<Location>>,
+    // One based input line of every row
+    lines: Vec<usize>,
+}
+
+impl LocationLists {
+    pub fn new(lists: Vec<Vec<Location>>) -> Result<Self, PuzzleError> {
+        if let Some(first) = lists.first() {
+            if let Some((index, list)) = lists
+                .iter()
+                .enumerate()
+                .find(|(_, list)| list.len() != first.len())
+            {
+                return Err(PuzzleError::InvalidContentError(format!(
+                    "Length of lists differs, list 0: {}, list {}: {}",
+                    first.len(),
+                    index,
+                    list.len()
+                )));
+            }
+        }
+
+        let mut sorted = lists.clone();
+        sorted.iter_mut().for_each(|list| list.sort());
+
+        let lines = (1..=lists.first().map_or(0, Vec::len)).collect();
+
+        Ok(Self {
+            lists,
+            sorted,
+            lines,
+        })
+    }
+
+    /// Set input lines of rows when they differ from positions, e.g. due to a header
+    pub fn with_lines(mut self, lines: Vec<usize>) -> Result<Self, PuzzleError> {
+        if lines.len() != self.lines.len() {
+            return Err(PuzzleError::InvalidContentError(format!(
+                "Expected {} lines of rows but {} found",
+                self.lines.len(),
+                lines.len()
+            )));
+        }
+
+        self.lines = lines;
+        Ok(self)
+    }
+
+    pub fn get_lines(&self) -> &[usize] {
+        &self.lines
+    }
+
+    pub fn get_count(&self) -> usize {
+        self.lists.len()
+    }
+
+    /// List in the order of the input
+    pub fn get_list(&self, index: usize) -> Result<&[Location], PuzzleError> {
+        self.lists
+            .get(index)
+            .map(Vec::as_slice)
+            .ok_or_else(|| self.missing(index))
+    }
+
+    fn get_sorted_list(&self, index: usize) -> Result<&[Location], PuzzleError> {
+        self.sorted
+            .get(index)
+            .map(Vec::as_slice)
+            .ok_or_else(|| self.missing(index))
+    }
+
+    fn missing(&self, index: usize) -> PuzzleError {
+        PuzzleError::GenericError(format!(
+            "List {} does not exist, input has {} lists",
+            index,
+            self.lists.len()
+        ))
+    }
+
+    /// Pair two of the lists to get their scores
+    pub fn to_locations(&self, left: usize, right: usize) -> Result<Locations, PuzzleError> {
+        Locations::on_lines(
+            self.get_list(left)?.to_vec(),
+            self.get_list(right)?.to_vec(),
+            self.lines.clone(),
+        )
+    }
+
+    pub fn get_distance(
+        &self,
+        left: usize,
+        right: usize,
+        metric: Metric,
+        aggregation: Aggregation,
+    ) -> Result<Distance, PuzzleError> {
+        let left = self.get_sorted_list(left)?;
+        let right = self.get_sorted_list(right)?;
+
+        aggregation.distance(metric, left.iter().copied().zip(right.iter().copied()))
+    }
+
+    /// Distances between every two lists, the matrix is symmetric with zeros on the diagonal
+    pub fn get_distance_matrix(
+        &self,
+        metric: Metric,
+        aggregation: Aggregation,
+    ) -> Result<Vec<Vec<Distance>>, PuzzleError> {
+        (0..self.lists.len())
+            .map(|left| {
+                (0..self.lists.len())
+                    .map(|right| self.get_distance(left, right, metric, aggregation))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_lists() -> LocationLists {
+        LocationLists::new(vec![
+            vec![3, 4, 2, 1, 3, 3],
+            vec![4, 3, 5, 3, 9, 3],
+            vec![1, 2, 3, 4, 5, 6],
+        ])
+        .expect("Failed to create lists")
+    }
+
+    #[test]
+    fn test_new_length_mismatch() {
+        let result = LocationLists::new(vec![vec![1, 2], vec![3, 4], vec![5]]);
+
+        assert!(
+            matches!(result, Err(PuzzleError::InvalidContentError(_))),
+            "Result: {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_get_distance() {
+        let lists = create_lists();
+
+        let distance = |metric, aggregation| lists.get_distance(0, 1, metric, aggregation).ok();
+
+        assert_eq!(
+            distance(Metric::Absolute, Aggregation::Sum),
+            Some(Distance::Exact(11))
+        );
+        assert_eq!(
+            distance(Metric::Absolute, Aggregation::Max),
+            Some(Distance::Exact(5))
+        );
+        assert_eq!(
+            distance(Metric::Squared, Aggregation::Sum),
+            Some(Distance::Exact(35))
+        );
+        assert_eq!(
+            distance(Metric::Squared, Aggregation::Mean),
+            Some(Distance::Approximate(35.0 / 6.0))
+        );
+        assert!(lists
+            .get_distance(0, 3, Metric::Absolute, Aggregation::Sum)
+            .is_err());
+    }
+
+    #[test]
+    fn test_get_distance_matrix() {
+        let matrix = create_lists()
+            .get_distance_matrix(Metric::Absolute, Aggregation::Sum)
+            .unwrap();
+
+        assert_eq!(
+            matrix,
+            [[0, 11, 5], [11, 0, 6], [5, 6, 0]]
+                .map(|row| row.map(Distance::Exact).to_vec())
+                .to_vec()
+        );
+    }
+
+    #[test]
+    fn test_to_locations() {
+        let locations = create_lists().to_locations(0, 1).unwrap();
+
+        assert_eq!(locations.get_total_distance(), 11);
+        assert_eq!(locations.get_similarity_score(), 31);
+        assert!(create_lists().to_locations(1, 3).is_err());
+    }
+
+    #[test]
+    fn test_to_locations_with_lines() {
+        let lists = LocationLists::new(vec![vec![5, 1], vec![2, 4]])
+            .and_then(|lists| lists.with_lines(vec![2, 4]))
+            .unwrap();
+
+        let pairs = lists.to_locations(0, 1).unwrap().get_pairs();
+
+        assert_eq!(
+            pairs
+                .iter()
+                .map(|pair| (pair.left_line, pair.right_line))
+                .collect::<Vec<_>>(),
+            vec![(4, 2), (2, 4)]
+        );
+        assert!(lists.with_lines(vec![1]).is_err());
+    }
+}
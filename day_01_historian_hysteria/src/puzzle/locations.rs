@@ -1,53 +1,257 @@
-pub type Location = usize;
+use std::{cmp::Ordering, collections::BTreeMap, iter};
 
+use advent_of_code::puzzles::puzzle_error::PuzzleError;
+
+use super::{occurrence::Occurrence, pair::Pair};
+
+pub type Location = i64;
+
+// One based input lines of every location in a list, keys and lines are kept sorted
+type Lines = BTreeMap<Location, Vec<usize>>;
+
+/// Two lists of locations paired in sorted order
+///
+/// Lists are stored as lines of every location, so the similarity score is computed in linear
+/// time and pairs can be inserted or removed without sorting the lists again. Both scores are
+/// kept current after every change.
 #[derive(Debug, PartialEq, Default)]
 pub struct Locations {
-    left: Vec<Location>,
-    right: Vec<Location>,
+    left: Lines,
+    right: Lines,
+    // Line of the last inserted pair, zero for empty lists
+    last_line: usize,
+    total_distance: u128,
+    similarity_score: i128,
 }
 
 impl Locations {
-    pub fn new(left: Vec<Location>, right: Vec<Location>) -> Self {
-        if left.len() != right.len() {
-            panic!(
-                "Length of lists differs, left: {}, right: {}",
+    /// Lists where the n-th locations are on the n-th line
+    pub fn new(left: Vec<Location>, right: Vec<Location>) -> Result<Self, PuzzleError> {
+        let lines = (1..=left.len()).collect();
+        Self::on_lines(left, right, lines)
+    }
+
+    /// Lists where both n-th locations come from the n-th of increasing input lines
+    pub fn on_lines(
+        left: Vec<Location>,
+        right: Vec<Location>,
+        lines: Vec<usize>,
+    ) -> Result<Self, PuzzleError> {
+        if left.len() != right.len() || left.len() != lines.len() {
+            return Err(PuzzleError::InvalidContentError(format!(
+                "Length of lists differs, left: {}, right: {}, lines: {}",
                 left.len(),
-                right.len()
-            );
+                right.len(),
+                lines.len()
+            )));
         }
 
-        // Sort collections
-        let mut left = left;
-        left.sort();
+        let mut locations = Self {
+            left: Self::index(&left, &lines),
+            right: Self::index(&right, &lines),
+            last_line: lines.last().copied().unwrap_or_default(),
+            ..Default::default()
+        };
+
+        locations.total_distance = locations.compute_total_distance();
+        locations.similarity_score = locations.compute_similarity_score();
 
-        let mut right = right;
-        right.sort();
+        Ok(locations)
+    }
+
+    fn index(list: &[Location], lines: &[usize]) -> Lines {
+        let mut index = Lines::new();
+
+        for (location, line) in list.iter().zip(lines) {
+            index.entry(*location).or_default().push(*line);
+        }
 
-        Self { left, right }
+        index
     }
 
-    pub fn get_left(&self) -> &Vec<Location> {
-        &self.left
+    fn count(lines: &Lines, location: Location) -> usize {
+        lines.get(&location).map_or(0, Vec::len)
     }
 
-    pub fn get_right(&self) -> &Vec<Location> {
-        &self.right
+    pub fn get_left(&self) -> Vec<Location> {
+        Self::expand(&self.left).collect()
     }
 
-    pub fn get_total_distance(&self) -> usize {
-        self.left
+    pub fn get_right(&self) -> Vec<Location> {
+        Self::expand(&self.right).collect()
+    }
+
+    // Sorted list with every location repeated by its count
+    fn expand(lines: &Lines) -> impl Iterator<Item = Location> + '_ {
+        lines
             .iter()
-            .zip(self.right.iter())
-            .map(|(l, r)| if l > r { l - r } else { r - l })
+            .flat_map(|(location, lines)| iter::repeat_n(*location, lines.len()))
+    }
+
+    // Sorted list of locations with their lines, equal locations are ordered by lines
+    fn expand_lines(lines: &Lines) -> impl Iterator<Item = (Location, usize)> + '_ {
+        lines
+            .iter()
+            .flat_map(|(location, lines)| lines.iter().map(|line| (*location, *line)))
+    }
+
+    /// Pairs of the sorted lists, which sum up to the total distance
+    pub fn get_pairs(&self) -> Vec<Pair> {
+        Self::expand_lines(&self.left)
+            .zip(Self::expand_lines(&self.right))
+            .map(|((left, left_line), (right, right_line))| Pair {
+                left,
+                right,
+                distance: left.abs_diff(right),
+                left_line,
+                right_line,
+            })
+            .collect()
+    }
+
+    /// Counts of every location in both lists, which sum up to the similarity score
+    pub fn get_occurrences(&self) -> Vec<Occurrence> {
+        let mut locations = self
+            .left
+            .keys()
+            .chain(self.right.keys())
+            .copied()
+            .collect::<Vec<_>>();
+        locations.sort_unstable();
+        locations.dedup();
+
+        locations
+            .into_iter()
+            .map(|location| {
+                let left_count = Self::count(&self.left, location);
+                let right_count = Self::count(&self.right, location);
+
+                Occurrence {
+                    location,
+                    left_count,
+                    right_count,
+                    similarity: location as i128 * left_count as i128 * right_count as i128,
+                }
+            })
+            .collect()
+    }
+
+    pub fn get_total_distance(&self) -> u128 {
+        self.total_distance
+    }
+
+    pub fn get_similarity_score(&self) -> i128 {
+        self.similarity_score
+    }
+
+    /// Insert a pair as the next line of the lists
+    pub fn insert_pair(&mut self, left: Location, right: Location) {
+        // Sorted pairing changes only between both locations, see `shift_distance`
+        let delta = match left.cmp(&right) {
+            Ordering::Less => self.shift_distance(left, right, 1),
+            Ordering::Greater => self.shift_distance(right, left, -1),
+            Ordering::Equal => 0,
+        };
+        self.total_distance = self.total_distance.saturating_add_signed(delta);
+
+        self.last_line += 1;
+
+        self.similarity_score += left as i128 * Self::count(&self.right, left) as i128;
+        self.left.entry(left).or_default().push(self.last_line);
+
+        self.similarity_score += right as i128 * Self::count(&self.left, right) as i128;
+        self.right.entry(right).or_default().push(self.last_line);
+    }
+
+    /// Remove both locations, the last inserted lines of them are removed
+    pub fn remove_pair(&mut self, left: Location, right: Location) -> Result<(), PuzzleError> {
+        for (list, counts, location) in [("left", &self.left, left), ("right", &self.right, right)]
+        {
+            if !counts.contains_key(&location) {
+                return Err(PuzzleError::GenericError(format!(
+                    "Location {} is not in the {} list",
+                    location, list
+                )));
+            }
+        }
+
+        // Distance is shifted against the current lists, before the locations are removed
+        let delta = match left.cmp(&right) {
+            Ordering::Less => self.shift_distance(left, right, -1),
+            Ordering::Greater => self.shift_distance(right, left, 1),
+            Ordering::Equal => 0,
+        };
+        self.total_distance = self.total_distance.saturating_add_signed(delta);
+
+        Self::decrement(&mut self.right, right);
+        self.similarity_score -= right as i128 * Self::count(&self.left, right) as i128;
+
+        Self::decrement(&mut self.left, left);
+        self.similarity_score -= left as i128 * Self::count(&self.right, left) as i128;
+
+        Ok(())
+    }
+
+    fn decrement(lines: &mut Lines, location: Location) {
+        if let Some(location_lines) = lines.get_mut(&location) {
+            location_lines.pop();
+
+            if location_lines.is_empty() {
+                lines.remove(&location);
+            }
+        }
+    }
+
+    // The total distance of sorted pairs equals the sum of |difference| over all x, where the
+    // difference is the count of left locations <= x minus the count of right locations <= x.
+    // Adding a pair changes the difference by `step` only on the range [from, to), so the change
+    // of the total distance is computed from locations within the range.
+    fn shift_distance(&self, from: Location, to: Location, step: i128) -> i128 {
+        let mut breakpoints = self
+            .left
+            .range(from + 1..to)
+            .chain(self.right.range(from + 1..to))
+            .map(|(location, _)| *location)
+            .collect::<Vec<_>>();
+        breakpoints.sort_unstable();
+        breakpoints.dedup();
+        breakpoints.push(to);
+
+        let mut difference = Self::count_up_to(&self.left, from) as i128
+            - Self::count_up_to(&self.right, from) as i128;
+        let mut position = from;
+        let mut delta = 0;
+
+        for breakpoint in breakpoints {
+            let width = breakpoint as i128 - position as i128;
+            delta += ((difference + step).abs() - difference.abs()) * width;
+
+            difference += Self::count(&self.left, breakpoint) as i128
+                - Self::count(&self.right, breakpoint) as i128;
+            position = breakpoint;
+        }
+
+        delta
+    }
+
+    fn count_up_to(lines: &Lines, location: Location) -> usize {
+        lines.range(..=location).map(|(_, lines)| lines.len()).sum()
+    }
+
+    fn compute_total_distance(&self) -> u128 {
+        Self::expand(&self.left)
+            .zip(Self::expand(&self.right))
+            .map(|(l, r)| l.abs_diff(r) as u128)
             .sum()
     }
 
-    pub fn get_similarity_score(&self) -> usize {
+    fn compute_similarity_score(&self) -> i128 {
         self.left
             .iter()
-            .map(|l| {
-                let right_occurrences = self.right.iter().filter(|&r| l == r).count();
-                l * right_occurrences
+            .map(|(location, lines)| {
+                *location as i128
+                    * lines.len() as i128
+                    * Self::count(&self.right, *location) as i128
             })
             .sum()
     }
@@ -59,6 +263,29 @@ mod tests {
 
     fn create_locations() -> Locations {
         Locations::new(vec![3, 4, 2, 1, 3, 3], vec![4, 3, 5, 3, 9, 3])
+            .expect("Failed to create locations")
+    }
+
+    fn assert_scores(locations: &Locations) {
+        assert_eq!(
+            locations.get_total_distance(),
+            locations.compute_total_distance()
+        );
+        assert_eq!(
+            locations.get_similarity_score(),
+            locations.compute_similarity_score()
+        );
+    }
+
+    #[test]
+    fn test_new_length_mismatch() {
+        let result = Locations::new(vec![1, 2], vec![1]);
+
+        assert!(
+            matches!(result, Err(PuzzleError::InvalidContentError(_))),
+            "Result: {:?}",
+            result
+        );
     }
 
     #[test]
@@ -72,4 +299,162 @@ mod tests {
         let locations = create_locations();
         assert_eq!(locations.get_similarity_score(), 31);
     }
+
+    #[test]
+    fn test_get_pairs() {
+        let pairs = create_locations().get_pairs();
+
+        assert_eq!(pairs.len(), 6);
+        assert_eq!(
+            pairs[0],
+            Pair {
+                left: 1,
+                right: 3,
+                distance: 2,
+                left_line: 4,
+                right_line: 2
+            }
+        );
+        assert_eq!(
+            pairs
+                .iter()
+                .map(|pair| (pair.left_line, pair.right_line))
+                .collect::<Vec<_>>(),
+            vec![(4, 2), (3, 4), (1, 6), (5, 1), (6, 3), (2, 5)]
+        );
+        assert_eq!(pairs.iter().map(|pair| pair.distance).sum::<u64>(), 11);
+    }
+
+    #[test]
+    fn test_get_occurrences() {
+        let occurrences = create_locations().get_occurrences();
+
+        assert_eq!(
+            occurrences
+                .iter()
+                .map(|o| (o.location, o.left_count, o.right_count, o.similarity))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, 1, 0, 0),
+                (2, 1, 0, 0),
+                (3, 3, 3, 27),
+                (4, 1, 1, 4),
+                (5, 0, 1, 0),
+                (9, 0, 1, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_insert_pair() {
+        let mut locations = Locations::default();
+
+        for (left, right) in [(3, 4), (4, 3), (2, 5), (1, 3), (3, 9), (3, 3)] {
+            locations.insert_pair(left, right);
+            assert_scores(&locations);
+        }
+
+        assert_eq!(locations, create_locations());
+        assert_eq!(locations.get_left(), vec![1, 2, 3, 3, 3, 4]);
+        assert_eq!(locations.get_right(), vec![3, 3, 3, 4, 5, 9]);
+    }
+
+    #[test]
+    fn test_remove_pair() {
+        let mut locations = create_locations();
+
+        for (left, right) in [(3, 9), (1, 3), (4, 4), (2, 3), (3, 5), (3, 3)] {
+            locations.remove_pair(left, right).unwrap();
+            assert_scores(&locations);
+        }
+
+        assert!(locations.get_pairs().is_empty());
+        assert_eq!(locations.get_total_distance(), 0);
+        assert_eq!(locations.get_similarity_score(), 0);
+    }
+
+    #[test]
+    fn test_remove_pair_missing() {
+        let mut locations = create_locations();
+
+        assert!(locations.remove_pair(7, 3).is_err());
+        assert!(locations.remove_pair(3, 7).is_err());
+        assert_eq!(locations, create_locations());
+    }
+
+    #[test]
+    fn test_insert_remove_pair_extreme() {
+        let mut locations = Locations::default();
+        let pairs = [
+            (Location::MIN, Location::MAX),
+            (Location::MAX, Location::MIN),
+            (Location::MAX, Location::MAX),
+            (0, Location::MIN),
+        ];
+
+        locations.insert_pair(Location::MIN, Location::MAX);
+        assert_eq!(locations.get_total_distance(), u64::MAX as u128);
+
+        for (left, right) in pairs.into_iter().skip(1) {
+            locations.insert_pair(left, right);
+            assert_scores(&locations);
+        }
+
+        assert_eq!(locations.get_total_distance(), 1 << 63);
+        assert_eq!(
+            locations.get_similarity_score(),
+            2 * Location::MIN as i128 + 4 * Location::MAX as i128
+        );
+
+        for (left, right) in pairs.into_iter().rev() {
+            locations.remove_pair(left, right).unwrap();
+            assert_scores(&locations);
+        }
+    }
+
+    #[test]
+    fn test_insert_remove_pair_many() {
+        let mut locations = Locations::default();
+
+        // Deterministic pseudo random pairs with many duplicates
+        let pairs = (0..200_i64)
+            .map(|i| ((i * 37 + 11) % 23, (i * 53 + 7) % 19))
+            .collect::<Vec<_>>();
+
+        // Lists kept alongside to know whether a removal has to succeed
+        let mut left_list = Vec::new();
+        let mut right_list = Vec::new();
+
+        for (left, right) in &pairs {
+            locations.insert_pair(*left, *right);
+            left_list.push(*left);
+            right_list.push(*right);
+            assert_scores(&locations);
+        }
+
+        for (left, right) in pairs.iter().step_by(3) {
+            for (left, right) in [(*right % 23, *left % 19), (*left, *right)] {
+                let left_index = left_list.iter().position(|&l| l == left);
+                let right_index = right_list.iter().position(|&r| r == right);
+
+                let result = locations.remove_pair(left, right);
+
+                match (left_index, right_index) {
+                    (Some(left_index), Some(right_index)) => {
+                        assert!(result.is_ok(), "Pair ({}, {}): {:?}", left, right, result);
+                        left_list.swap_remove(left_index);
+                        right_list.swap_remove(right_index);
+                    }
+                    _ => assert!(result.is_err(), "Pair ({}, {}) is not present", left, right),
+                }
+
+                assert_scores(&locations);
+            }
+        }
+
+        left_list.sort();
+        right_list.sort();
+        assert_eq!(locations.get_left(), left_list);
+        assert_eq!(locations.get_right(), right_list);
+    }
 }
@@ -0,0 +1,59 @@
+use serde::Deserialize;
+
+use super::locations::Location;
+
+/// Distance between two paired locations
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Metric {
+    #[default]
+    Absolute,
+    Squared,
+    /// Absolute difference divided by the bigger absolute value of both locations
+    Relative,
+}
+
+impl Metric {
+    /// Integer distance, None for metrics with fractional distances
+    pub fn exact_distance(&self, a: Location, b: Location) -> Option<u128> {
+        let difference = a.abs_diff(b) as u128;
+
+        match self {
+            Metric::Absolute => Some(difference),
+            Metric::Squared => Some(difference * difference),
+            Metric::Relative => None,
+        }
+    }
+
+    pub fn is_exact(&self) -> bool {
+        *self != Metric::Relative
+    }
+
+    pub fn distance(&self, a: Location, b: Location) -> f64 {
+        match self.exact_distance(a, b) {
+            Some(distance) => distance as f64,
+            None => match a.unsigned_abs().max(b.unsigned_abs()) {
+                0 => 0.0,
+                bigger => a.abs_diff(b) as f64 / bigger as f64,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(Metric::Absolute.distance(3, 7), 4.0);
+        assert_eq!(Metric::Absolute.distance(7, 3), 4.0);
+        assert_eq!(Metric::Squared.distance(3, 7), 16.0);
+        assert_eq!(Metric::Relative.distance(6, 8), 0.25);
+        assert_eq!(Metric::Relative.distance(0, 0), 0.0);
+        assert_eq!(Metric::Absolute.distance(-3, 7), 10.0);
+        assert_eq!(Metric::Relative.distance(-8, 6), 1.75);
+        assert_eq!(Metric::Squared.exact_distance(-3, 7), Some(100));
+        assert_eq!(Metric::Relative.exact_distance(-3, 7), None);
+    }
+}
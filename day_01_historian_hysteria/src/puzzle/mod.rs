@@ -1,3 +1,12 @@
+pub mod aggregation;
+pub mod config;
+pub mod distance;
+pub mod input_format;
+pub mod location_lists;
 pub mod locations;
+pub mod metric;
+pub mod occurrence;
+pub mod pair;
+pub mod pairing_report;
 pub mod parser;
 pub mod solver;
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use super::locations::Location;
+
+/// Counts of a location in both lists and its part of the similarity score
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Occurrence {
+    pub location: Location,
+    pub left_count: usize,
+    pub right_count: usize,
+    pub similarity: i128,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use super::locations::Location;
+
+/// Locations matched by sorting both lists, lines are the one based lines of the input
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pair {
+    pub left: Location,
+    pub right: Location,
+    pub distance: u64,
+    pub left_line: usize,
+    pub right_line: usize,
+}
@@ -0,0 +1,92 @@
+use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use serde::{Deserialize, Serialize};
+
+use super::{locations::Locations, occurrence::Occurrence, pair::Pair};
+
+/// Matches behind the total distance and counts behind the similarity score
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PairingReport {
+    pub pairs: Vec<Pair>,
+    pub occurrences: Vec<Occurrence>,
+}
+
+impl PairingReport {
+    pub fn new(locations: &Locations) -> Self {
+        Self {
+            pairs: locations.get_pairs(),
+            occurrences: locations.get_occurrences(),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, PuzzleError> {
+        serde_json::to_string_pretty(self).map_err(|err| {
+            PuzzleError::GenericError(format!("Failed to serialize pairing report [{}]", err))
+        })
+    }
+
+    pub fn pairs_to_csv(&self) -> String {
+        let mut csv = String::from("left,right,distance,left_line,right_line\n");
+
+        for pair in &self.pairs {
+            csv += &format!(
+                "{},{},{},{},{}\n",
+                pair.left, pair.right, pair.distance, pair.left_line, pair.right_line
+            );
+        }
+
+        csv
+    }
+
+    pub fn occurrences_to_csv(&self) -> String {
+        let mut csv = String::from("location,left_count,right_count,similarity\n");
+
+        for occurrence in &self.occurrences {
+            csv += &format!(
+                "{},{},{},{}\n",
+                occurrence.location,
+                occurrence.left_count,
+                occurrence.right_count,
+                occurrence.similarity
+            );
+        }
+
+        csv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_report() -> PairingReport {
+        let locations = Locations::new(vec![3, 4, 2], vec![4, 3, 5]).unwrap();
+        PairingReport::new(&locations)
+    }
+
+    #[test]
+    fn test_pairs_to_csv() {
+        assert_eq!(
+            create_report().pairs_to_csv(),
+            "left,right,distance,left_line,right_line\n2,3,1,3,2\n3,4,1,1,1\n4,5,1,2,3\n"
+        );
+    }
+
+    #[test]
+    fn test_occurrences_to_csv() {
+        assert_eq!(
+            create_report().occurrences_to_csv(),
+            "location,left_count,right_count,similarity\n2,1,0,0\n3,1,1,3\n4,1,1,4\n5,0,1,0\n"
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let report = create_report();
+        let json = report.to_json().unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<PairingReport>(&json).unwrap(),
+            report
+        );
+    }
+}
@@ -1,54 +1,171 @@
-use std::sync::LazyLock;
+use std::{ops::Range, sync::LazyLock};
 
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::parse_error::ParseError;
 use regex::Regex;
+use serde_json::value::RawValue;
 
-use super::locations::Locations;
+use super::{input_format::InputFormat, location_lists::LocationLists, locations::Location};
+
+// Locations of a row together with its one based line of the input
+type Row = (usize, Vec<Location>);
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<Locations, PuzzleError> {
-        let mut left = Vec::new();
-        let mut right = Vec::new();
+    pub fn parse_lines(lines: &[&str], format: InputFormat) -> Result<LocationLists, ParseError> {
+        let rows = match format.detect(lines) {
+            InputFormat::Json => Self::decode_json(lines)?,
+            format => Self::decode_rows(lines, format.separator())?,
+        };
+
+        let mut lists = vec![Vec::new(); rows.first().map_or(0, |(_, row)| row.len())];
+        let mut row_lines = Vec::with_capacity(rows.len());
+
+        for (line, row) in rows {
+            for (list, location) in lists.iter_mut().zip(row) {
+                list.push(location);
+            }
+            row_lines.push(line);
+        }
+
+        LocationLists::new(lists)
+            .and_then(|lists| lists.with_lines(row_lines))
+            .map_err(|err| ParseError::new(err.to_string()))
+    }
+
+    fn decode_rows(lines: &[&str], separator: Option<char>) -> Result<Vec<Row>, ParseError> {
+        let mut rows: Vec<Row> = Vec::new();
+
+        for (index, line) in lines.iter().enumerate() {
+            // Separated values may start with a header, which has no numbers
+            if index == 0
+                && separator.is_some()
+                && Self::split_fields(line, separator)
+                    .iter()
+                    .all(|(field, _)| field.parse::<Location>().is_err())
+            {
+                continue;
+            }
+
+            let row = Self::decode_line(line, separator).map_err(|err| err.with_line(index))?;
+
+            if let Some((_, first)) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(ParseError::new(format!(
+                        "Expected {} columns but {} found",
+                        first.len(),
+                        row.len()
+                    ))
+                    .with_whole_line(line)
+                    .with_line(index));
+                }
+            }
+
+            rows.push((index + 1, row));
+        }
+
+        Ok(rows)
+    }
+
+    fn decode_json(lines: &[&str]) -> Result<Vec<Row>, ParseError> {
+        let content = lines.join("\n");
 
-        for line in lines {
-            let (l, r) = Self::decode_line(line)?;
+        // Rows are kept raw first, their position in the content gives their line
+        let raw_rows: Vec<&RawValue> = serde_json::from_str(&content).map_err(|err| {
+            ParseError::new(format!(
+                "Failed to parse JSON array of rows with an error '{}'",
+                err
+            ))
+            .with_line(err.line().saturating_sub(1))
+        })?;
 
-            left.push(l);
-            right.push(r);
+        let rows = raw_rows
+            .into_iter()
+            .map(|raw| {
+                let offset = raw.get().as_ptr() as usize - content.as_ptr() as usize;
+                let line = content[..offset].matches('\n').count() + 1;
+
+                serde_json::from_str::<Vec<Location>>(raw.get())
+                    .map(|row| (line, row))
+                    .map_err(|err| {
+                        ParseError::new(format!("Failed to parse JSON row with an error '{}'", err))
+                            .with_line(line - 1)
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let columns = rows.first().map_or(0, |(_, row)| row.len());
+
+        if columns < 2 {
+            return Err(ParseError::new(format!(
+                "Expected at least 2 locations in a row of JSON but {} found",
+                columns
+            )));
+        }
+
+        if let Some((line, row)) = rows.iter().find(|(_, row)| row.len() != columns) {
+            return Err(ParseError::new(format!(
+                "Row of JSON has {} locations but {} expected",
+                row.len(),
+                columns
+            ))
+            .with_line(line - 1));
         }
 
-        Ok(Locations::new(left, right))
+        Ok(rows)
     }
 
-    fn decode_line(line: &str) -> Result<(usize, usize), PuzzleError> {
-        static RE: LazyLock<Regex> = LazyLock::new(|| {
-            Regex::new(r"^(\w+)\s+(\w+)").expect("Failed to create 'Location' regex")
-        });
-
-        if let Some(captures) = RE.captures(line) {
-            let left = captures[1].parse::<usize>().map_err(|err| {
-                PuzzleError::InvalidContentError(format!(
-                    "Failed to parse 'left' '{}' to usize with an error '{}'",
-                    &captures[1], err
-                ))
-            })?;
-
-            let right = captures[2].parse::<usize>().map_err(|err| {
-                PuzzleError::InvalidContentError(format!(
-                    "Failed to parse 'right' '{}' to usize with an error '{}'",
-                    &captures[2], err
-                ))
-            })?;
-
-            return Ok((left, right));
+    fn decode_line(line: &str, separator: Option<char>) -> Result<Vec<Location>, ParseError> {
+        let locations = Self::split_fields(line, separator)
+            .into_iter()
+            .enumerate()
+            .map(|(column, (field, range))| {
+                field.parse::<Location>().map_err(|err| {
+                    ParseError::new(format!(
+                        "Failed to parse column {} '{}' to i64 with an error '{}'",
+                        column, field, err
+                    ))
+                    .with_span(line, range)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if locations.len() < 2 {
+            return Err(ParseError::new(format!(
+                "Expected at least 2 columns of locations but {} found",
+                locations.len()
+            ))
+            .with_whole_line(line));
         }
 
-        Err(PuzzleError::InvalidContentError(format!(
-            "Failed to decode 'Location' from line '{}'",
-            line
-        )))
+        Ok(locations)
+    }
+
+    // Trimmed fields of a line with their byte ranges, whitespace separates fields if None
+    fn split_fields(line: &str, separator: Option<char>) -> Vec<(&str, Range<usize>)> {
+        static RE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"\S+").expect("Failed to create 'Location' regex"));
+
+        match separator {
+            None => RE
+                .find_iter(line)
+                .map(|m| (m.as_str(), m.range()))
+                .collect(),
+            Some(separator) => {
+                let mut start = 0;
+
+                line.split(separator)
+                    .map(|field| {
+                        let leading = field.len() - field.trim_start().len();
+                        let trimmed = field.trim();
+                        let range = start + leading..start + leading + trimmed.len();
+
+                        start += field.len() + separator.len_utf8();
+                        (trimmed, range)
+                    })
+                    .collect()
+            }
+        }
     }
 }
 
@@ -56,22 +173,113 @@ impl Parser {
 mod tests {
     use super::*;
 
+    fn parse(lines: &[&str]) -> Result<LocationLists, ParseError> {
+        Parser::parse_lines(lines, InputFormat::Auto)
+    }
+
     #[test]
     fn test_parse_lines() {
-        let lines = ["1 2", "3 4", "5 6"];
+        let lines = ["1 2", "5 4", "3 6"];
 
-        let result = Parser::parse_lines(&lines);
+        let result = parse(&lines);
 
         assert!(result.is_ok(), "Result: {:?}", result);
-        assert_eq!(result.as_ref().unwrap().get_left(), &[1, 3, 5]);
-        assert_eq!(result.as_ref().unwrap().get_right(), &[2, 4, 6]);
+        assert_eq!(result.as_ref().unwrap().get_count(), 2);
+        assert_eq!(result.as_ref().unwrap().get_list(0).unwrap(), &[1, 5, 3]);
+        assert_eq!(result.as_ref().unwrap().get_list(1).unwrap(), &[2, 4, 6]);
     }
 
     #[test]
-    fn decode_line() {
-        let result = Parser::decode_line("42 24");
+    fn test_parse_lines_columns() {
+        let result = parse(&["1 2 3", "4 5 6"]);
 
         assert!(result.is_ok(), "Result: {:?}", result);
-        assert_eq!(result.as_ref().unwrap(), &(42, 24));
+        assert_eq!(result.as_ref().unwrap().get_count(), 3);
+        assert_eq!(result.as_ref().unwrap().get_list(2).unwrap(), &[3, 6]);
+    }
+
+    #[test]
+    fn test_parse_lines_columns_mismatch() {
+        let result = parse(&["1 2 3", "4 5"]);
+
+        assert!(result.is_err(), "Result: {:?}", result);
+        assert!(
+            result.unwrap_err().to_string().contains("line 2"),
+            "Missing location"
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_error_location() {
+        let result = parse(&["1 2", "3 x4"]);
+
+        assert!(result.is_err(), "Result: {:?}", result);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("line 2, columns 3-4"),
+            "Missing location"
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_separated() {
+        let expected = |result: Result<LocationLists, ParseError>| {
+            assert!(result.is_ok(), "Result: {:?}", result);
+            assert_eq!(result.as_ref().unwrap().get_list(0).unwrap(), &[-1, 5]);
+            assert_eq!(result.as_ref().unwrap().get_list(1).unwrap(), &[2, -4]);
+        };
+
+        expected(parse(&["left, right", "-1, 2", "5, -4"]));
+        expected(parse(&["-1,2", "5,-4"]));
+        expected(parse(&["left\tright", "-1\t2", "5\t-4"]));
+        expected(parse(&["[[-1, 2],", " [5, -4]]"]));
+        expected(Parser::parse_lines(
+            &["-1 2", "5 -4"],
+            InputFormat::Whitespace,
+        ));
+    }
+
+    #[test]
+    fn test_parse_lines_separated_error_location() {
+        let result = parse(&["left,right", "1,2", "3, x4"]);
+
+        assert!(result.is_err(), "Result: {:?}", result);
+        let err = result.unwrap_err();
+        assert_eq!(err.get_line(), Some(3));
+        assert_eq!(err.get_columns(), Some(&(4..6)));
+    }
+
+    #[test]
+    fn test_parse_lines_row_lines() {
+        let lines = |lines: &[&str]| parse(lines).unwrap().get_lines().to_vec();
+
+        assert_eq!(lines(&["1 2", "3 4"]), vec![1, 2]);
+        assert_eq!(lines(&["left,right", "1,2", "3,4"]), vec![2, 3]);
+        assert_eq!(lines(&["[", "  [1, 2],", "  [3, 4]", "]"]), vec![2, 3]);
+        assert_eq!(lines(&["[[1, 2], [3, 4],", "[5, 6]]"]), vec![1, 1, 2]);
+    }
+
+    #[test]
+    fn test_parse_lines_json_error() {
+        assert!(parse(&["[[1, 2], [3]]"]).is_err());
+        assert!(parse(&["[[1], [3]]"]).is_err());
+        assert!(parse(&["[[1, 2], [3, 4]"]).is_err());
+        assert!(parse(&["[[1, 2], [3, \"4\"]]"]).is_err());
+
+        let line = |lines: &[&str]| parse(lines).unwrap_err().get_line();
+
+        assert_eq!(line(&["[", "  [1, 2],", "  [3]", "]"]), Some(3));
+        assert_eq!(line(&["[", "  [1, 2],", "  [3, x]", "]"]), Some(3));
+    }
+
+    #[test]
+    fn decode_line() {
+        assert_eq!(Parser::decode_line("42   24", None), Ok(vec![42, 24]));
+        assert_eq!(Parser::decode_line("1 2 -3", None), Ok(vec![1, 2, -3]));
+        assert_eq!(Parser::decode_line(" 1 ,2", Some(',')), Ok(vec![1, 2]));
+        assert!(Parser::decode_line("42", None).is_err());
+        assert!(Parser::decode_line("1,,2", Some(',')).is_err());
     }
 }
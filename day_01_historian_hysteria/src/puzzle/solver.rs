@@ -1,18 +1,202 @@
+use std::collections::{HashMap, HashSet};
+
 use advent_of_code::puzzles::{
     puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
     puzzle_solver::PuzzleSolver,
 };
+use aoc_common::{
+    configurable::Configurable, explainable::Explainable, parsable::Parsable,
+    parse_error::ParseError, part::Part,
+};
 
-use super::{locations::Locations, parser::Parser};
+use super::{
+    config::Config,
+    location_lists::LocationLists,
+    locations::{Location, Locations},
+    pairing_report::PairingReport,
+    parser::Parser,
+};
 
 pub struct Solver {
-    locations: Locations,
+    config: Config,
+    lists: LocationLists,
+}
+
+impl Solver {
+    fn get_locations(&self) -> Result<Locations, PuzzleError> {
+        let [left, right] = self.config.lists;
+        self.lists.to_locations(left, right)
+    }
+
+    fn get_lists(&self) -> Result<(&[Location], &[Location]), PuzzleError> {
+        let [left, right] = self.config.lists;
+        Ok((self.lists.get_list(left)?, self.lists.get_list(right)?))
+    }
+
+    // Count of every location on its input line, several JSON rows may share a line
+    fn count_on_lines(&self, list: &[Location]) -> HashMap<(usize, Location), usize> {
+        let mut counts = HashMap::new();
+
+        for (line, location) in self.lists.get_lines().iter().zip(list) {
+            *counts.entry((*line, *location)).or_insert(0) += 1;
+        }
+
+        counts
+    }
+
+    // Use up a location on a line, false if the input has no more of them
+    fn take(
+        counts: &mut HashMap<(usize, Location), usize>,
+        line: usize,
+        location: Location,
+    ) -> bool {
+        match counts.get_mut(&(line, location)) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn check_pairs(&self, report: &PairingReport) -> Result<String, PuzzleError> {
+        let invalid = |index: usize, reason: &str| {
+            PuzzleError::GenericError(format!("Pair {}: {}", index + 1, reason))
+        };
+
+        let (left, right) = self.get_lists()?;
+        if report.pairs.len() != left.len() {
+            return Err(PuzzleError::GenericError(format!(
+                "Expected {} pairs but {} found",
+                left.len(),
+                report.pairs.len()
+            )));
+        }
+
+        let mut left_counts = self.count_on_lines(left);
+        let mut right_counts = self.count_on_lines(right);
+
+        for (index, pair) in report.pairs.iter().enumerate() {
+            if !Self::take(&mut left_counts, pair.left_line, pair.left)
+                || !Self::take(&mut right_counts, pair.right_line, pair.right)
+            {
+                return Err(invalid(
+                    index,
+                    "location is not on its line of the input or is repeated",
+                ));
+            }
+
+            if pair.distance != pair.left.abs_diff(pair.right) {
+                return Err(invalid(index, "distance differs from the locations"));
+            }
+
+            if let Some(previous) = index.checked_sub(1).map(|i| &report.pairs[i]) {
+                if previous.left > pair.left || previous.right > pair.right {
+                    return Err(invalid(index, "pairs are not sorted"));
+                }
+            }
+        }
+
+        let distance = self.config.aggregation.distance(
+            self.config.metric,
+            report.pairs.iter().map(|pair| (pair.left, pair.right)),
+        )?;
+
+        Ok(distance.to_string())
+    }
+
+    fn check_occurrences(&self, report: &PairingReport) -> Result<String, PuzzleError> {
+        let invalid = |location: Location, reason: &str| {
+            PuzzleError::GenericError(format!("Location {}: {}", location, reason))
+        };
+
+        let count = |list: &[Location]| {
+            let mut counts = HashMap::new();
+            list.iter()
+                .for_each(|location| *counts.entry(*location).or_insert(0) += 1);
+            counts
+        };
+
+        let (left, right) = self.get_lists()?;
+        let left_counts = count(left);
+        let right_counts = count(right);
+        let mut locations = HashSet::new();
+
+        for occurrence in &report.occurrences {
+            let location = occurrence.location;
+
+            if !locations.insert(location) {
+                return Err(invalid(location, "location is repeated"));
+            }
+
+            if occurrence.left_count != left_counts.get(&location).copied().unwrap_or_default()
+                || occurrence.right_count
+                    != right_counts.get(&location).copied().unwrap_or_default()
+            {
+                return Err(invalid(location, "counts differ from the input"));
+            }
+
+            if occurrence.similarity
+                != location as i128 * occurrence.left_count as i128 * occurrence.right_count as i128
+            {
+                return Err(invalid(location, "similarity differs from the counts"));
+            }
+        }
+
+        if let Some(location) = left_counts
+            .keys()
+            .find(|location| !locations.contains(location))
+        {
+            return Err(invalid(*location, "location of the left list is missing"));
+        }
+
+        let score = report
+            .occurrences
+            .iter()
+            .map(|occurrence| occurrence.similarity)
+            .sum::<i128>();
+
+        Ok(score.to_string())
+    }
+}
+
+impl Explainable for Solver {
+    type Witness = PairingReport;
+    type Error = PuzzleError;
+
+    fn witness(&self, _part: Part) -> Result<Self::Witness, PuzzleError> {
+        Ok(PairingReport::new(&self.get_locations()?))
+    }
+
+    fn check_witness(&self, part: Part, witness: &Self::Witness) -> Result<String, PuzzleError> {
+        match part {
+            Part::Part1 => self.check_pairs(witness),
+            Part::Part2 => self.check_occurrences(witness),
+        }
+    }
+}
+
+impl Configurable for Solver {
+    type Config = Config;
+
+    fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+}
+
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        self.lists = Parser::parse_lines(lines, self.config.format)?;
+        Ok(())
+    }
 }
 
 impl PuzzleSolver for Solver {
     fn new() -> Self {
         Self {
-            locations: Default::default(),
+            config: Config::default(),
+            lists: LocationLists::default(),
         }
     }
 
@@ -21,16 +205,21 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        self.locations = Parser::parse_lines(lines)?;
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
-        Ok(self.locations.get_total_distance().to_string())
+        let [left, right] = self.config.lists;
+        let distance =
+            self.lists
+                .get_distance(left, right, self.config.metric, self.config.aggregation)?;
+
+        Ok(distance.to_string())
     }
 
     fn part_2(&self) -> SolutionResult {
-        Ok(self.locations.get_similarity_score().to_string())
+        Ok(self.get_locations()?.get_similarity_score().to_string())
     }
 }
 
@@ -40,6 +229,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -62,4 +252,14 @@ mod tests {
     fn test_part_2() {
         get_tester().test_part_2();
     }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
+
+    #[test]
+    fn test_explain_examples() {
+        ExampleTester::new(crate::day()).test_explain();
+    }
 }
@@ -0,0 +1,9 @@
+pub mod puzzle;
+
+pub use advent_of_code::puzzles::puzzle_solver::PuzzleSolver;
+pub use puzzle::{
+    direction::Direction, parser::Parser, report::Report, safety_policy::SafetyPolicy,
+    solver::Solver,
+};
+
+aoc_common::export_day!(2, Solver, configurable, parsable);
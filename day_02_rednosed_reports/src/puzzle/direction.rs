@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+/// Direction in which levels of a safe report have to change
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+    /// Increasing or decreasing, the first step decides for the whole report
+    #[default]
+    Either,
+}
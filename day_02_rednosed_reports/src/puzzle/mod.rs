@@ -1,3 +1,5 @@
+pub mod direction;
 pub mod parser;
 pub mod report;
+pub mod safety_policy;
 pub mod solver;
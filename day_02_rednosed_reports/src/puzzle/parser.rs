@@ -1,28 +1,30 @@
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::parse_error::ParseError;
 
 use super::report::Report;
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<Vec<Report>, PuzzleError> {
+    pub fn parse_lines(lines: &[&str]) -> Result<Vec<Report>, ParseError> {
         lines
             .iter()
-            .map(|&line| Self::decode_report(line))
+            .enumerate()
+            .map(|(index, &line)| Self::decode_report(line).map_err(|err| err.with_line(index)))
             .collect()
     }
 
-    fn decode_report(line: &str) -> Result<Report, PuzzleError> {
+    fn decode_report(line: &str) -> Result<Report, ParseError> {
         let splits = line.split_ascii_whitespace();
 
         let mut numbers = Vec::new();
 
         for split in splits {
             let number = split.parse::<usize>().map_err(|err| {
-                PuzzleError::InvalidContentError(format!(
-                    "Failed to convert '{}' to usize with an error '{}', line: {}",
-                    split, err, line
+                ParseError::new(format!(
+                    "Failed to convert '{}' to usize with an error '{}'",
+                    split, err
                 ))
+                .with_substring(line, split)
             })?;
 
             numbers.push(number);
@@ -49,5 +51,6 @@ mod tests {
         let result = Parser::decode_report("1 2 3x 4 5");
 
         assert!(result.is_err(), "Result: {:?}", result);
+        assert_eq!(result.unwrap_err().get_columns(), Some(&(5..7)));
     }
 }
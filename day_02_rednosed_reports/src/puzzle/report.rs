@@ -1,3 +1,5 @@
+use super::safety_policy::SafetyPolicy;
+
 #[derive(Debug, PartialEq)]
 pub struct Report {
     internal: Vec<usize>,
@@ -9,32 +11,19 @@ impl Report {
     }
 
     pub fn is_safe(&self) -> bool {
-        Self::check_safeness(&self.internal)
+        self.is_safe_with(&SafetyPolicy::default())
     }
 
-    fn check_safeness(numbers: &[usize]) -> bool {
-        match numbers[0].cmp(&numbers[1]) {
-            std::cmp::Ordering::Equal => false,
-            std::cmp::Ordering::Less => Self::compare_less(numbers),
-            std::cmp::Ordering::Greater => {
-                Self::compare_less(&numbers.iter().rev().copied().collect::<Vec<_>>())
-            }
-        }
+    pub fn is_safe_with(&self, policy: &SafetyPolicy) -> bool {
+        policy.allows(&self.internal)
     }
 
-    fn compare_less(numbers: &[usize]) -> bool {
-        // All have to be ascending with diff <1; 3>
-        for i in 0..numbers.len() - 1 {
-            if numbers[i] >= numbers[i + 1] || numbers[i + 1] - numbers[i] > 3 {
-                return false;
-            }
-        }
-
-        true
+    pub fn is_safe_problem_dampener(&self) -> bool {
+        self.is_safe_problem_dampener_with(&SafetyPolicy::default())
     }
 
-    pub fn is_safe_problem_dampener(&self) -> bool {
-        if Self::check_safeness(&self.internal) {
+    pub fn is_safe_problem_dampener_with(&self, policy: &SafetyPolicy) -> bool {
+        if policy.allows(&self.internal) {
             return true;
         }
 
@@ -43,7 +32,7 @@ impl Report {
             let mut numbers = self.internal.clone();
             numbers.remove(i);
 
-            if Self::check_safeness(&numbers) {
+            if policy.allows(&numbers) {
                 return true;
             }
         }
@@ -74,5 +63,20 @@ mod tests {
         assert!(Report::new(vec![1, 3, 2, 4, 5]).is_safe_problem_dampener());
         // assert!(Report::new(vec![8, 6, 4, 4, 1]).is_safe_problem_dampener());
         // assert!(Report::new(vec![1, 3, 6, 7, 9]).is_safe_problem_dampener());
+        assert!(Report::new(vec![1, 9]).is_safe_problem_dampener());
+    }
+
+    #[test]
+    pub fn test_is_safe_with() {
+        let policy = SafetyPolicy {
+            max_step: 5,
+            allow_equal: true,
+            ..Default::default()
+        };
+
+        assert!(Report::new(vec![1, 2, 7, 8, 9]).is_safe_with(&policy));
+        assert!(Report::new(vec![8, 6, 4, 4, 1]).is_safe_with(&policy));
+        assert!(!Report::new(vec![1, 3, 2, 4, 5]).is_safe_with(&policy));
+        assert!(Report::new(vec![1, 3, 2, 4, 5]).is_safe_problem_dampener_with(&policy));
     }
 }
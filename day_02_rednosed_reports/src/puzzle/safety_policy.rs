@@ -0,0 +1,117 @@
+use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use serde::Deserialize;
+
+use super::direction::Direction;
+
+/// Rules of a safe report, defaults match the puzzle
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SafetyPolicy {
+    /// Smallest allowed difference of adjacent levels
+    pub min_step: usize,
+    /// Biggest allowed difference of adjacent levels
+    pub max_step: usize,
+    pub direction: Direction,
+    /// Equal adjacent levels are allowed regardless of steps and direction
+    pub allow_equal: bool,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        Self {
+            min_step: 1,
+            max_step: 3,
+            direction: Direction::Either,
+            allow_equal: false,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    pub fn validate(&self) -> Result<(), PuzzleError> {
+        if self.min_step > self.max_step {
+            return Err(PuzzleError::GenericError(format!(
+                "Minimum step {} is bigger than maximum step {}",
+                self.min_step, self.max_step
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Check levels of a report, reports with less than 2 levels are always safe
+    pub fn allows(&self, levels: &[usize]) -> bool {
+        let mut increasing = match self.direction {
+            Direction::Increasing => Some(true),
+            Direction::Decreasing => Some(false),
+            Direction::Either => None,
+        };
+
+        for pair in levels.windows(2) {
+            let (current, next) = (pair[0], pair[1]);
+
+            if current == next {
+                if !self.allow_equal {
+                    return false;
+                }
+                continue;
+            }
+
+            let step = current.abs_diff(next);
+            if step < self.min_step || step > self.max_step {
+                return false;
+            }
+
+            if *increasing.get_or_insert(current < next) != (current < next) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allows() {
+        let policy = SafetyPolicy::default();
+
+        assert!(policy.allows(&[7, 6, 4, 2, 1]));
+        assert!(policy.allows(&[1, 3, 6, 7, 9]));
+        assert!(!policy.allows(&[1, 2, 7, 8, 9]));
+        assert!(!policy.allows(&[1, 3, 2, 4, 5]));
+        assert!(!policy.allows(&[8, 6, 4, 4, 1]));
+        assert!(policy.allows(&[5]));
+        assert!(policy.allows(&[]));
+    }
+
+    #[test]
+    fn test_allows_custom() {
+        let policy = SafetyPolicy {
+            min_step: 2,
+            max_step: 5,
+            direction: Direction::Increasing,
+            allow_equal: true,
+        };
+
+        assert!(policy.allows(&[1, 3, 3, 8]));
+        assert!(!policy.allows(&[1, 2, 4]));
+        assert!(!policy.allows(&[1, 7]));
+        assert!(!policy.allows(&[9, 7, 5]));
+        assert!(policy.allows(&[4, 4, 4]));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(SafetyPolicy::default().validate().is_ok());
+
+        let policy = SafetyPolicy {
+            min_step: 4,
+            ..Default::default()
+        };
+        assert!(policy.validate().is_err());
+    }
+}
@@ -1,17 +1,36 @@
 use advent_of_code::puzzles::{
     puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
     puzzle_solver::PuzzleSolver,
 };
+use aoc_common::{configurable::Configurable, parsable::Parsable, parse_error::ParseError};
 
-use super::{parser::Parser, report::Report};
+use super::{parser::Parser, report::Report, safety_policy::SafetyPolicy};
 
 pub struct Solver {
+    policy: SafetyPolicy,
     reports: Vec<Report>,
 }
 
+impl Configurable for Solver {
+    type Config = SafetyPolicy;
+
+    fn set_config(&mut self, config: SafetyPolicy) {
+        self.policy = config;
+    }
+}
+
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        self.reports = Parser::parse_lines(lines)?;
+        Ok(())
+    }
+}
+
 impl PuzzleSolver for Solver {
     fn new() -> Self {
         Self {
+            policy: SafetyPolicy::default(),
             reports: Vec::new(),
         }
     }
@@ -21,21 +40,29 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        self.reports = Parser::parse_lines(lines)?;
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
-        let safe_reports_count = self.reports.iter().filter(|&r| r.is_safe()).count();
+        self.policy.validate()?;
+
+        let safe_reports_count = self
+            .reports
+            .iter()
+            .filter(|&r| r.is_safe_with(&self.policy))
+            .count();
 
         Ok(safe_reports_count.to_string())
     }
 
     fn part_2(&self) -> SolutionResult {
+        self.policy.validate()?;
+
         let safe_reports_count = self
             .reports
             .iter()
-            .filter(|&r| r.is_safe_problem_dampener())
+            .filter(|&r| r.is_safe_problem_dampener_with(&self.policy))
             .count();
 
         Ok(safe_reports_count.to_string())
@@ -48,6 +75,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -70,4 +98,9 @@ mod tests {
     fn test_part_2() {
         get_tester().test_part_2();
     }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
 }
@@ -1,66 +1,63 @@
 use std::sync::LazyLock;
 
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
-use regex::Regex;
+use aoc_common::parse_error::ParseError;
+use regex::{Captures, Regex};
 
 use super::instruction::Instruction;
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<Vec<Instruction>, PuzzleError> {
+    pub fn parse_lines(lines: &[&str]) -> Result<Vec<Instruction>, ParseError> {
         let mut instructions = Vec::new();
 
-        for line in lines {
-            let mut line_instructions = Self::decode_instructions(line)?;
+        for (index, line) in lines.iter().enumerate() {
+            let mut line_instructions =
+                Self::decode_instructions(line).map_err(|err| err.with_line(index))?;
             instructions.append(&mut line_instructions);
         }
 
         Ok(instructions)
     }
 
-    pub fn decode_instructions(line: &str) -> Result<Vec<Instruction>, PuzzleError> {
+    pub fn decode_instructions(line: &str) -> Result<Vec<Instruction>, ParseError> {
         static RE: LazyLock<Regex> = LazyLock::new(|| {
             Regex::new(r"(?P<mul>mul\((?P<first>\d+),(?P<second>\d+)\))|(?P<do_not>don't\(\))|(?P<do>do\(\))")
                 .expect("Failed to create regex 'mul'")
         });
 
-        let instructions = RE
-            .captures_iter(line)
+        RE.captures_iter(line)
             .filter_map(|capture| {
                 if capture.name("mul").is_some() {
-                    let first = capture["first"]
-                        .parse::<usize>()
-                        .map_err(|err| {
-                            PuzzleError::InvalidContentError(format!(
-                                "Failed to parse 1st number '{}' to usize with an error '{}'",
-                                &capture[1], err
-                            ))
-                        })
-                        .ok()?;
-
-                    let second = capture["second"]
-                        .parse::<usize>()
-                        .map_err(|err| {
-                            PuzzleError::InvalidContentError(format!(
-                                "Failed to parse 2nd number '{}' to usize with an error '{}'",
-                                &capture[2], err
-                            ))
-                        })
-                        .ok()?;
-
-                    Some(Instruction::Multiply(first, second))
+                    Some(Self::decode_multiply(line, &capture))
                 } else if capture.name("do_not").is_some() {
-                    Some(Instruction::DoNot)
+                    Some(Ok(Instruction::DoNot))
                 } else if capture.name("do").is_some() {
-                    Some(Instruction::Do)
+                    Some(Ok(Instruction::Do))
                 } else {
                     None
                 }
             })
-            .collect();
+            .collect()
+    }
 
-        Ok(instructions)
+    fn decode_multiply(line: &str, capture: &Captures) -> Result<Instruction, ParseError> {
+        let decode_number = |name: &str| {
+            let number = &capture[name];
+
+            number.parse::<usize>().map_err(|err| {
+                ParseError::new(format!(
+                    "Failed to parse {} number '{}' to usize with an error '{}'",
+                    name, number, err
+                ))
+                .with_span(line, capture.name(name).map_or(0..0, |m| m.range()))
+            })
+        };
+
+        Ok(Instruction::Multiply(
+            decode_number("first")?,
+            decode_number("second")?,
+        ))
     }
 }
 
@@ -87,4 +84,12 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_decode_instructions_overflow() {
+        let result = Parser::decode_instructions("mul(2,4)mul(99999999999999999999999,1)");
+
+        assert!(result.is_err(), "Result: {:?}", result);
+        assert_eq!(result.unwrap_err().get_columns(), Some(&(13..36)));
+    }
 }
@@ -1,7 +1,9 @@
 use advent_of_code::puzzles::{
     puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
     puzzle_solver::PuzzleSolver,
 };
+use aoc_common::{parsable::Parsable, parse_error::ParseError};
 
 use super::{instruction::Instruction, parser::Parser};
 
@@ -9,6 +11,13 @@ pub struct Solver {
     instructions: Vec<Instruction>,
 }
 
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        self.instructions = Parser::parse_lines(lines)?;
+        Ok(())
+    }
+}
+
 impl PuzzleSolver for Solver {
     fn new() -> Self {
         Self {
@@ -21,8 +30,8 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        self.instructions = Parser::parse_lines(lines)?;
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
@@ -69,6 +78,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -91,4 +101,9 @@ mod tests {
     fn test_part_2() {
         get_tester().test_part_2();
     }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
 }
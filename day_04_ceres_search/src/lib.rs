@@ -0,0 +1,6 @@
+pub mod puzzle;
+
+pub use advent_of_code::puzzles::puzzle_solver::PuzzleSolver;
+pub use puzzle::{grid::Grid, parser::Parser, solver::Solver};
+
+aoc_common::export_day!(4, Solver, parsable, explainable, renderable);
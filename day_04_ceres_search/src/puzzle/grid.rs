@@ -1,5 +1,19 @@
 use std::fmt::Display;
 
+use aoc_common::render::frame::Frame;
+
+// Row and column differences of all 8 ways how to read a word
+const DIRECTIONS: [(isize, isize); 8] = [
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+];
+
 #[derive(Default)]
 pub struct Grid {
     internal: Vec<Vec<char>>,
@@ -28,6 +42,54 @@ impl Grid {
         count
     }
 
+    /// Positions `(x, y)` of all letters of every occurrence of the word
+    pub fn find_words(&self, word: &str) -> Vec<Vec<(isize, isize)>> {
+        let mut words = Vec::new();
+
+        for (row_idx, row) in self.internal.iter().enumerate() {
+            for col_idx in 0..row.len() {
+                for (row_diff, col_diff) in DIRECTIONS {
+                    let text = self.get_text(row_idx, row_diff, col_idx, col_diff, word.len());
+                    if text.as_deref() != Some(word) {
+                        continue;
+                    }
+
+                    let positions = (0..word.len() as isize)
+                        .map(|idx| {
+                            (
+                                col_idx as isize + idx * col_diff,
+                                row_idx as isize + idx * row_diff,
+                            )
+                        })
+                        .collect();
+
+                    words.push(positions);
+                }
+            }
+        }
+
+        words
+    }
+
+    /// Frame of letters, tiles are `(column, row)`
+    pub fn to_frame(&self, title: &str) -> Frame {
+        let cols = self
+            .internal
+            .first()
+            .map(|row| row.len())
+            .unwrap_or_default();
+
+        Frame::new(title, self.internal.len(), cols, |x, y| {
+            self.get_char(x, y).unwrap_or(' ')
+        })
+    }
+
+    /// Character at position `(x, y)`, None outside of the grid
+    pub fn get_char(&self, x: isize, y: isize) -> Option<char> {
+        let row = self.internal.get(usize::try_from(y).ok()?)?;
+        row.get(usize::try_from(x).ok()?).copied()
+    }
+
     fn spawn_possible_texts(&self, row_idx: usize, col_idx: usize, word_len: usize) -> Vec<String> {
         // There are up to 8 possible ways how to create a text
         let texts = vec![
@@ -140,6 +202,21 @@ impl Grid {
         count
     }
 
+    /// Positions `(x, y)` of the middle 'A' of every X-MAS
+    pub fn find_xmas_centers(&self) -> Vec<(isize, isize)> {
+        let mut centers = Vec::new();
+
+        for (row_idx, row) in self.internal.iter().enumerate() {
+            for (col_idx, character) in row.iter().enumerate() {
+                if character == &'A' && self.is_xmas_pattern(row_idx, col_idx) {
+                    centers.push((col_idx as isize, row_idx as isize));
+                }
+            }
+        }
+
+        centers
+    }
+
     fn is_xmas_pattern(&self, row_idx: usize, col_idx: usize) -> bool {
         // Check if we have enough space in left and right A is always in he middle
         if row_idx == 0
@@ -196,6 +273,25 @@ mod tests {
         ])
     }
 
+    #[test]
+    fn test_find_words() {
+        let grid = create_grid();
+        let words = grid.find_words("XMAS");
+
+        assert_eq!(words.len(), grid.word_count("XMAS"));
+        assert!(words.contains(&vec![(5, 0), (6, 0), (7, 0), (8, 0)]));
+        assert!(words.contains(&vec![(9, 9), (8, 8), (7, 7), (6, 6)]));
+    }
+
+    #[test]
+    fn test_find_xmas_centers() {
+        let grid = create_grid();
+        let centers = grid.find_xmas_centers();
+
+        assert_eq!(centers.len(), grid.xmas_count());
+        assert!(centers.contains(&(2, 1)));
+    }
+
     #[test]
     fn test_get_text_right() {
         let grid = create_grid();
@@ -1,3 +1,4 @@
 pub mod grid;
 pub mod parser;
 pub mod solver;
+pub mod witness;
@@ -1,11 +1,13 @@
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::{grid_lines, parse_error::ParseError};
 
 use super::grid::Grid;
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<Grid, PuzzleError> {
+    pub fn parse_lines(lines: &[&str]) -> Result<Grid, ParseError> {
+        grid_lines::check_rectangular(lines)?;
+
         let rows = lines.iter().map(|&line| line.chars().collect()).collect();
         Ok(Grid::new(rows))
     }
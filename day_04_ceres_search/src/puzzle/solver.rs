@@ -1,14 +1,86 @@
 use advent_of_code::puzzles::{
     puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
     puzzle_solver::PuzzleSolver,
 };
+use aoc_common::{
+    explainable::Explainable,
+    parsable::Parsable,
+    parse_error::ParseError,
+    part::Part,
+    render::{frame::Frame, layer::Layer},
+    renderable::Renderable,
+};
+
+use super::{grid::Grid, parser::Parser, witness::Witness};
 
-use super::{grid::Grid, parser::Parser};
+const WORD: &str = "XMAS";
 
 pub struct Solver {
     grid: Grid,
 }
 
+impl Explainable for Solver {
+    type Witness = Witness;
+    type Error = PuzzleError;
+
+    fn witness(&self, part: Part) -> Result<Witness, PuzzleError> {
+        Ok(match part {
+            Part::Part1 => Witness::Words {
+                positions: self.grid.find_words(WORD),
+            },
+            Part::Part2 => Witness::Crosses {
+                centers: self.grid.find_xmas_centers(),
+            },
+        })
+    }
+
+    fn check_witness(&self, _part: Part, witness: &Witness) -> Result<String, PuzzleError> {
+        witness
+            .check(&self.grid, WORD)
+            .map_err(PuzzleError::GenericError)
+    }
+}
+
+impl Renderable for Solver {
+    type Error = PuzzleError;
+
+    fn frames(&self, part: Part) -> Result<Vec<Frame>, PuzzleError> {
+        let (mut frame, points) = match part {
+            Part::Part1 => (
+                self.grid.to_frame(&format!("Occurrences of {}", WORD)),
+                self.grid.find_words(WORD).into_iter().flatten().collect(),
+            ),
+            Part::Part2 => (
+                self.grid.to_frame("Occurrences of X-MAS"),
+                self.grid
+                    .find_xmas_centers()
+                    .into_iter()
+                    .flat_map(|(x, y)| {
+                        [
+                            (x, y),
+                            (x - 1, y - 1),
+                            (x + 1, y - 1),
+                            (x - 1, y + 1),
+                            (x + 1, y + 1),
+                        ]
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+        };
+        frame.add_layer(Layer::Marker, points);
+
+        Ok(vec![frame])
+    }
+}
+
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        self.grid = Parser::parse_lines(lines)?;
+        Ok(())
+    }
+}
+
 impl PuzzleSolver for Solver {
     fn new() -> Self {
         Self {
@@ -21,12 +93,12 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        self.grid = Parser::parse_lines(lines)?;
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
-        let count = self.grid.word_count("XMAS");
+        let count = self.grid.word_count(WORD);
         Ok(count.to_string())
     }
 
@@ -42,6 +114,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -64,4 +137,14 @@ mod tests {
     fn test_part_2() {
         get_tester().test_part_2();
     }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
+
+    #[test]
+    fn test_explain_examples() {
+        ExampleTester::new(crate::day()).test_explain();
+    }
 }
@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use super::grid::Grid;
+
+/// Evidence of an answer, coordinates are `(x, y)` like in the input
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Witness {
+    /// Positions of all letters of every occurrence of the word
+    Words { positions: Vec<Vec<(isize, isize)>> },
+    /// Positions of the middle 'A' of every X-MAS
+    Crosses { centers: Vec<(isize, isize)> },
+}
+
+impl Witness {
+    /// Compute the answer from the witness, only the grid and the word are trusted
+    pub fn check(&self, grid: &Grid, word: &str) -> Result<String, String> {
+        match self {
+            Witness::Words { positions } => {
+                let mut seen = HashSet::new();
+
+                for word_positions in positions {
+                    Self::check_word(grid, word, word_positions)?;
+
+                    if !seen.insert(word_positions) {
+                        return Err(format!("Word at {:?} is repeated", word_positions));
+                    }
+                }
+
+                Ok(positions.len().to_string())
+            }
+            Witness::Crosses { centers } => {
+                let mut seen = HashSet::new();
+
+                for center in centers {
+                    Self::check_cross(grid, *center)?;
+
+                    if !seen.insert(center) {
+                        return Err(format!("X-MAS at {:?} is repeated", center));
+                    }
+                }
+
+                Ok(centers.len().to_string())
+            }
+        }
+    }
+
+    fn check_word(grid: &Grid, word: &str, positions: &[(isize, isize)]) -> Result<(), String> {
+        let text = positions
+            .iter()
+            .map(|&(x, y)| grid.get_char(x, y).unwrap_or(' '))
+            .collect::<String>();
+
+        if text != word {
+            return Err(format!("Positions {:?} read '{}'", positions, text));
+        }
+
+        // All letters have to follow the same straight line
+        let steps = positions
+            .windows(2)
+            .map(|pair| (pair[1].0 - pair[0].0, pair[1].1 - pair[0].1))
+            .collect::<HashSet<_>>();
+
+        let is_straight = match steps.iter().next() {
+            None => true,
+            Some(&(dx, dy)) => {
+                steps.len() == 1 && dx.abs() <= 1 && dy.abs() <= 1 && (dx, dy) != (0, 0)
+            }
+        };
+
+        match is_straight {
+            true => Ok(()),
+            false => Err(format!("Positions {:?} are not in a line", positions)),
+        }
+    }
+
+    fn check_cross(grid: &Grid, (x, y): (isize, isize)) -> Result<(), String> {
+        let is_mas = |first: Option<char>, last: Option<char>| {
+            matches!(
+                (first, last),
+                (Some('M'), Some('S')) | (Some('S'), Some('M'))
+            )
+        };
+
+        let is_cross = grid.get_char(x, y) == Some('A')
+            && is_mas(grid.get_char(x - 1, y - 1), grid.get_char(x + 1, y + 1))
+            && is_mas(grid.get_char(x - 1, y + 1), grid.get_char(x + 1, y - 1));
+
+        match is_cross {
+            true => Ok(()),
+            false => Err(format!("There is no X-MAS at {:?}", (x, y))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_grid() -> Grid {
+        Grid::new(vec![
+            String::from("XMAS."),
+            String::from(".M.S."),
+            String::from("..A.."),
+            String::from(".M.S."),
+        ])
+    }
+
+    #[test]
+    fn test_check_words() {
+        let grid = create_grid();
+        let words = |positions: Vec<Vec<(isize, isize)>>| Witness::Words { positions };
+
+        let witness = words(vec![vec![(0, 0), (1, 0), (2, 0), (3, 0)]]);
+        assert_eq!(witness.check(&grid, "XMAS"), Ok("1".to_string()));
+
+        for positions in [
+            // Letters do not read the word
+            vec![vec![(0, 0), (0, 1), (0, 2), (0, 3)]],
+            // Letters are not in a line
+            vec![vec![(0, 0), (1, 1), (2, 0), (3, 0)]],
+            // Word is repeated
+            vec![
+                vec![(0, 0), (1, 0), (2, 0), (3, 0)],
+                vec![(0, 0), (1, 0), (2, 0), (3, 0)],
+            ],
+        ] {
+            let witness = words(positions);
+            assert!(
+                witness.check(&grid, "XMAS").is_err(),
+                "witness: {:?}",
+                witness
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_crosses() {
+        let grid = create_grid();
+        let crosses = |centers: Vec<(isize, isize)>| Witness::Crosses { centers };
+
+        assert_eq!(
+            crosses(vec![(2, 2)]).check(&grid, "XMAS"),
+            Ok("1".to_string())
+        );
+        assert!(crosses(vec![(2, 2), (2, 2)]).check(&grid, "XMAS").is_err());
+        assert!(crosses(vec![(2, 0)]).check(&grid, "XMAS").is_err());
+        assert!(crosses(vec![(0, 0)]).check(&grid, "XMAS").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::parse_error::ParseError;
 
 use super::print_queue::PrintQueue;
 
@@ -10,11 +10,13 @@ enum ParserState {
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<PrintQueue, PuzzleError> {
+    pub fn parse_lines(lines: &[&str]) -> Result<PrintQueue, ParseError> {
         let mut print_queue = PrintQueue::new();
         let mut parser_state = ParserState::Order;
 
-        for line in lines {
+        for (index, line) in lines.iter().enumerate() {
+            let with_line = |err: ParseError| err.with_line(index);
+
             // Empty line separates 'orders' and 'pages'
             if line.is_empty() {
                 parser_state = ParserState::Page;
@@ -23,11 +25,11 @@ impl Parser {
 
             match parser_state {
                 ParserState::Order => {
-                    let (first, second) = Self::decode_order(line)?;
+                    let (first, second) = Self::decode_order(line).map_err(with_line)?;
                     print_queue.insert_order(first, vec![second]);
                 }
                 ParserState::Page => {
-                    let page = Self::decode_page(line)?;
+                    let page = Self::decode_page(line).map_err(with_line)?;
                     print_queue.insert_page(vec![page]);
                 }
             }
@@ -36,58 +38,52 @@ impl Parser {
         Ok(print_queue)
     }
 
-    fn decode_order(line: &str) -> Result<(usize, usize), PuzzleError> {
+    fn decode_order(line: &str) -> Result<(usize, usize), ParseError> {
         let splitted = line.split_terminator("|").collect::<Vec<_>>();
 
         if splitted.len() != 2 {
-            return Err(PuzzleError::InvalidContentError(format!(
+            return Err(ParseError::new(format!(
                 "Order should contain 2 elements but {} were found",
                 splitted.len(),
-            )));
-        }
-
-        let first = splitted[0].parse::<usize>().map_err(|err| {
-            PuzzleError::InvalidContentError(format!(
-                "Failed to convert '{}' to usize with an error '{}'",
-                splitted[0], err
             ))
-        })?;
+            .with_whole_line(line));
+        }
 
-        let second = splitted[1].parse::<usize>().map_err(|err| {
-            PuzzleError::InvalidContentError(format!(
-                "Failed to convert '{}' to usize with an error '{}'",
-                splitted[1], err
-            ))
-        })?;
+        let first = Self::decode_number(line, splitted[0])?;
+        let second = Self::decode_number(line, splitted[1])?;
 
         Ok((first, second))
     }
 
-    fn decode_page(line: &str) -> Result<Vec<usize>, PuzzleError> {
+    fn decode_page(line: &str) -> Result<Vec<usize>, ParseError> {
         let splitted = line.split_terminator(",").collect::<Vec<_>>();
 
         if splitted.is_empty() {
-            return Err(PuzzleError::InvalidContentError(format!(
+            return Err(ParseError::new(format!(
                 "Page should contain at least 1 element but {} were found",
                 splitted.len(),
-            )));
+            ))
+            .with_whole_line(line));
         }
 
         let mut pages = Vec::new();
 
         for split in splitted {
-            let number = split.parse::<usize>().map_err(|err| {
-                PuzzleError::InvalidContentError(format!(
-                    "Failed to convert '{}' to usize with an error '{}'",
-                    split, err
-                ))
-            })?;
-
-            pages.push(number);
+            pages.push(Self::decode_number(line, split)?);
         }
 
         Ok(pages)
     }
+
+    fn decode_number(line: &str, number: &str) -> Result<usize, ParseError> {
+        number.parse::<usize>().map_err(|err| {
+            ParseError::new(format!(
+                "Failed to convert '{}' to usize with an error '{}'",
+                number, err
+            ))
+            .with_substring(line, number)
+        })
+    }
 }
 
 #[cfg(test)]
@@ -109,4 +105,19 @@ mod tests {
         assert!(result.is_ok(), "Result: {:?}", result);
         assert_eq!(result.unwrap(), vec![1, 2, 3]);
     }
+
+    #[test]
+    fn test_parse_lines_error_location() {
+        let result = Parser::parse_lines(&["42|24", "", "1,2,3", "4,x5"]);
+
+        assert!(result.is_err());
+        assert!(
+            result
+                .err()
+                .unwrap()
+                .to_string()
+                .contains("line 4, columns 3-4"),
+            "Missing location"
+        );
+    }
 }
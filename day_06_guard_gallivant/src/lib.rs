@@ -0,0 +1,9 @@
+pub mod puzzle;
+
+pub use advent_of_code::puzzles::puzzle_solver::PuzzleSolver;
+pub use puzzle::{
+    direction::Direction, guard::Guard, maze::Maze, maze_object::MazeObject, parser::Parser,
+    position::Position, solver::Solver,
+};
+
+aoc_common::export_day!(6, Solver, parsable, renderable);
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
-use std::io::Write;
-use std::{collections::HashSet, fs::File};
+use std::collections::HashSet;
+
+use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::render::frame::Frame;
 
 use super::position::Position;
 use super::{direction::Direction, guard::Guard, maze_object::MazeObject};
@@ -27,17 +29,46 @@ impl Default for Maze {
 }
 
 impl Maze {
-    pub fn new(maze: Vec<Vec<MazeObject>>, guard: Guard) -> Self {
+    pub fn new(maze: Vec<Vec<MazeObject>>, guard: Guard) -> Result<Self, PuzzleError> {
         let maze_rows = maze.len();
-        let maze_cols = maze[0].len();
+        let maze_cols = match maze.first() {
+            Some(row) => row.len(),
+            None => {
+                return Err(PuzzleError::InvalidContentError(String::from(
+                    "Maze is empty",
+                )))
+            }
+        };
 
-        Self {
+        // All rows must have the same length
+        if let Some((row, line)) = maze
+            .iter()
+            .enumerate()
+            .find(|(_, line)| line.len() != maze_cols)
+        {
+            return Err(PuzzleError::InvalidContentError(format!(
+                "Row {} has {} columns but {} expected",
+                row,
+                line.len(),
+                maze_cols
+            )));
+        }
+
+        let position = guard.get_position();
+        if position.x >= maze_rows || position.y >= maze_cols {
+            return Err(PuzzleError::InvalidContentError(format!(
+                "Guard {:?} is outside of the maze {}x{}",
+                position, maze_rows, maze_cols
+            )));
+        }
+
+        Ok(Self {
             maze,
             maze_rows,
             maze_cols,
             guard,
             new_obstacle_position: None,
-        }
+        })
     }
 
     pub fn collect_guard_moves(&self) -> Vec<Position> {
@@ -58,55 +89,31 @@ impl Maze {
             guard = Some(new_guard);
         }
 
-        const PRINT_TO_FILE: bool = false;
-        if PRINT_TO_FILE {
-            self.print_to_file(&visited);
-        }
-
         // Return visited positions
         visited.into_keys().collect::<Vec<_>>()
     }
 
-    fn print_to_file(&self, visited: &HashMap<Position, HashSet<Direction>>) {
-        // Print visited locations into the file
-        let mut file = File::create("/tmp/log.txt").unwrap();
-
-        for i in 0..self.maze_rows {
-            for j in 0..self.maze_cols {
-                let c = match self.maze[i][j] {
-                    MazeObject::Empty => {
-                        if self.guard.get_position().x == i && self.guard.get_position().y == j {
-                            match self.guard.get_direction() {
-                                Direction::Right => ">",
-                                Direction::Down => "v",
-                                Direction::Left => "<",
-                                Direction::Up => "^",
-                            }
-                        } else if let Some(dirs) = visited.get(&Position::new(i, j)) {
-                            let up_down =
-                                dirs.contains(&Direction::Up) || dirs.contains(&Direction::Down);
-
-                            let left_right =
-                                dirs.contains(&Direction::Left) || dirs.contains(&Direction::Right);
-
-                            match (up_down, left_right) {
-                                (true, true) => "+",
-                                (true, false) => "|",
-                                (false, true) => "-",
-                                (false, false) => "x",
-                            }
-                        } else {
-                            "."
-                        }
-                    }
-                    MazeObject::Obstruction => "#",
-                    MazeObject::NewObstruction => "O",
-                };
+    /// Frame of the maze with the guard at the start position, tiles are `(column, row)`
+    pub fn to_frame(&self, title: &str) -> Frame {
+        let guard = self.guard.get_position();
+
+        Frame::new(title, self.maze_rows, self.maze_cols, |x, y| {
+            let (row, col) = (y as usize, x as usize);
 
-                write!(file, "{}", c).unwrap();
+            match &self.maze[row][col] {
+                MazeObject::Empty if guard.x == row && guard.y == col => {
+                    match self.guard.get_direction() {
+                        Direction::Right => '>',
+                        Direction::Down => 'v',
+                        Direction::Left => '<',
+                        Direction::Up => '^',
+                    }
+                }
+                MazeObject::Empty => '.',
+                MazeObject::Obstruction => '#',
+                MazeObject::NewObstruction => 'O',
             }
-            writeln!(file).unwrap();
-        }
+        })
     }
 
     fn move_guard(&self, guard: &Option<Guard>) -> Option<Guard> {
@@ -218,11 +225,16 @@ impl Maze {
     }
 
     pub fn find_obstructions_count(&self) -> usize {
+        self.find_obstructions().len()
+    }
+
+    /// Positions of new obstructions which trap the guard in a loop
+    pub fn find_obstructions(&self) -> Vec<Position> {
         // Optimization - insert obstructions only on positions through which guard moves
         let guard_moves = self.collect_guard_moves();
 
         guard_moves
-            .iter()
+            .into_iter()
             .filter(|new_obstruction| {
                 // Spawn new puzzle with a new obstacle and investigate if it contains a loop
                 let mut maze = self.clone();
@@ -230,7 +242,7 @@ impl Maze {
 
                 maze.investigate_loop()
             })
-            .count()
+            .collect()
     }
 
     fn investigate_loop(&self) -> bool {
@@ -302,6 +314,30 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_new_invalid_content() {
+        let guard = Guard::new(Position::new(0, 0), Direction::Up);
+
+        assert!(matches!(
+            Maze::new(vec![], guard.clone()),
+            Err(PuzzleError::InvalidContentError(_))
+        ));
+        assert!(matches!(
+            Maze::new(
+                vec![vec![MazeObject::Empty; 2], vec![MazeObject::Empty]],
+                guard
+            ),
+            Err(PuzzleError::InvalidContentError(_))
+        ));
+        assert!(matches!(
+            Maze::new(
+                vec![vec![MazeObject::Empty; 2]],
+                Guard::new(Position::new(1, 0), Direction::Up)
+            ),
+            Err(PuzzleError::InvalidContentError(_))
+        ));
+    }
+
     #[test]
     fn test_collect_guard_moves_len() {
         let maze = create_maze();
@@ -355,4 +391,14 @@ mod tests {
 
         assert_eq!(maze.find_obstructions_count(), 6);
     }
+
+    #[test]
+    fn test_to_frame() {
+        let frame = create_maze().to_frame("test");
+
+        assert_eq!((frame.get_rows(), frame.get_cols()), (10, 10));
+        assert_eq!(frame.get_tile(4, 0), Some('#'));
+        assert_eq!(frame.get_tile(4, 6), Some('^'));
+        assert_eq!(frame.get_tile(0, 0), Some('.'));
+    }
 }
@@ -1,4 +1,4 @@
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::{grid_lines, parse_error::ParseError};
 
 use super::{
     direction::Direction, guard::Guard, maze::Maze, maze_object::MazeObject, position::Position,
@@ -7,30 +7,32 @@ use super::{
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<Maze, PuzzleError> {
+    pub fn parse_lines(lines: &[&str]) -> Result<Maze, ParseError> {
+        grid_lines::check_rectangular(lines)?;
+
         let mut rows = Vec::with_capacity(lines.len());
 
-        for line in lines {
-            rows.push(Self::decode_row(line)?);
+        for (index, line) in lines.iter().enumerate() {
+            rows.push(Self::decode_row(line).map_err(|err| err.with_line(index))?);
         }
 
         let guard = Self::find_guard(lines)?;
 
-        Ok(Maze::new(rows, guard))
+        Maze::new(rows, guard).map_err(|err| ParseError::new(err.to_string()))
     }
 
-    fn decode_row(line: &str) -> Result<Vec<MazeObject>, PuzzleError> {
+    fn decode_row(line: &str) -> Result<Vec<MazeObject>, ParseError> {
         let mut row = Vec::with_capacity(line.chars().count());
 
-        for c in line.chars() {
+        for (position, c) in line.char_indices() {
             match c {
                 '.' | '<' | '>' | '^' | 'v' => row.push(MazeObject::Empty),
                 '#' => row.push(MazeObject::Obstruction),
                 c => {
-                    return Err(PuzzleError::InvalidContentError(format!(
-                        "Invalid character '{}' in maze input",
-                        c
-                    )))
+                    return Err(
+                        ParseError::new(format!("Invalid character '{}' in maze input", c))
+                            .with_span(line, position..position + c.len_utf8()),
+                    )
                 }
             }
         }
@@ -38,7 +40,7 @@ impl Parser {
         Ok(row)
     }
 
-    fn find_guard(lines: &[&str]) -> Result<Guard, PuzzleError> {
+    fn find_guard(lines: &[&str]) -> Result<Guard, ParseError> {
         for (row_idx, line) in lines.iter().enumerate() {
             if let Some(col_idx) = line.find(">") {
                 return Ok(Guard::new(
@@ -60,9 +62,7 @@ impl Parser {
             }
         }
 
-        Err(PuzzleError::InvalidContentError(String::from(
-            "Guard not found in the maze",
-        )))
+        Err(ParseError::new("Guard not found in the maze"))
     }
 }
 
@@ -87,6 +87,14 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_decode_row_invalid() {
+        let result = Parser::decode_row("..#x#");
+
+        assert!(result.is_err(), "Result: {:?}", result);
+        assert_eq!(result.unwrap_err().get_columns(), Some(&(4..5)));
+    }
+
     #[test]
     fn test_find_guard() {
         let result = Parser::find_guard(&["..>.."]);
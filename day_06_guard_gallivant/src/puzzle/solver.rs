@@ -1,14 +1,60 @@
 use advent_of_code::puzzles::{
     puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
     puzzle_solver::PuzzleSolver,
 };
+use aoc_common::{
+    parsable::Parsable,
+    parse_error::ParseError,
+    part::Part,
+    render::{frame::Frame, layer::Layer},
+    renderable::Renderable,
+};
 
-use super::{maze::Maze, parser::Parser};
+use super::{maze::Maze, parser::Parser, position::Position};
 
 pub struct Solver {
     maze: Maze,
 }
 
+impl Renderable for Solver {
+    type Error = PuzzleError;
+
+    fn frames(&self, part: Part) -> Result<Vec<Frame>, PuzzleError> {
+        // Positions are (row, column) while frames use (column, row)
+        let to_point = |position: &Position| (position.y as isize, position.x as isize);
+        let guard_moves = self.maze.collect_guard_moves();
+
+        let frame = match part {
+            Part::Part1 => {
+                let mut frame = self.maze.to_frame("Positions visited by the guard");
+                frame.add_layer(Layer::Path, guard_moves.iter().map(to_point));
+                frame
+            }
+            Part::Part2 => {
+                let mut frame = self
+                    .maze
+                    .to_frame("Obstructions trapping the guard in a loop");
+                frame.add_layer(Layer::Path, guard_moves.iter().map(to_point));
+                frame.add_layer(
+                    Layer::Marker,
+                    self.maze.find_obstructions().iter().map(to_point),
+                );
+                frame
+            }
+        };
+
+        Ok(vec![frame])
+    }
+}
+
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        self.maze = Parser::parse_lines(lines)?;
+        Ok(())
+    }
+}
+
 impl PuzzleSolver for Solver {
     fn new() -> Self {
         Self {
@@ -21,8 +67,8 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        self.maze = Parser::parse_lines(lines)?;
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
@@ -42,6 +88,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -64,4 +111,9 @@ mod tests {
     fn test_part_2() {
         get_tester().test_part_2();
     }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
 }
@@ -0,0 +1,9 @@
+pub mod puzzle;
+
+pub use advent_of_code::puzzles::puzzle_solver::PuzzleSolver;
+pub use puzzle::{
+    equation::Equation, equation_state::EquationState, operation::Operation, parser::Parser,
+    solver::Solver, witness::SolvedEquation,
+};
+
+aoc_common::export_day!(7, Solver, parsable, explainable);
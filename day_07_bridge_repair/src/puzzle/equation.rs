@@ -20,6 +20,10 @@ impl Equation {
         self.calibration
     }
 
+    pub fn get_numbers(&self) -> &[usize] {
+        &self.numbers
+    }
+
     pub fn solve_without_concatenation(&self) -> Vec<Vec<Operation>> {
         self.solve(false)
     }
@@ -95,6 +99,8 @@ impl Equation {
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
@@ -139,4 +145,96 @@ mod tests {
             vec![vec![Operation::Concatenation, Operation::Add]]
         );
     }
+
+    fn evaluate(numbers: &[usize], operations: &[Operation]) -> usize {
+        numbers[1..]
+            .iter()
+            .zip(operations)
+            .fold(numbers[0], |value, (number, operation)| match operation {
+                Operation::Add => value + number,
+                Operation::Multiply => value * number,
+                Operation::Concatenation => format!("{}{}", value, number).parse().unwrap(),
+            })
+    }
+
+    // All operator sequences for the given count of numbers
+    fn all_operations(count: usize, allowed: &[Operation]) -> Vec<Vec<Operation>> {
+        (1..count).fold(vec![vec![]], |sequences, _| {
+            sequences
+                .iter()
+                .flat_map(|sequence| {
+                    allowed.iter().map(move |operation| {
+                        let mut next = sequence.clone();
+                        next.push(operation.clone());
+                        next
+                    })
+                })
+                .collect()
+        })
+    }
+
+    fn operation() -> impl Strategy<Value = Operation> {
+        prop_oneof![
+            Just(Operation::Add),
+            Just(Operation::Multiply),
+            Just(Operation::Concatenation),
+        ]
+    }
+
+    // Equations built from random operations, so they always have at least one solution with
+    // concatenation, or with a random calibration
+    fn equation() -> impl Strategy<Value = Equation> {
+        prop::collection::vec(0..100_usize, 1..=6).prop_flat_map(|numbers| {
+            let count = numbers.len();
+
+            (
+                Just(numbers),
+                prop::collection::vec(operation(), count - 1),
+                prop::option::of(0..10_000_usize),
+            )
+                .prop_map(|(numbers, operations, calibration)| {
+                    let calibration =
+                        calibration.unwrap_or_else(|| evaluate(&numbers, &operations));
+                    Equation::new(calibration, numbers)
+                })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_solve_finds_all_solutions(equation in equation()) {
+            let cases = [
+                (
+                    equation.solve_without_concatenation(),
+                    vec![Operation::Add, Operation::Multiply],
+                ),
+                (
+                    equation.solve_with_concatenation(),
+                    vec![Operation::Add, Operation::Multiply, Operation::Concatenation],
+                ),
+            ];
+
+            for (solutions, allowed) in cases {
+                let expected = all_operations(equation.numbers.len(), &allowed)
+                    .into_iter()
+                    .filter(|operations| {
+                        evaluate(&equation.numbers, operations) == equation.calibration
+                    })
+                    .collect::<Vec<_>>();
+
+                prop_assert_eq!(solutions.len(), expected.len());
+                prop_assert!(solutions.iter().all(|solution| expected.contains(solution)));
+            }
+        }
+
+        #[test]
+        fn prop_solve_without_concatenation_is_subset(equation in equation()) {
+            let with_concatenation = equation.solve_with_concatenation();
+
+            for solution in equation.solve_without_concatenation() {
+                prop_assert!(!solution.contains(&Operation::Concatenation));
+                prop_assert!(with_concatenation.contains(&solution));
+            }
+        }
+    }
 }
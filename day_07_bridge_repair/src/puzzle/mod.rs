@@ -3,3 +3,4 @@ pub mod equation_state;
 pub mod operation;
 pub mod parser;
 pub mod solver;
+pub mod witness;
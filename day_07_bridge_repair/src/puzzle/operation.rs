@@ -1,6 +1,11 @@
-#[derive(Debug, Clone, PartialEq)]
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum Operation {
+    #[serde(rename = "+")]
     Add,
+    #[serde(rename = "*")]
     Multiply,
+    #[serde(rename = "||")]
     Concatenation,
 }
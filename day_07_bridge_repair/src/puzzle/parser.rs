@@ -1,40 +1,47 @@
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::parse_error::ParseError;
 
 use super::equation::Equation;
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<Vec<Equation>, PuzzleError> {
-        lines.iter().map(|line| Self::decode_line(line)).collect()
+    pub fn parse_lines(lines: &[&str]) -> Result<Vec<Equation>, ParseError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| Self::decode_line(line).map_err(|err| err.with_line(index)))
+            .collect()
     }
 
-    fn decode_line(line: &str) -> Result<Equation, PuzzleError> {
+    fn decode_line(line: &str) -> Result<Equation, ParseError> {
         let split = line.split(":").collect::<Vec<_>>();
 
         if split.len() != 2 {
-            return Err(PuzzleError::InvalidContentError(format!(
+            return Err(ParseError::new(format!(
                 "Invalid number of elements [{}] after split with :",
                 split.len()
-            )));
+            ))
+            .with_whole_line(line));
         }
 
         // Extract calibration
         let calibration = split[0].parse::<usize>().map_err(|err| {
-            PuzzleError::InvalidContentError(format!(
+            ParseError::new(format!(
                 "Failed to convert 'calibration' '{}' to usize with an error '{}'",
                 split[0], err
             ))
+            .with_substring(line, split[0])
         })?;
 
         // Extract numbers
         let raw_numbers = split[1].split_ascii_whitespace().collect::<Vec<_>>();
 
         if raw_numbers.is_empty() {
-            return Err(PuzzleError::InvalidContentError(format!(
+            return Err(ParseError::new(format!(
                 "Invalid number of elements [{}] after split with <whitespace>",
                 raw_numbers.len()
-            )));
+            ))
+            .with_substring(line, split[1]));
         }
 
         let mut numbers = Vec::new();
@@ -42,10 +49,11 @@ impl Parser {
         for raw_number in raw_numbers {
             // Convert to usize
             let number = raw_number.parse::<usize>().map_err(|err| {
-                PuzzleError::InvalidContentError(format!(
+                ParseError::new(format!(
                     "Failed to convert 'number' '{}' to usize with an error '{}'",
                     raw_number, err
                 ))
+                .with_substring(line, raw_number)
             })?;
 
             // Add to numbers
@@ -67,4 +75,12 @@ mod tests {
         assert!(result.is_ok(), "Result: {:?}", result);
         assert_eq!(result.unwrap(), Equation::new(161011, vec![16, 10, 13]));
     }
+
+    #[test]
+    fn test_decode_line_invalid() {
+        let result = Parser::decode_line("161011: 16 1O 13");
+
+        assert!(result.is_err(), "Result: {:?}", result);
+        assert_eq!(result.unwrap_err().get_columns(), Some(&(12..14)));
+    }
 }
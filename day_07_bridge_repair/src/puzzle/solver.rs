@@ -1,14 +1,103 @@
+use std::collections::HashSet;
+
 use advent_of_code::puzzles::{
     puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
     puzzle_solver::PuzzleSolver,
 };
+use aoc_common::{
+    explainable::Explainable, parsable::Parsable, parse_error::ParseError, part::Part,
+};
 
-use super::{equation::Equation, parser::Parser};
+use super::{equation::Equation, operation::Operation, parser::Parser, witness::SolvedEquation};
 
 pub struct Solver {
     equations: Vec<Equation>,
 }
 
+impl Explainable for Solver {
+    type Witness = Vec<SolvedEquation>;
+    type Error = PuzzleError;
+
+    fn witness(&self, part: Part) -> Result<Self::Witness, PuzzleError> {
+        Ok(self
+            .equations
+            .iter()
+            .enumerate()
+            .filter_map(|(index, equation)| {
+                let solutions = match part {
+                    Part::Part1 => equation.solve_without_concatenation(),
+                    Part::Part2 => equation.solve_with_concatenation(),
+                };
+
+                solutions
+                    .into_iter()
+                    .next()
+                    .map(|operators| SolvedEquation {
+                        line: index + 1,
+                        calibration: equation.get_calibration(),
+                        numbers: equation.get_numbers().to_vec(),
+                        operators,
+                    })
+            })
+            .collect())
+    }
+
+    fn check_witness(&self, part: Part, witness: &Self::Witness) -> Result<String, PuzzleError> {
+        let invalid = |line: usize, reason: &str| {
+            PuzzleError::GenericError(format!("Line {}: {}", line, reason))
+        };
+
+        let mut lines = HashSet::new();
+
+        for solved in witness {
+            let equation = solved
+                .line
+                .checked_sub(1)
+                .and_then(|index| self.equations.get(index))
+                .ok_or_else(|| invalid(solved.line, "line is not in the input"))?;
+
+            if equation.get_calibration() != solved.calibration
+                || equation.get_numbers() != solved.numbers
+            {
+                return Err(invalid(solved.line, "equation differs from the input"));
+            }
+
+            if !lines.insert(solved.line) {
+                return Err(invalid(solved.line, "equation is repeated"));
+            }
+
+            if part == Part::Part1 && solved.operators.contains(&Operation::Concatenation) {
+                return Err(invalid(
+                    solved.line,
+                    "concatenation is allowed only in part 2",
+                ));
+            }
+
+            if solved.evaluate() != Some(solved.calibration) {
+                return Err(invalid(
+                    solved.line,
+                    "operators do not give the calibration",
+                ));
+            }
+        }
+
+        let total = witness
+            .iter()
+            .map(|solved| solved.calibration)
+            .sum::<usize>();
+
+        Ok(total.to_string())
+    }
+}
+
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        self.equations = Parser::parse_lines(lines)?;
+        Ok(())
+    }
+}
+
 impl PuzzleSolver for Solver {
     fn new() -> Self {
         Self {
@@ -21,8 +110,8 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        self.equations = Parser::parse_lines(lines)?;
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
@@ -62,6 +151,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -84,4 +174,52 @@ mod tests {
     fn test_part_2() {
         get_tester().test_part_2();
     }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
+
+    #[test]
+    fn test_explain_examples() {
+        ExampleTester::new(crate::day()).test_explain();
+    }
+
+    #[test]
+    fn test_check_witness_invalid() {
+        let mut solver = Solver::new();
+        solver.equations = vec![Equation::new(156, vec![15, 6])];
+
+        let solved = |line: usize, numbers: Vec<usize>, operators: Vec<Operation>| {
+            vec![SolvedEquation {
+                line,
+                calibration: 156,
+                numbers,
+                operators,
+            }]
+        };
+
+        let valid = solved(1, vec![15, 6], vec![Operation::Concatenation]);
+        assert_eq!(
+            solver.check_witness(Part::Part2, &valid).ok(),
+            Some("156".to_string())
+        );
+        assert!(solver.check_witness(Part::Part1, &valid).is_err());
+
+        let mut repeated = valid.clone();
+        repeated.extend(valid.clone());
+
+        for witness in [
+            solved(2, vec![15, 6], vec![Operation::Concatenation]),
+            solved(1, vec![15, 7], vec![Operation::Concatenation]),
+            solved(1, vec![15, 6], vec![Operation::Add]),
+            repeated,
+        ] {
+            assert!(
+                solver.check_witness(Part::Part2, &witness).is_err(),
+                "witness: {:?}",
+                witness
+            );
+        }
+    }
 }
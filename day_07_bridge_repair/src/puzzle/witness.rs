@@ -0,0 +1,68 @@
+use serde::Serialize;
+
+use super::operation::Operation;
+
+/// Equation of the input together with operators which evaluate it to its calibration
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SolvedEquation {
+    pub line: usize,
+    pub calibration: usize,
+    pub numbers: Vec<usize>,
+    pub operators: Vec<Operation>,
+}
+
+impl SolvedEquation {
+    /// Evaluate numbers with operators strictly left to right, None on overflow
+    pub fn evaluate(&self) -> Option<usize> {
+        if self.numbers.is_empty() || self.operators.len() + 1 != self.numbers.len() {
+            return None;
+        }
+
+        self.numbers[1..].iter().zip(&self.operators).try_fold(
+            self.numbers[0],
+            |value, (&number, operation)| match operation {
+                Operation::Add => value.checked_add(number),
+                Operation::Multiply => value.checked_mul(number),
+                Operation::Concatenation => format!("{}{}", value, number).parse().ok(),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate() {
+        let solved = |numbers: Vec<usize>, operators: Vec<Operation>| SolvedEquation {
+            line: 1,
+            calibration: 0,
+            numbers,
+            operators,
+        };
+
+        assert_eq!(
+            solved(vec![81, 40, 27], vec![Operation::Add, Operation::Multiply]).evaluate(),
+            Some(3267)
+        );
+        assert_eq!(
+            solved(
+                vec![6, 8, 6, 15],
+                vec![
+                    Operation::Multiply,
+                    Operation::Concatenation,
+                    Operation::Multiply
+                ]
+            )
+            .evaluate(),
+            Some(7290)
+        );
+        assert_eq!(solved(vec![7], vec![]).evaluate(), Some(7));
+        assert_eq!(solved(vec![7, 8], vec![]).evaluate(), None);
+        assert_eq!(
+            solved(vec![usize::MAX, 2], vec![Operation::Multiply]).evaluate(),
+            None
+        );
+    }
+}
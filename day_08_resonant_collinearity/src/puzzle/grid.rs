@@ -1,8 +1,6 @@
-use std::{
-    cell::RefCell,
-    collections::{HashMap, HashSet},
-};
+use std::collections::{HashMap, HashSet};
 
+use aoc_common::render::frame::Frame;
 use itertools::Itertools;
 
 use super::{part::Part, position::Position};
@@ -11,7 +9,6 @@ pub struct Grid {
     grid: Vec<Vec<char>>,
     rows: usize,
     cols: usize,
-    algorithm: RefCell<Part>,
 }
 
 impl Default for Grid {
@@ -25,19 +22,10 @@ impl Grid {
         let rows = grid.len();
         let cols = if rows != 0 { grid[0].len() } else { 0 };
 
-        Self {
-            grid,
-            rows,
-            cols,
-            algorithm: RefCell::new(Part::Part1),
-        }
-    }
-
-    pub fn set_algorithm(&self, algorithm: Part) {
-        *self.algorithm.borrow_mut() = algorithm;
+        Self { grid, rows, cols }
     }
 
-    pub fn collect_anti_nodes(&self) -> HashSet<Position> {
+    pub fn collect_anti_nodes(&self, algorithm: Part) -> HashSet<Position> {
         let mut anti_nodes = HashSet::new();
 
         let antennas = self.collect_antennas();
@@ -51,7 +39,7 @@ impl Grid {
             let combinations = positions.iter().combinations(2);
 
             for combination in combinations {
-                let nodes = match *self.algorithm.borrow() {
+                let nodes = match algorithm {
                     Part::Part1 => self.compute_anti_nodes(combination[0], combination[1]),
                     Part::Part2 => {
                         self.compute_anti_nodes_recursive(combination[0], combination[1])
@@ -67,6 +55,13 @@ impl Grid {
         anti_nodes
     }
 
+    /// Frame with antennas, tiles are `(column, row)`
+    pub fn to_frame(&self, title: &str) -> Frame {
+        Frame::new(title, self.rows, self.cols, |x, y| {
+            self.grid[y as usize][x as usize]
+        })
+    }
+
     fn compute_anti_nodes(&self, a: &Position, b: &Position) -> Vec<Position> {
         // Return only valid anti node positions
         let x_diff = a.x.abs_diff(b.x);
@@ -373,7 +368,7 @@ mod tests {
     fn test_collect_anti_nodes_simple() {
         let grid = create_grid_simple();
 
-        let positions = grid.collect_anti_nodes();
+        let positions = grid.collect_anti_nodes(Part::Part1);
         assert_eq!(
             positions,
             [Position::new(1, 3), Position::new(7, 6)]
@@ -386,7 +381,7 @@ mod tests {
     fn test_collect_anti_nodes_simple_reversed() {
         let grid = create_grid_simple_reversed();
 
-        let positions = grid.collect_anti_nodes();
+        let positions = grid.collect_anti_nodes(Part::Part1);
         assert_eq!(
             positions,
             [Position::new(1, 6), Position::new(7, 3)]
@@ -399,7 +394,7 @@ mod tests {
     fn test_collect_anti_nodes_medium() {
         let grid = create_grid_medium();
 
-        let positions = grid.collect_anti_nodes();
+        let positions = grid.collect_anti_nodes(Part::Part1);
         assert_eq!(
             positions,
             [
@@ -417,7 +412,7 @@ mod tests {
     fn test_collect_anti_nodes_complex() {
         let grid = create_grid_complex();
 
-        let positions = grid.collect_anti_nodes();
+        let positions = grid.collect_anti_nodes(Part::Part1);
 
         assert_eq!(
             positions,
@@ -445,9 +440,8 @@ mod tests {
     #[test]
     fn test_collect_anti_nodes_simple_recursive() {
         let grid = create_grid_simple();
-        grid.set_algorithm(Part::Part2);
 
-        let positions = grid.collect_anti_nodes();
+        let positions = grid.collect_anti_nodes(Part::Part2);
         assert_eq!(
             positions,
             [
@@ -465,9 +459,8 @@ mod tests {
     #[test]
     fn test_collect_anti_nodes_simple_recursive_reversed() {
         let grid = create_grid_simple_reversed();
-        grid.set_algorithm(Part::Part2);
 
-        let positions = grid.collect_anti_nodes();
+        let positions = grid.collect_anti_nodes(Part::Part2);
         assert_eq!(
             positions,
             [
@@ -485,9 +478,8 @@ mod tests {
     #[test]
     fn test_collect_anti_nodes_complex_recursive() {
         let grid = create_grid_complex();
-        grid.set_algorithm(Part::Part2);
 
-        let positions = grid.collect_anti_nodes();
+        let positions = grid.collect_anti_nodes(Part::Part2);
 
         assert_eq!(
             positions,
@@ -535,9 +527,8 @@ mod tests {
     #[test]
     fn test_collect_anti_nodes_t_complex_recursive() {
         let grid = create_grid_t_complex();
-        grid.set_algorithm(Part::Part2);
 
-        let positions = grid.collect_anti_nodes();
+        let positions = grid.collect_anti_nodes(Part::Part2);
 
         assert_eq!(
             positions,
@@ -1,11 +1,16 @@
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::{grid_lines, parse_error::ParseError};
 
 use super::grid::Grid;
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<Grid, PuzzleError> {
+    pub fn parse_lines(lines: &[&str]) -> Result<Grid, ParseError> {
+        // Antennas are marked by a letter or a digit
+        grid_lines::check_rectangular(lines).and_then(|_| {
+            grid_lines::check_characters(lines, |c| c == '.' || c.is_ascii_alphanumeric())
+        })?;
+
         let grid = lines
             .iter()
             .map(|line| line.chars().collect::<Vec<_>>())
@@ -1,7 +1,15 @@
 use advent_of_code::puzzles::{
     puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
     puzzle_solver::PuzzleSolver,
 };
+use aoc_common::{
+    parsable::Parsable,
+    parse_error::ParseError,
+    part::Part as AocPart,
+    render::{frame::Frame, layer::Layer},
+    renderable::Renderable,
+};
 
 use super::{grid::Grid, parser::Parser, part::Part};
 
@@ -9,6 +17,37 @@ pub struct Solver {
     grid: Grid,
 }
 
+impl Renderable for Solver {
+    type Error = PuzzleError;
+
+    fn frames(&self, part: AocPart) -> Result<Vec<Frame>, PuzzleError> {
+        let algorithm = match part {
+            AocPart::Part1 => Part::Part1,
+            AocPart::Part2 => Part::Part2,
+        };
+
+        // Positions are (row, column) while frames use (column, row)
+        let anti_nodes = self.grid.collect_anti_nodes(algorithm);
+
+        let mut frame = self.grid.to_frame("Antinodes of antennas");
+        frame.add_layer(
+            Layer::Antinode,
+            anti_nodes
+                .iter()
+                .map(|node| (node.y as isize, node.x as isize)),
+        );
+
+        Ok(vec![frame])
+    }
+}
+
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        self.grid = Parser::parse_lines(lines)?;
+        Ok(())
+    }
+}
+
 impl PuzzleSolver for Solver {
     fn new() -> Self {
         Self {
@@ -21,19 +60,17 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        self.grid = Parser::parse_lines(lines)?;
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
-        self.grid.set_algorithm(Part::Part1);
-        let anti_nodes_len = self.grid.collect_anti_nodes().len();
+        let anti_nodes_len = self.grid.collect_anti_nodes(Part::Part1).len();
         Ok(anti_nodes_len.to_string())
     }
 
     fn part_2(&self) -> SolutionResult {
-        self.grid.set_algorithm(Part::Part2);
-        let anti_nodes_len = self.grid.collect_anti_nodes().len();
+        let anti_nodes_len = self.grid.collect_anti_nodes(Part::Part2).len();
         Ok(anti_nodes_len.to_string())
     }
 }
@@ -44,6 +81,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -66,4 +104,9 @@ mod tests {
     fn test_part_2() {
         get_tester().test_part_2();
     }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
 }
@@ -0,0 +1,6 @@
+pub mod puzzle;
+
+pub use advent_of_code::puzzles::puzzle_solver::PuzzleSolver;
+pub use puzzle::{block::Block, disk_map::DiskMap, parser::Parser, solver::Solver};
+
+aoc_common::export_day!(9, Solver, parsable);
@@ -1,20 +1,30 @@
+use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use tracing::debug;
+
 use super::block::Block;
 
 #[derive(Default)]
 pub struct DiskMap {
-    dense_format: String,
+    blocks: Vec<Block>,
 }
 
 impl DiskMap {
-    pub fn new(dense_format: &str) -> Self {
-        Self {
-            dense_format: String::from(dense_format),
+    pub fn new(dense_format: &str) -> Result<Self, PuzzleError> {
+        // Expand raw format
+        let blocks = Self::expand(dense_format)?;
+
+        if blocks.is_empty() {
+            return Err(PuzzleError::InvalidContentError(format!(
+                "Disk map '{}' does not contain any block",
+                dense_format
+            )));
         }
+
+        Ok(Self { blocks })
     }
 
     pub fn compact_per_block_get_checksum(&self) -> usize {
-        // Expand raw format
-        let mut expanded = Self::expand(&self.dense_format);
+        let mut expanded = self.blocks.clone();
 
         // Compact file format
         Self::compact_per_block(&mut expanded);
@@ -24,8 +34,7 @@ impl DiskMap {
     }
 
     pub fn compact_per_file_get_checksum(&self) -> usize {
-        // Expand raw format
-        let mut expanded = Self::expand(&self.dense_format);
+        let mut expanded = self.blocks.clone();
 
         // Compact file format
         Self::compact_per_file(&mut expanded);
@@ -45,14 +54,17 @@ impl DiskMap {
             .sum()
     }
 
-    fn expand(dense_format: &str) -> Vec<Block> {
+    fn expand(dense_format: &str) -> Result<Vec<Block>, PuzzleError> {
         // NOTE: Warning - magic number 10
         let mut blocks = Vec::with_capacity(dense_format.len() * 10);
 
         for (id, c) in dense_format.chars().enumerate() {
-            let number = c.to_digit(10).unwrap_or_else(|| {
-                panic!("Invalid non-numeric digit '{}' detected", c);
-            }) as usize;
+            let number = c.to_digit(10).ok_or_else(|| {
+                PuzzleError::InvalidContentError(format!(
+                    "Invalid non-numeric digit '{}' detected",
+                    c
+                ))
+            })? as usize;
 
             let mut data = match id % 2 == 0 {
                 // Block
@@ -69,7 +81,7 @@ impl DiskMap {
             blocks.append(&mut data);
         }
 
-        blocks
+        Ok(blocks)
     }
 
     fn compact_per_block(blocks: &mut [Block]) {
@@ -83,6 +95,7 @@ impl DiskMap {
             }
 
             // Swap elements
+            debug!(from = file_index, to = free_index, "Move block");
             blocks.swap(free_index, file_index);
 
             // Find next candidates
@@ -117,6 +130,7 @@ impl DiskMap {
         let mut file;
         let mut free;
 
+        // Nothing can be moved in front of the first block, so the position saturates at zero
         while right_position > 0 {
             file = Self::find_file_from_right(blocks, right_position);
             free = Self::find_free_from_left_from_file(blocks, 0, file);
@@ -128,11 +142,17 @@ impl DiskMap {
             {
                 // Swap only if free is before file
                 if free_index_from > file_index_from {
-                    right_position = file_index_from - 1;
+                    right_position = file_index_from.saturating_sub(1);
                     continue;
                 }
 
                 // Swap elements
+                debug!(
+                    from = file_index_from,
+                    to = free_index_from,
+                    size = file_index_to - file_index_from + 1,
+                    "Move file"
+                );
                 for (free_index, file_index) in
                     (free_index_from..=free_index_to).zip(file_index_from..=file_index_to)
                 {
@@ -140,10 +160,10 @@ impl DiskMap {
                 }
 
                 // Update position
-                right_position = file_index_from - 1;
+                right_position = file_index_from.saturating_sub(1);
             } else if let Some((file_index_from, _file_index_to)) = file {
                 // Update position
-                right_position = file_index_from - 1;
+                right_position = file_index_from.saturating_sub(1);
             } else {
                 right_position -= 1;
             }
@@ -233,24 +253,28 @@ impl DiskMap {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
     fn test_compact_per_block_get_checksum() {
-        let disk_map = DiskMap::new("2333133121414131402");
+        let disk_map = DiskMap::new("2333133121414131402").expect("Failed to create disk map");
         assert_eq!(disk_map.compact_per_block_get_checksum(), 1928);
     }
 
     #[test]
     fn test_compact_per_file_get_checksum() {
-        let disk_map = DiskMap::new("2333133121414131402");
+        let disk_map = DiskMap::new("2333133121414131402").expect("Failed to create disk map");
         assert_eq!(disk_map.compact_per_file_get_checksum(), 2858);
     }
 
     #[test]
     fn test_expand() {
         assert_eq!(
-            DiskMap::expand("12345"),
+            DiskMap::expand("12345").expect("Failed to expand disk map"),
             vec![
                 Block::File { id: 0 },
                 Block::Free,
@@ -271,6 +295,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_new_invalid_content() {
+        assert!(matches!(
+            DiskMap::new("12a45"),
+            Err(PuzzleError::InvalidContentError(_))
+        ));
+        assert!(matches!(
+            DiskMap::new(""),
+            Err(PuzzleError::InvalidContentError(_))
+        ));
+    }
+
     #[test]
     fn test_compact_per_block() {
         let mut blocks = vec![
@@ -661,4 +697,127 @@ mod tests {
         assert_eq!(DiskMap::find_free_from_left_len(&blocks, 14, 4), None);
         assert_eq!(DiskMap::find_free_from_left_len(&blocks, 14, 5), None);
     }
+
+    #[test]
+    fn test_compact_per_file_first_file_cannot_move() {
+        let disk_map = DiskMap::new("11").expect("Failed to create disk map");
+        assert_eq!(disk_map.compact_per_file_get_checksum(), 0);
+
+        let disk_map = DiskMap::new("1").expect("Failed to create disk map");
+        assert_eq!(disk_map.compact_per_file_get_checksum(), 0);
+    }
+
+    fn count_files(blocks: &[Block]) -> HashMap<usize, usize> {
+        let mut counts = HashMap::new();
+
+        for block in blocks {
+            if let Block::File { id } = block {
+                *counts.entry(*id).or_insert(0) += 1;
+            }
+        }
+
+        counts
+    }
+
+    fn file_start(blocks: &[Block], file_id: usize) -> Option<usize> {
+        blocks
+            .iter()
+            .position(|block| block == &Block::File { id: file_id })
+    }
+
+    // Straightforward per file compaction over (start, len) spans used as a reference
+    fn compact_per_file_reference(blocks: &[Block]) -> Vec<Block> {
+        let mut files: Vec<(usize, usize)> = Vec::new();
+        let mut free: Vec<(usize, usize)> = Vec::new();
+
+        let mut index = 0;
+        while index < blocks.len() {
+            let len = blocks[index..]
+                .iter()
+                .take_while(|block| *block == &blocks[index])
+                .count();
+
+            match blocks[index] {
+                Block::File { id } => {
+                    files.resize(files.len().max(id + 1), (0, 0));
+                    files[id] = (index, len);
+                }
+                Block::Free => free.push((index, len)),
+            }
+
+            index += len;
+        }
+
+        // Every file is moved at most once in the order of decreasing file id
+        for (start, len) in files.iter_mut().rev() {
+            if let Some(span) = free
+                .iter_mut()
+                .find(|(free_start, free_len)| *free_start < *start && *free_len >= *len)
+            {
+                *start = span.0;
+                span.0 += *len;
+                span.1 -= *len;
+            }
+        }
+
+        let mut compacted = vec![Block::Free; blocks.len()];
+        for (id, (start, len)) in files.iter().enumerate() {
+            for block in compacted.iter_mut().skip(*start).take(*len) {
+                *block = Block::File { id };
+            }
+        }
+
+        compacted
+    }
+
+    fn dense_format() -> impl Strategy<Value = String> {
+        // Files have at least one block, so they are always present in the expanded format
+        prop::collection::vec((1..=9_u32, 0..=9_u32), 1..=20).prop_map(|pairs| {
+            pairs
+                .iter()
+                .flat_map(|(file, free)| [file, free])
+                .map(|digit| char::from_digit(*digit, 10).unwrap())
+                .collect()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_compact_per_block(dense_format in dense_format()) {
+            let original = DiskMap::expand(&dense_format).unwrap();
+            let mut blocks = original.clone();
+
+            DiskMap::compact_per_block(&mut blocks);
+
+            prop_assert_eq!(blocks.len(), original.len());
+            prop_assert_eq!(count_files(&blocks), count_files(&original));
+
+            // There is no free space between files
+            let files = blocks.iter().take_while(|block| **block != Block::Free).count();
+            prop_assert!(blocks[files..].iter().all(|block| *block == Block::Free));
+        }
+
+        #[test]
+        fn prop_compact_per_file(dense_format in dense_format()) {
+            let original = DiskMap::expand(&dense_format).unwrap();
+            let mut blocks = original.clone();
+
+            DiskMap::compact_per_file(&mut blocks);
+
+            prop_assert_eq!(blocks.len(), original.len());
+            prop_assert_eq!(count_files(&blocks), count_files(&original));
+
+            // Files are moved only as a whole and only to the left
+            for (id, len) in count_files(&original) {
+                let start = file_start(&blocks, id).unwrap();
+
+                let file = Block::File { id };
+
+                prop_assert!(blocks[start..start + len].iter().all(|block| *block == file));
+                prop_assert!(start <= file_start(&original, id).unwrap());
+            }
+
+            prop_assert_eq!(&blocks, &compact_per_file_reference(&original));
+        }
+    }
 }
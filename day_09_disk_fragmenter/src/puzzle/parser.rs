@@ -1,19 +1,22 @@
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::{grid_lines, parse_error::ParseError};
 
 use super::disk_map::DiskMap;
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<DiskMap, PuzzleError> {
+    pub fn parse_lines(lines: &[&str]) -> Result<DiskMap, ParseError> {
         // We expect only a single line in the input file
         if lines.len() != 1 {
-            return Err(PuzzleError::InvalidContentError(format!(
+            return Err(ParseError::new(format!(
                 "Input file should contain only 1 line but {} found",
                 lines.len()
             )));
         }
 
-        Ok(DiskMap::new(lines[0]))
+        // Point to the exact position of non-numeric digit
+        grid_lines::check_characters(lines, |c| c.is_ascii_digit())?;
+
+        DiskMap::new(lines[0]).map_err(|err| ParseError::new(err.to_string()))
     }
 }
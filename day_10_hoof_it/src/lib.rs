@@ -0,0 +1,9 @@
+pub mod puzzle;
+
+pub use advent_of_code::puzzles::puzzle_solver::PuzzleSolver;
+pub use puzzle::{
+    grid::Grid, parser::Parser, position::Position, solver::Solver,
+    topographic_map::TopographicMap, topographic_state::TopographicState,
+};
+
+aoc_common::export_day!(10, Solver, parsable, renderable);
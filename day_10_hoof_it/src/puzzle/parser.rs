@@ -1,30 +1,36 @@
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::{grid_lines, parse_error::ParseError};
 
 use super::topographic_map::TopographicMap;
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<TopographicMap, PuzzleError> {
-        let topography: Result<Vec<Vec<u8>>, PuzzleError> =
-            lines.iter().map(|line| Parser::parse_line(line)).collect();
+    pub fn parse_lines(lines: &[&str]) -> Result<TopographicMap, ParseError> {
+        grid_lines::check_rectangular(lines)?;
 
-        Ok(TopographicMap::new(topography?))
+        let topography: Result<Vec<Vec<u8>>, ParseError> = lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| Parser::parse_line(line).map_err(|err| err.with_line(index)))
+            .collect();
+
+        TopographicMap::new(topography?).map_err(|err| ParseError::new(err.to_string()))
     }
 
-    fn parse_line(line: &str) -> Result<Vec<u8>, PuzzleError> {
+    fn parse_line(line: &str) -> Result<Vec<u8>, ParseError> {
         let mut digits = Vec::new();
 
-        for c in line.chars() {
+        for (position, c) in line.char_indices() {
             match c.to_string().parse::<u8>() {
                 Ok(number) => {
                     digits.push(number);
                 }
                 Err(err) => {
-                    return Err(PuzzleError::InvalidContentError(format!(
+                    return Err(ParseError::new(format!(
                         "Failed to parse digit '{}' to u8 with an error '{}'",
                         c, err
-                    )))
+                    ))
+                    .with_span(line, position..position + c.len_utf8()))
                 }
             }
         }
@@ -50,5 +56,6 @@ mod tests {
         let result = Parser::parse_line("012345x6789");
 
         assert!(result.is_err(), "result: {:?}", result);
+        assert_eq!(result.unwrap_err().get_columns(), Some(&(7..8)));
     }
 }
@@ -1,14 +1,57 @@
 use advent_of_code::puzzles::{
     puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
     puzzle_solver::PuzzleSolver,
 };
+use aoc_common::{
+    parsable::Parsable,
+    parse_error::ParseError,
+    part::Part,
+    render::{frame::Frame, layer::Layer},
+    renderable::Renderable,
+};
 
-use super::{parser::Parser, topographic_map::TopographicMap};
+use super::{parser::Parser, position::Position, topographic_map::TopographicMap};
 
 pub struct Solver {
     topographic_map: TopographicMap,
 }
 
+impl Renderable for Solver {
+    type Error = PuzzleError;
+
+    fn frames(&self, part: Part) -> Result<Vec<Frame>, PuzzleError> {
+        let to_point = |position: &Position| (position.col as isize, position.row as isize);
+        let map = &self.topographic_map;
+
+        let mut frame = match part {
+            Part::Part1 => {
+                let mut frame = map.to_frame("Summits reachable from trail heads");
+                frame.add_layer(
+                    Layer::Path,
+                    map.find_reachable_summits().iter().map(to_point),
+                );
+                frame
+            }
+            Part::Part2 => {
+                let mut frame = map.to_frame("Tiles of all hiking trails");
+                frame.add_layer(Layer::Path, map.find_trail_tiles().iter().map(to_point));
+                frame
+            }
+        };
+        frame.add_layer(Layer::Marker, map.find_trail_heads().iter().map(to_point));
+
+        Ok(vec![frame])
+    }
+}
+
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        self.topographic_map = Parser::parse_lines(lines)?;
+        Ok(())
+    }
+}
+
 impl PuzzleSolver for Solver {
     fn new() -> Self {
         Self {
@@ -21,8 +64,8 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        self.topographic_map = Parser::parse_lines(lines)?;
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
@@ -42,6 +85,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -64,4 +108,9 @@ mod tests {
     fn test_part_2() {
         get_tester().test_part_2();
     }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
 }
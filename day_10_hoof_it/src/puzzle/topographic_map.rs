@@ -1,6 +1,9 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
-use super::{position::Position, topographic_state::TopographicState};
+use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::render::frame::Frame;
+
+use super::{grid::Grid, position::Position, topographic_state::TopographicState};
 
 const TRAIL_HEAD_START: u8 = 0;
 const TRAIL_HEAD_END: u8 = 9;
@@ -12,25 +15,41 @@ pub struct TopographicMap {
     internal: Vec<Vec<u8>>,
 }
 
+impl Grid for TopographicMap {
+    fn rows_len(&self) -> usize {
+        self.rows
+    }
+
+    fn cols_len(&self) -> usize {
+        self.cols
+    }
+}
+
 impl TopographicMap {
-    pub fn new(grid: Vec<Vec<u8>>) -> Self {
+    pub fn new(grid: Vec<Vec<u8>>) -> Result<Self, PuzzleError> {
         if grid.is_empty() {
-            panic!("Empty grid");
+            return Err(PuzzleError::InvalidContentError(String::from(
+                "Topographic map is empty",
+            )));
         }
 
-        Self {
-            rows: grid.len(),
-            cols: grid[0].len(),
-            internal: grid,
-        }
-    }
+        let cols = grid[0].len();
 
-    pub fn rows_len(&self) -> usize {
-        self.rows
-    }
+        // All rows must have the same length
+        if let Some((row, line)) = grid.iter().enumerate().find(|(_, line)| line.len() != cols) {
+            return Err(PuzzleError::InvalidContentError(format!(
+                "Row {} has {} columns but {} expected",
+                row,
+                line.len(),
+                cols
+            )));
+        }
 
-    pub fn cols_len(&self) -> usize {
-        self.cols
+        Ok(Self {
+            rows: grid.len(),
+            cols,
+            internal: grid,
+        })
     }
 
     pub fn count_trail_heads_score(&self) -> usize {
@@ -42,7 +61,7 @@ impl TopographicMap {
             .sum()
     }
 
-    fn find_trail_heads(&self) -> Vec<Position> {
+    pub fn find_trail_heads(&self) -> Vec<Position> {
         let mut trail_heads = Vec::new();
 
         for row in 0..self.rows {
@@ -110,6 +129,67 @@ impl TopographicMap {
             .sum()
     }
 
+    /// Summits which can be reached from any trail head
+    pub fn find_reachable_summits(&self) -> Vec<Position> {
+        self.find_reachable_tiles()
+            .into_iter()
+            .filter(|position| self.get_value(position) == TRAIL_HEAD_END)
+            .collect()
+    }
+
+    /// Tiles which are part of any hiking trail from a trail head to a summit
+    pub fn find_trail_tiles(&self) -> HashSet<Position> {
+        let reachable = self.find_reachable_tiles();
+
+        // Walk down from reachable summits, only tiles reachable from a trail head are kept
+        let mut trail = reachable
+            .iter()
+            .filter(|position| self.get_value(position) == TRAIL_HEAD_END)
+            .cloned()
+            .collect::<HashSet<_>>();
+        let mut remaining = trail.iter().cloned().collect::<VecDeque<_>>();
+
+        while let Some(position) = remaining.pop_front() {
+            let value = self.get_value(&position);
+
+            for neighbor in position.get_neighbors(self) {
+                if self.get_value(&neighbor) + 1 == value
+                    && reachable.contains(&neighbor)
+                    && trail.insert(neighbor.clone())
+                {
+                    remaining.push_back(neighbor);
+                }
+            }
+        }
+
+        trail
+    }
+
+    // Tiles which can be reached from any trail head with a gradual uphill slope
+    fn find_reachable_tiles(&self) -> HashSet<Position> {
+        let mut reachable = self.find_trail_heads().into_iter().collect::<HashSet<_>>();
+        let mut remaining = reachable.iter().cloned().collect::<VecDeque<_>>();
+
+        while let Some(position) = remaining.pop_front() {
+            let value = self.get_value(&position);
+
+            for neighbor in position.get_neighbors(self) {
+                if self.get_value(&neighbor) == value + 1 && reachable.insert(neighbor.clone()) {
+                    remaining.push_back(neighbor);
+                }
+            }
+        }
+
+        reachable
+    }
+
+    /// Frame with heights of the map, tiles are `(column, row)`
+    pub fn to_frame(&self, title: &str) -> Frame {
+        Frame::new(title, self.rows, self.cols, |x, y| {
+            char::from(b'0' + self.internal[y as usize][x as usize])
+        })
+    }
+
     fn count_trail_head_rating(topographic_map: &TopographicMap, trail_head: &Position) -> usize {
         let solutions = Self::find_trail_head_solutions(topographic_map, trail_head);
         solutions.values().copied().sum()
@@ -127,6 +207,7 @@ mod tests {
             vec![8, 7, 6, 5],
             vec![9, 8, 7, 6],
         ])
+        .expect("Failed to create topographic map")
     }
 
     fn create_map_complex() -> TopographicMap {
@@ -140,6 +221,37 @@ mod tests {
             vec![0, 1, 3, 2, 9, 8, 0, 1],
             vec![1, 0, 4, 5, 6, 7, 3, 2],
         ])
+        .expect("Failed to create topographic map")
+    }
+
+    #[test]
+    fn test_new_invalid_content() {
+        assert!(matches!(
+            TopographicMap::new(vec![]),
+            Err(PuzzleError::InvalidContentError(_))
+        ));
+        assert!(matches!(
+            TopographicMap::new(vec![vec![0, 1], vec![2]]),
+            Err(PuzzleError::InvalidContentError(_))
+        ));
+    }
+
+    #[test]
+    fn test_find_reachable_summits() {
+        assert_eq!(
+            create_map_simple().find_reachable_summits(),
+            vec![Position { row: 3, col: 0 }]
+        );
+        assert_eq!(create_map_complex().find_reachable_summits().len(), 7);
+    }
+
+    #[test]
+    fn test_find_trail_tiles() {
+        assert_eq!(create_map_simple().find_trail_tiles().len(), 16);
+
+        let trail = create_map_complex().find_trail_tiles();
+        assert!(trail.contains(&Position { row: 0, col: 2 }));
+        assert!(!trail.contains(&Position { row: 0, col: 0 }));
     }
 
     #[test]
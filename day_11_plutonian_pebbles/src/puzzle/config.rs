@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+/// Puzzle constants, defaults match the puzzle description
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Count of blinks in part 1
+    pub blinks_part_1: usize,
+    /// Count of blinks in part 2
+    pub blinks_part_2: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            blinks_part_1: 25,
+            blinks_part_2: 75,
+        }
+    }
+}
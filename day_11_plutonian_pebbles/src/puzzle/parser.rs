@@ -1,26 +1,28 @@
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::parse_error::ParseError;
 
 use super::pebbles::Pebbles;
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<Pebbles, PuzzleError> {
+    pub fn parse_lines(lines: &[&str]) -> Result<Pebbles, ParseError> {
         if lines.len() != 1 {
-            return Err(PuzzleError::InvalidContentError(format!(
+            return Err(ParseError::new(format!(
                 "Input file should contain only single line but '{}'",
                 lines.len()
             )));
         };
 
-        let numbers: Result<Vec<usize>, PuzzleError> = lines[0]
+        let numbers: Result<Vec<usize>, ParseError> = lines[0]
             .split_ascii_whitespace()
             .map(|value| {
                 value.parse::<usize>().map_err(|err| {
-                    PuzzleError::InvalidContentError(format!(
+                    ParseError::new(format!(
                         "Failed to convert '{}' to usize with an error '{}'",
                         value, err
                     ))
+                    .with_substring(lines[0], value)
+                    .with_line(0)
                 })
             })
             .collect();
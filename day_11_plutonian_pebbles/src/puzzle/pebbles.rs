@@ -43,7 +43,7 @@ impl Pebbles {
         // Rule 2
         let pebble_string = pebble.to_string();
         let pebble_len = pebble_string.len();
-        if pebble_len % 2 == 0 {
+        if pebble_len.is_multiple_of(2) {
             let middle = pebble_len / 2;
             let left = pebble_string[0..middle]
                 .parse::<usize>()
@@ -93,11 +93,8 @@ impl Pebbles {
     pub fn blink_stones_count(&self, blinks: usize) -> usize {
         // Collection contains also keys with 0 count, i.e. these numbers are not present
         self.blink(blinks)
-            .into_iter()
-            .filter_map(|(_, count)| match count {
-                0 => None,
-                _ => Some(count),
-            })
+            .into_values()
+            .filter(|&count| count != 0)
             .sum()
     }
 }
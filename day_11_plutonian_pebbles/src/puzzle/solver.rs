@@ -1,17 +1,36 @@
 use advent_of_code::puzzles::{
     puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
     puzzle_solver::PuzzleSolver,
 };
+use aoc_common::{configurable::Configurable, parsable::Parsable, parse_error::ParseError};
 
-use super::{parser::Parser, pebbles::Pebbles};
+use super::{config::Config, parser::Parser, pebbles::Pebbles};
 
 pub struct Solver {
+    config: Config,
     pebbles: Pebbles,
 }
 
+impl Configurable for Solver {
+    type Config = Config;
+
+    fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+}
+
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        self.pebbles = Parser::parse_lines(lines)?;
+        Ok(())
+    }
+}
+
 impl PuzzleSolver for Solver {
     fn new() -> Self {
         Self {
+            config: Config::default(),
             pebbles: Pebbles::default(),
         }
     }
@@ -21,17 +40,17 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        self.pebbles = Parser::parse_lines(lines)?;
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
-        let stones_count = self.pebbles.blink_stones_count(25);
+        let stones_count = self.pebbles.blink_stones_count(self.config.blinks_part_1);
         Ok(stones_count.to_string())
     }
 
     fn part_2(&self) -> SolutionResult {
-        let stones_count = self.pebbles.blink_stones_count(75);
+        let stones_count = self.pebbles.blink_stones_count(self.config.blinks_part_2);
         Ok(stones_count.to_string())
     }
 }
@@ -42,6 +61,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -64,4 +84,9 @@ mod tests {
     fn test_part_2() {
         get_tester().test_part_2();
     }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
 }
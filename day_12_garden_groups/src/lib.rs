@@ -0,0 +1,9 @@
+pub mod puzzle;
+
+pub use advent_of_code::puzzles::puzzle_solver::PuzzleSolver;
+pub use puzzle::{
+    corners::Corners, garden::Garden, parser::Parser, plot::Plot, position::Position,
+    solver::Solver,
+};
+
+aoc_common::export_day!(12, Solver, parsable, renderable);
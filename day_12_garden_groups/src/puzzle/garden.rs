@@ -1,81 +1,68 @@
-use std::{cell::RefCell, collections::VecDeque};
+use std::collections::{BTreeSet, VecDeque};
+
+use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::render::{frame::Frame, layer::Layer};
 
 use super::{corners::Corners, plot::Plot, position::Position};
 
+// Holds already analyzed positions, each fence computation uses its own copy
+type Visited = Vec<Vec<bool>>;
+
 #[derive(Default)]
 pub struct Garden {
     rows: usize,
     cols: usize,
     grid: Vec<Vec<char>>,
-    visited: RefCell<Vec<Vec<bool>>>,
 }
 
 impl Garden {
-    pub fn new(grid: Vec<Vec<char>>) -> Self {
-        assert!(!grid.is_empty());
+    pub fn new(grid: Vec<Vec<char>>) -> Result<Self, PuzzleError> {
+        if grid.is_empty() {
+            return Err(PuzzleError::InvalidContentError(String::from(
+                "Garden is empty",
+            )));
+        }
 
         let rows = grid.len();
         let cols = grid[0].len();
 
-        // Fill in visited to false
-        let mut visited = Vec::with_capacity(rows);
-        for _ in 0..rows {
-            visited.push(vec![false; cols]);
+        // All rows must have the same length
+        if let Some((row, line)) = grid.iter().enumerate().find(|(_, line)| line.len() != cols) {
+            return Err(PuzzleError::InvalidContentError(format!(
+                "Row {} has {} columns but {} expected",
+                row,
+                line.len(),
+                cols
+            )));
         }
 
-        Self {
-            rows,
-            cols,
-            grid,
-            visited: RefCell::new(visited),
-        }
+        Ok(Self { rows, cols, grid })
     }
 
-    fn reset(&self) {
-        let mut visited = self.visited.borrow_mut();
-
-        for row in 0..self.rows {
-            for col in 0..self.cols {
-                visited[row][col] = false;
-            }
-        }
+    fn new_visited(&self) -> Visited {
+        vec![vec![false; self.cols]; self.rows]
     }
 
     pub fn fence_price(&self) -> usize {
-        self.reset();
-
+        let mut visited = self.new_visited();
         let mut price = 0;
 
-        while let Some(next) = self.find_next_position() {
-            price += self.area_price(&next);
+        while let Some(next) = self.find_next_position(&visited) {
+            price += self.area_price(&next, &mut visited);
         }
 
         price
     }
 
-    fn find_next_position(&self) -> Option<Position> {
-        for row in 0..self.rows {
-            for col in 0..self.cols {
-                if !self.visited.borrow()[row][col] {
-                    return Some(Position::new(row, col));
-                }
-            }
-        }
-
-        None
-    }
-
-    #[inline]
-    fn is_visited(&self, pos: &Position) -> bool {
-        self.visited.borrow()[pos.row][pos.col]
-    }
-
-    #[inline]
-    fn set_visited(&self, pos: &Position) {
-        self.visited.borrow_mut()[pos.row][pos.col] = true;
+    fn find_next_position(&self, visited: &Visited) -> Option<Position> {
+        visited.iter().enumerate().find_map(|(row, line)| {
+            line.iter()
+                .position(|&is_visited| !is_visited)
+                .map(|col| Position::new(row, col))
+        })
     }
 
-    fn area_price(&self, pos: &Position) -> usize {
+    fn area_price(&self, pos: &Position, visited: &mut Visited) -> usize {
         let mut area = 0;
         let mut perimeter = 0;
 
@@ -85,7 +72,7 @@ impl Garden {
         while let Some(next) = remaining.pop_front() {
             // Note: We could insert the same position in the 'remaining' queue multiple times.
             // To solve this issue check also here if position was already analyzed;
-            if self.is_visited(&next) {
+            if visited[next.row][next.col] {
                 continue;
             }
 
@@ -93,7 +80,7 @@ impl Garden {
             area += 1;
 
             // Mark current position as already analyzed
-            self.set_visited(&next);
+            visited[next.row][next.col] = true;
 
             // Get all corners around current position
             let neighbors_corners = next.corners(self.rows, self.cols);
@@ -111,19 +98,84 @@ impl Garden {
         area * perimeter
     }
 
-    pub fn fence_price_discount(&self) -> usize {
-        self.reset();
+    /// Positions of all regions, a region is a group of adjacent plots with the same plant
+    pub fn find_regions(&self) -> Vec<Vec<Position>> {
+        let mut visited = self.new_visited();
+        let mut regions = Vec::new();
+
+        while let Some(start) = self.find_next_position(&visited) {
+            let plant = self.grid[start.row][start.col];
+            let mut region = Vec::new();
+
+            visited[start.row][start.col] = true;
+            let mut remaining: VecDeque<_> = vec![start].into();
+
+            while let Some(next) = remaining.pop_front() {
+                for neighbor in next.neighbors(self.rows, self.cols) {
+                    if !visited[neighbor.row][neighbor.col]
+                        && self.grid[neighbor.row][neighbor.col] == plant
+                    {
+                        visited[neighbor.row][neighbor.col] = true;
+                        remaining.push_back(neighbor);
+                    }
+                }
+
+                region.push(next);
+            }
 
+            regions.push(region);
+        }
+
+        regions
+    }
+
+    /// Frame of plants with colored regions, tiles are `(column, row)`
+    pub fn to_frame(&self, title: &str) -> Frame {
+        let mut frame = Frame::new(title, self.rows, self.cols, |x, y| {
+            self.grid[y as usize][x as usize]
+        });
+
+        // Regions get the lowest color which none of their already colored neighbors has
+        let regions = self.find_regions();
+        let mut colors: Vec<Vec<Option<usize>>> = vec![vec![None; self.cols]; self.rows];
+
+        for region in regions.iter() {
+            let used = region
+                .iter()
+                .flat_map(|position| position.neighbors(self.rows, self.cols))
+                .filter_map(|neighbor| colors[neighbor.row][neighbor.col])
+                .collect::<BTreeSet<_>>();
+            let color = (0..)
+                .find(|color| !used.contains(color))
+                .unwrap_or_default();
+
+            for position in region {
+                colors[position.row][position.col] = Some(color);
+            }
+
+            frame.add_layer(
+                Layer::Region(color),
+                region
+                    .iter()
+                    .map(|position| (position.col as isize, position.row as isize)),
+            );
+        }
+
+        frame
+    }
+
+    pub fn fence_price_discount(&self) -> usize {
+        let mut visited = self.new_visited();
         let mut price = 0;
 
-        while let Some(next) = self.find_next_position() {
-            price += self.area_price_discount(&next);
+        while let Some(next) = self.find_next_position(&visited) {
+            price += self.area_price_discount(&next, &mut visited);
         }
 
         price
     }
 
-    fn area_price_discount(&self, pos: &Position) -> usize {
+    fn area_price_discount(&self, pos: &Position, visited: &mut Visited) -> usize {
         let mut area = 0;
         // Note: Number of corner == number of sides, so we can compute number of corners instead
         let mut corners = 0;
@@ -134,7 +186,7 @@ impl Garden {
         while let Some(next) = remaining.pop_front() {
             // Note: We could insert the same position in the 'remaining' queue multiple times.
             // To solve this issue check also here if position was already analyzed;
-            if self.is_visited(&next) {
+            if visited[next.row][next.col] {
                 continue;
             }
 
@@ -142,7 +194,7 @@ impl Garden {
             area += 1;
 
             // Mark current position as already analyzed
-            self.set_visited(&next);
+            visited[next.row][next.col] = true;
 
             // Get all corners around current position
             let neighbors_corners = next.corners(self.rows, self.cols);
@@ -250,6 +302,8 @@ impl Garden {
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
 
     fn create_garden_simple() -> Garden {
@@ -259,6 +313,7 @@ mod tests {
             vec!['B', 'B', 'C', 'C'],
             vec!['E', 'E', 'E', 'C'],
         ])
+        .expect("Failed to create garden")
     }
 
     fn create_garden_medium() -> Garden {
@@ -269,6 +324,7 @@ mod tests {
             vec!['O', 'X', 'O', 'X', 'O'],
             vec!['O', 'O', 'O', 'O', 'O'],
         ])
+        .expect("Failed to create garden")
     }
 
     fn create_garden_complex() -> Garden {
@@ -284,6 +340,7 @@ mod tests {
             vec!['M', 'I', 'I', 'I', 'S', 'I', 'J', 'E', 'E', 'E'],
             vec!['M', 'M', 'M', 'I', 'S', 'S', 'J', 'E', 'E', 'E'],
         ])
+        .expect("Failed to create garden")
     }
 
     fn create_garden_e_shaped() -> Garden {
@@ -294,6 +351,7 @@ mod tests {
             vec!['E', 'X', 'X', 'X', 'X'],
             vec!['E', 'E', 'E', 'E', 'E'],
         ])
+        .expect("Failed to create garden")
     }
 
     fn create_garden_abab() -> Garden {
@@ -305,6 +363,36 @@ mod tests {
             vec!['A', 'B', 'B', 'A', 'A', 'A'],
             vec!['A', 'A', 'A', 'A', 'A', 'A'],
         ])
+        .expect("Failed to create garden")
+    }
+
+    #[test]
+    fn test_find_regions() {
+        assert_eq!(create_garden_simple().find_regions().len(), 5);
+        assert_eq!(create_garden_medium().find_regions().len(), 5);
+        assert_eq!(create_garden_complex().find_regions().len(), 11);
+    }
+
+    #[test]
+    fn test_to_frame() {
+        let frame = create_garden_simple().to_frame("test");
+
+        assert_eq!(frame.get_tile(2, 1), Some('C'));
+        assert_eq!(frame.get_layer(0, 0), frame.get_layer(3, 0));
+        assert_ne!(frame.get_layer(0, 0), frame.get_layer(0, 1));
+        assert_ne!(frame.get_layer(2, 1), frame.get_layer(3, 1));
+    }
+
+    #[test]
+    fn test_new_invalid_content() {
+        assert!(matches!(
+            Garden::new(vec![]),
+            Err(PuzzleError::InvalidContentError(_))
+        ));
+        assert!(matches!(
+            Garden::new(vec![vec!['A', 'A'], vec!['B']]),
+            Err(PuzzleError::InvalidContentError(_))
+        ));
     }
 
     #[test]
@@ -337,7 +425,7 @@ mod tests {
             let garden = create_garden_simple();
 
             assert_eq!(
-                garden.area_price(&pos),
+                garden.area_price(&pos, &mut garden.new_visited()),
                 price,
                 "Invalid price for position '{:?}'",
                 pos
@@ -393,7 +481,7 @@ mod tests {
             let garden = create_garden_simple();
 
             assert_eq!(
-                garden.area_price_discount(&pos),
+                garden.area_price_discount(&pos, &mut garden.new_visited()),
                 price,
                 "Invalid price for position '{:?}'",
                 pos
@@ -401,6 +489,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fence_price_repeated() {
+        let garden = create_garden_complex();
+        assert_eq!(garden.fence_price(), 1930);
+        assert_eq!(garden.fence_price_discount(), 1206);
+        assert_eq!(garden.fence_price(), 1930);
+    }
+
     #[test]
     fn test_fence_price_discount_simple() {
         let garden = create_garden_simple();
@@ -424,4 +520,105 @@ mod tests {
         let garden = create_garden_abab();
         assert_eq!(garden.fence_price_discount(), 368);
     }
+
+    // Reference prices counting fence segments directly, returns (price, discounted price)
+    fn reference_prices(grid: &[Vec<char>]) -> (usize, usize) {
+        let rows = grid.len() as isize;
+        let cols = grid[0].len() as isize;
+
+        let plant = |row: isize, col: isize| {
+            let inside = (0..rows).contains(&row) && (0..cols).contains(&col);
+            inside.then(|| grid[row as usize][col as usize])
+        };
+
+        // Label regions with a flood fill
+        let mut regions = vec![vec![usize::MAX; cols as usize]; rows as usize];
+        let mut count = 0;
+
+        for row in 0..rows {
+            for col in 0..cols {
+                if regions[row as usize][col as usize] != usize::MAX {
+                    continue;
+                }
+
+                let mut stack = vec![(row, col)];
+                while let Some((r, c)) = stack.pop() {
+                    if regions[r as usize][c as usize] != usize::MAX {
+                        continue;
+                    }
+                    regions[r as usize][c as usize] = count;
+
+                    for (dr, dc) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                        if plant(r + dr, c + dc) == plant(row, col) {
+                            stack.push((r + dr, c + dc));
+                        }
+                    }
+                }
+
+                count += 1;
+            }
+        }
+
+        let mut area = vec![0; count];
+        let mut perimeter = vec![0; count];
+        let mut sides = vec![0; count];
+
+        // Fence direction together with the neighbor along the same side
+        let directions = [
+            ((-1, 0), (0, -1)),
+            ((1, 0), (0, -1)),
+            ((0, -1), (-1, 0)),
+            ((0, 1), (-1, 0)),
+        ];
+
+        for row in 0..rows {
+            for col in 0..cols {
+                let region = regions[row as usize][col as usize];
+                let has_fence = |r: isize, c: isize, (dr, dc): (isize, isize)| {
+                    plant(r, c) == plant(row, col) && plant(r + dr, c + dc) != plant(row, col)
+                };
+
+                area[region] += 1;
+
+                for (fence, (ar, ac)) in directions {
+                    if has_fence(row, col, fence) {
+                        perimeter[region] += 1;
+
+                        // Side starts here if the previous plot on the same side has no fence
+                        if !has_fence(row + ar, col + ac, fence) {
+                            sides[region] += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        (0..count).fold((0, 0), |(price, discount), region| {
+            (
+                price + area[region] * perimeter[region],
+                discount + area[region] * sides[region],
+            )
+        })
+    }
+
+    fn small_grid() -> impl Strategy<Value = Vec<Vec<char>>> {
+        (1..=6_usize, 1..=6_usize).prop_flat_map(|(rows, cols)| {
+            prop::collection::vec(
+                prop::collection::vec(prop::sample::select(vec!['A', 'B', 'C']), cols),
+                rows,
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_fence_prices_match_reference(grid in small_grid()) {
+            let garden = Garden::new(grid.clone()).unwrap();
+            let (price, discount) = reference_prices(&grid);
+
+            prop_assert_eq!(garden.fence_price(), price);
+            prop_assert_eq!(garden.fence_price_discount(), discount);
+            prop_assert!(discount <= price);
+        }
+    }
 }
@@ -1,19 +1,23 @@
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::{grid_lines, parse_error::ParseError};
 
 use super::garden::Garden;
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<Garden, PuzzleError> {
-        let grid: Result<Vec<Vec<char>>, PuzzleError> =
+    pub fn parse_lines(lines: &[&str]) -> Result<Garden, ParseError> {
+        // Plots are marked by uppercase letters
+        grid_lines::check_rectangular(lines)
+            .and_then(|_| grid_lines::check_characters(lines, |c| c.is_ascii_uppercase()))?;
+
+        let grid: Result<Vec<Vec<char>>, ParseError> =
             lines.iter().map(|&line| Self::parse_line(line)).collect();
 
-        Ok(Garden::new(grid?))
+        Garden::new(grid?).map_err(|err| ParseError::new(err.to_string()))
     }
 
     #[inline]
-    fn parse_line(line: &str) -> Result<Vec<char>, PuzzleError> {
+    fn parse_line(line: &str) -> Result<Vec<char>, ParseError> {
         Ok(line.chars().collect())
     }
 }
@@ -0,0 +1,9 @@
+pub mod puzzle;
+
+pub use advent_of_code::puzzles::puzzle_solver::PuzzleSolver;
+pub use puzzle::{
+    button::Button, claw_machine::ClawMachine, claw_machine_state::ClawMachineState, game::Game,
+    parser::Parser, prize::Prize, solver::Solver,
+};
+
+aoc_common::export_day!(13, Solver, configurable, parsable);
@@ -1,10 +1,6 @@
 use std::collections::{HashSet, VecDeque};
 
-use super::{button::Button, claw_machine_state::ClawMachineState, prize::Prize};
-
-pub const BUTTON_A_TOKENS: usize = 3;
-pub const BUTTON_B_TOKENS: usize = 1;
-pub const MAX_ATTEMPTS: usize = 100;
+use super::{button::Button, claw_machine_state::ClawMachineState, config::Config, prize::Prize};
 
 #[derive(Debug, Clone)]
 pub struct ClawMachine {
@@ -23,7 +19,7 @@ impl ClawMachine {
         self.prize.y += value;
     }
 
-    pub fn find_fewest_tokens(&self) -> Option<usize> {
+    pub fn find_fewest_tokens(&self, config: &Config) -> Option<usize> {
         let mut fewest_tokens = usize::MAX;
 
         let mut remaining: VecDeque<_> = vec![ClawMachineState::default()].into_iter().collect();
@@ -39,18 +35,18 @@ impl ClawMachine {
             visited.insert((current_state.a_count, current_state.b_count));
 
             // Check for too many attempts
-            if current_state.too_many_attempts() {
+            if current_state.too_many_attempts(config) {
                 continue;
             }
 
             // If we already have more token than minimum we can stop
-            if current_state.calc_tokens() >= fewest_tokens {
+            if current_state.calc_tokens(config) >= fewest_tokens {
                 continue;
             }
 
             // If we have a solution update fewest tokens of applicable
             if current_state.is_solution(&self.a, &self.b, &self.prize) {
-                let tokens = current_state.calc_tokens();
+                let tokens = current_state.calc_tokens(config);
                 if tokens < fewest_tokens {
                     fewest_tokens = tokens;
                 }
@@ -71,11 +67,21 @@ impl ClawMachine {
         }
     }
 
-    pub fn calculate_fewest_tokens(&self) -> Option<usize> {
+    pub fn calculate_fewest_tokens(&self, config: &Config) -> Option<usize> {
+        self.calculate_fewest_presses(config)
+            .map(|state| state.calc_tokens(config))
+    }
+
+    pub fn calculate_fewest_presses(&self, config: &Config) -> Option<ClawMachineState> {
         // Little bit of math
         // Note: We have to use isize as equations could ne negative
         let divider = self.a.x as isize * self.b.y as isize - self.a.y as isize * self.b.x as isize;
 
+        // Both buttons move the claw along the same line, there is no single solution
+        if divider == 0 {
+            return self.calculate_fewest_presses_collinear(config);
+        }
+
         let a_count = (self.b.y as isize * self.prize.x as isize
             - self.b.x as isize * self.prize.y as isize)
             / divider;
@@ -85,26 +91,110 @@ impl ClawMachine {
             / divider;
 
         // Because we are using isize results could be truncated. Therefore we need to check
-        // equations if found a_count and b_count are really correct.
-        if a_count > 0
-            && b_count > 0
+        // equations if found a_count and b_count are really correct. Button may be pressed
+        // zero times.
+        if a_count >= 0
+            && b_count >= 0
             && a_count as usize * self.a.x + b_count as usize * self.b.x == self.prize.x
             && a_count as usize * self.a.y + b_count as usize * self.b.y == self.prize.y
         {
-            let state = ClawMachineState {
+            Some(ClawMachineState {
                 a_count: a_count as usize,
                 b_count: b_count as usize,
-            };
-
-            Some(state.calc_tokens())
+            })
         } else {
             None
         }
     }
+
+    fn calculate_fewest_presses_collinear(&self, config: &Config) -> Option<ClawMachineState> {
+        // Direction of the line both buttons are moving along
+        let direction = match (self.a.x, self.a.y) {
+            (0, 0) => (self.b.x, self.b.y),
+            _ => (self.a.x, self.a.y),
+        };
+
+        // Neither button moves the claw
+        if direction == (0, 0) {
+            return match (self.prize.x, self.prize.y) {
+                (0, 0) => Some(ClawMachineState::default()),
+                _ => None,
+            };
+        }
+
+        // Prize has to be on the same line
+        if direction.0 * self.prize.y != direction.1 * self.prize.x {
+            return None;
+        }
+
+        // All points are on one line, so it is enough to solve a single axis where the line moves
+        let (a, b, prize) = match direction.0 {
+            0 => (self.a.y, self.b.y, self.prize.y),
+            _ => (self.a.x, self.b.x, self.prize.x),
+        };
+
+        Self::fewest_presses_on_line(a as i128, b as i128, prize as i128, config)
+    }
+
+    // Find a_count, b_count >= 0 with a_count * a + b_count * b == prize and the fewest tokens
+    fn fewest_presses_on_line(
+        a: i128,
+        b: i128,
+        prize: i128,
+        config: &Config,
+    ) -> Option<ClawMachineState> {
+        let state = |a_count: i128, b_count: i128| ClawMachineState {
+            a_count: a_count as usize,
+            b_count: b_count as usize,
+        };
+
+        match (a, b) {
+            (0, 0) => return (prize == 0).then(ClawMachineState::default),
+            (0, _) => return (prize % b == 0).then(|| state(0, prize / b)),
+            (_, 0) => return (prize % a == 0).then(|| state(prize / a, 0)),
+            _ => {}
+        }
+
+        let (gcd, x, y) = Self::extended_gcd(a, b);
+        if prize % gcd != 0 {
+            return None;
+        }
+
+        // All solutions are (a0 + k * step_a, b0 - k * step_b) for an integer k
+        let (a0, b0) = (x * (prize / gcd), y * (prize / gcd));
+        let (step_a, step_b) = (b / gcd, a / gcd);
+
+        // Keep both counts non-negative
+        let k_min = -a0.div_euclid(step_a);
+        let k_max = b0.div_euclid(step_b);
+        if k_min > k_max {
+            return None;
+        }
+
+        // Tokens change linearly with k, so the fewest tokens are at one end of the range
+        let slope =
+            config.button_a_tokens as i128 * step_a - config.button_b_tokens as i128 * step_b;
+        let k = if slope > 0 { k_min } else { k_max };
+
+        Some(state(a0 + k * step_a, b0 - k * step_b))
+    }
+
+    // Returns (gcd, x, y) so that a * x + b * y == gcd
+    fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+        match b {
+            0 => (a, 1, 0),
+            _ => {
+                let (gcd, x, y) = Self::extended_gcd(b, a % b);
+                (gcd, y, x - (a / b) * y)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
 
     fn create_input() -> Vec<(ClawMachine, Option<usize>)> {
@@ -198,7 +288,7 @@ mod tests {
         let input = create_input();
 
         for (machine, expected_tokens) in input {
-            let tokens = machine.find_fewest_tokens();
+            let tokens = machine.find_fewest_tokens(&Config::default());
             assert_eq!(tokens, expected_tokens, "machine: {:?}", machine);
         }
     }
@@ -208,8 +298,121 @@ mod tests {
         let input = create_input_appended();
 
         for (machine, expected_tokens) in input {
-            let tokens = machine.calculate_fewest_tokens();
+            let tokens = machine.calculate_fewest_tokens(&Config::default());
             assert_eq!(tokens, expected_tokens, "machine: {:?}", machine);
         }
     }
+
+    #[test]
+    fn test_calculate_fewest_tokens_zero_presses() {
+        let machine = ClawMachine::new(
+            Button { x: 94, y: 34 },
+            Button { x: 22, y: 67 },
+            Prize { x: 220, y: 670 },
+        );
+
+        assert_eq!(
+            machine.calculate_fewest_tokens(&Config::default()),
+            Some(10)
+        );
+        assert_eq!(machine.find_fewest_tokens(&Config::default()), Some(10));
+    }
+
+    #[test]
+    fn test_calculate_fewest_tokens_collinear() {
+        let input = [
+            // B is cheaper per distance, press it as much as possible
+            ((2, 2), (1, 1), (200, 200), Some(200)),
+            // A is cheaper per distance, B is needed for the remainder
+            ((12, 8), (3, 2), (30, 20), Some(8)),
+            // Prize is not on the line
+            ((2, 2), (1, 1), (200, 201), None),
+            // Prize is on the line but cannot be reached
+            ((4, 2), (6, 3), (2, 1), None),
+            ((4, 2), (6, 3), (14, 7), Some(7)),
+            // Buttons which do not move the claw
+            ((0, 0), (3, 5), (6, 10), Some(2)),
+            ((0, 0), (0, 0), (0, 0), Some(0)),
+            ((0, 0), (0, 0), (1, 0), None),
+        ];
+
+        for ((ax, ay), (bx, by), (px, py), expected) in input {
+            let machine = ClawMachine::new(
+                Button { x: ax, y: ay },
+                Button { x: bx, y: by },
+                Prize { x: px, y: py },
+            );
+
+            assert_eq!(
+                machine.calculate_fewest_tokens(&Config::default()),
+                expected,
+                "machine: {:?}",
+                machine
+            );
+        }
+    }
+
+    fn button() -> impl Strategy<Value = Button> {
+        (0..=30_usize, 0..=30_usize).prop_map(|(x, y)| Button { x, y })
+    }
+
+    // Machines with independent buttons, buttons along the same line and prizes which are
+    // either reachable within the maximum of presses or arbitrary
+    fn claw_machine() -> impl Strategy<Value = ClawMachine> {
+        let buttons = prop_oneof![
+            (button(), button()),
+            (button(), 1..=4_usize, 1..=4_usize).prop_map(|(a, multiply, divide)| {
+                let b = Button {
+                    x: a.x * multiply / divide,
+                    y: a.y * multiply / divide,
+                };
+                (a, b)
+            }),
+        ];
+
+        let max_presses = Config::default().max_presses;
+
+        (buttons, 0..=max_presses, 0..=max_presses, any::<bool>()).prop_flat_map(
+            |((a, b), a_count, b_count, reachable)| {
+                let prize = match reachable {
+                    true => Just(Prize {
+                        x: a_count * a.x + b_count * b.x,
+                        y: a_count * a.y + b_count * b.y,
+                    })
+                    .boxed(),
+                    false => (0..=1000_usize, 0..=1000_usize)
+                        .prop_map(|(x, y)| Prize { x, y })
+                        .boxed(),
+                };
+
+                prize.prop_map(move |prize| ClawMachine::new(a.clone(), b.clone(), prize))
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn prop_calculate_matches_find(machine in claw_machine()) {
+            let config = Config::default();
+            let found = machine.find_fewest_tokens(&config);
+            let calculated = machine.calculate_fewest_presses(&config);
+
+            match &calculated {
+                Some(state) => {
+                    // Calculated presses are a real solution and they are not worse than
+                    // the search limited by the maximum of presses
+                    prop_assert!(state.is_solution(&machine.a, &machine.b, &machine.prize));
+
+                    if let Some(found) = found {
+                        prop_assert!(state.calc_tokens(&config) <= found);
+                    }
+
+                    if !state.too_many_attempts(&config) {
+                        prop_assert_eq!(found, Some(state.calc_tokens(&config)));
+                    }
+                }
+                None => prop_assert_eq!(found, None),
+            }
+        }
+    }
 }
@@ -1,22 +1,18 @@
-use super::{
-    button::Button,
-    claw_machine::{BUTTON_A_TOKENS, BUTTON_B_TOKENS, MAX_ATTEMPTS},
-    prize::Prize,
-};
+use super::{button::Button, config::Config, prize::Prize};
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct ClawMachineState {
     pub a_count: usize,
     pub b_count: usize,
 }
 
 impl ClawMachineState {
-    pub fn calc_tokens(&self) -> usize {
-        self.a_count * BUTTON_A_TOKENS + self.b_count * BUTTON_B_TOKENS
+    pub fn calc_tokens(&self, config: &Config) -> usize {
+        self.a_count * config.button_a_tokens + self.b_count * config.button_b_tokens
     }
 
-    pub fn too_many_attempts(&self) -> bool {
-        self.a_count > MAX_ATTEMPTS || self.b_count > MAX_ATTEMPTS
+    pub fn too_many_attempts(&self, config: &Config) -> bool {
+        self.a_count > config.max_presses || self.b_count > config.max_presses
     }
 
     pub fn is_solution(&self, a: &Button, b: &Button, prize: &Prize) -> bool {
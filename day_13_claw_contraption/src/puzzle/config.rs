@@ -0,0 +1,26 @@
+use serde::Deserialize;
+
+/// Puzzle constants, defaults match the puzzle description
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Tokens spent for a single press of the button A
+    pub button_a_tokens: usize,
+    /// Tokens spent for a single press of the button B
+    pub button_b_tokens: usize,
+    /// Maximum presses of every button in part 1
+    pub max_presses: usize,
+    /// Value added to both prize coordinates in part 2
+    pub prize_offset: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            button_a_tokens: 3,
+            button_b_tokens: 1,
+            max_presses: 100,
+            prize_offset: 10_000_000_000_000,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::claw_machine::ClawMachine;
+use super::{claw_machine::ClawMachine, config::Config};
 use rayon::prelude::*;
 
 #[derive(Default)]
@@ -15,13 +15,13 @@ impl Game {
         self.machines.push(machine);
     }
 
-    pub fn count_fewest_tokens_to_win_all_prizes(&self) -> Option<usize> {
+    pub fn count_fewest_tokens_to_win_all_prizes(&self, config: &Config) -> Option<usize> {
         // Find out fewest tokens for every machine. If machine does not have a solution
         // None is returned.
         let tokens = self
             .machines
             .par_iter()
-            .filter_map(|machine| machine.find_fewest_tokens())
+            .filter_map(|machine| machine.find_fewest_tokens(config))
             .collect::<Vec<_>>();
 
         // There may be no solution -> in this case return None
@@ -31,13 +31,13 @@ impl Game {
         }
     }
 
-    pub fn count_fewest_tokens_to_win_all_prizes_fast(&self) -> Option<usize> {
+    pub fn count_fewest_tokens_to_win_all_prizes_fast(&self, config: &Config) -> Option<usize> {
         // Find out fewest tokens for every machine. If machine does not have a solution
         // None is returned.
         let tokens = self
             .machines
             .par_iter()
-            .filter_map(|machine| machine.calculate_fewest_tokens())
+            .filter_map(|machine| machine.calculate_fewest_tokens(config))
             .collect::<Vec<_>>();
 
         // There may be no solution -> in this case return None
@@ -47,9 +47,7 @@ impl Game {
         }
     }
 
-    pub fn calculate_fewest_tokens_to_win_all_prizes(&self) -> Option<usize> {
-        const APPEND_VALUE: usize = 10_000_000_000_000;
-
+    pub fn calculate_fewest_tokens_to_win_all_prizes(&self, config: &Config) -> Option<usize> {
         // Find out fewest tokens for every machine. If machine does not have a solution
         // None is returned.
         let tokens = self
@@ -60,9 +58,9 @@ impl Game {
                 // unit tests as all parts are run in parallel. Instead we will clone() machine
                 // and update its content.
                 let mut updated_machine = machine.clone();
-                updated_machine.append_prizes(APPEND_VALUE);
+                updated_machine.append_prizes(config.prize_offset);
 
-                updated_machine.calculate_fewest_tokens()
+                updated_machine.calculate_fewest_tokens(config)
             })
             .collect::<Vec<_>>();
 
@@ -110,14 +108,17 @@ mod tests {
     #[test]
     fn test_count_fewest_tokens_to_win_all_prizes() {
         let game = create_game();
-        assert_eq!(game.count_fewest_tokens_to_win_all_prizes(), Some(480));
+        assert_eq!(
+            game.count_fewest_tokens_to_win_all_prizes(&Config::default()),
+            Some(480)
+        );
     }
 
     #[test]
     fn test_calculate_fewest_tokens_to_win_all_prizes() {
         let game = create_game();
         assert_eq!(
-            game.calculate_fewest_tokens_to_win_all_prizes(),
+            game.calculate_fewest_tokens_to_win_all_prizes(&Config::default()),
             Some(875318608908)
         );
     }
@@ -1,6 +1,7 @@
 pub mod button;
 pub mod claw_machine;
 pub mod claw_machine_state;
+pub mod config;
 pub mod game;
 pub mod parser;
 pub mod prize;
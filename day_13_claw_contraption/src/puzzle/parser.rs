@@ -1,102 +1,90 @@
 use std::sync::LazyLock;
 
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
-use regex::Regex;
+use aoc_common::parse_error::ParseError;
+use regex::{Captures, Regex};
 
 use super::{button::Button, claw_machine::ClawMachine, game::Game, prize::Prize};
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<Game, PuzzleError> {
+    pub fn parse_lines(lines: &[&str]) -> Result<Game, ParseError> {
+        let mut machines = Vec::new();
+        let mut first_index = 0;
+
         // Create chunks of data per claw machine separated by an empty line
-        let chunks = lines.split(|line| line.is_empty()).collect::<Vec<_>>();
+        for chunk in lines.split(|line| line.is_empty()) {
+            let machine = Self::parse_claw_machine(chunk, first_index)?;
+            machines.push(machine);
 
-        let machines: Result<Vec<ClawMachine>, PuzzleError> = chunks
-            .iter()
-            .map(|&chunk| Self::parse_claw_machine(chunk))
-            .collect();
+            // Skip also the empty line
+            first_index += chunk.len() + 1;
+        }
 
-        Ok(Game::new(machines?))
+        Ok(Game::new(machines))
     }
 
-    fn parse_claw_machine(lines: &[&str]) -> Result<ClawMachine, PuzzleError> {
+    fn parse_claw_machine(lines: &[&str], first_index: usize) -> Result<ClawMachine, ParseError> {
         // Last record may be without
         if lines.len() != 3 {
-            return Err(PuzzleError::InvalidContentError(format!(
+            return Err(ParseError::new(format!(
                 "Claw machine should contain exactly 3 lines, but {} found",
                 lines.len()
-            )));
+            ))
+            .with_line(first_index));
         }
 
         // Example:
         // Button A: X+77, Y+52
         // Button B: X+14, Y+32
         // Prize: X=5233, Y=14652
-        let button_a = Self::parse_button(lines[0])?;
-        let button_b = Self::parse_button(lines[1])?;
-        let prize = Self::parse_prize(lines[2])?;
+        let button_a = Self::parse_button(lines[0]).map_err(|err| err.with_line(first_index))?;
+        let button_b =
+            Self::parse_button(lines[1]).map_err(|err| err.with_line(first_index + 1))?;
+        let prize = Self::parse_prize(lines[2]).map_err(|err| err.with_line(first_index + 2))?;
 
         Ok(ClawMachine::new(button_a, button_b, prize))
     }
 
-    fn parse_button(line: &str) -> Result<Button, PuzzleError> {
+    fn parse_button(line: &str) -> Result<Button, ParseError> {
         static RE: LazyLock<Regex> = LazyLock::new(|| {
             Regex::new(r#"^Button [A,B]: X\+(\d+), Y\+(\d+)"#)
                 .expect("Failed to create 'Button' regex")
         });
 
         if let Some(captures) = RE.captures(line) {
-            let x = captures[1].parse::<usize>().map_err(|err| {
-                PuzzleError::InvalidContentError(format!(
-                    "Failed to convert '{}' to usize with and error '{}'",
-                    &captures[1], err
-                ))
-            })?;
-
-            let y = captures[2].parse::<usize>().map_err(|err| {
-                PuzzleError::InvalidContentError(format!(
-                    "Failed to convert '{}' to usize with and error '{}'",
-                    &captures[2], err
-                ))
-            })?;
+            let x = Self::parse_number(line, &captures, 1)?;
+            let y = Self::parse_number(line, &captures, 2)?;
 
             return Ok(Button { x, y });
         }
 
-        Err(PuzzleError::GenericError(format!(
-            "Failed to parse button, line: {}",
-            line
-        )))
+        Err(ParseError::new("Failed to parse button").with_whole_line(line))
     }
 
-    fn parse_prize(line: &str) -> Result<Prize, PuzzleError> {
+    fn parse_prize(line: &str) -> Result<Prize, ParseError> {
         static RE: LazyLock<Regex> = LazyLock::new(|| {
             Regex::new(r#"^Prize: X=(\d+), Y=(\d+)"#).expect("Failed to create 'Prize' regex")
         });
 
         if let Some(captures) = RE.captures(line) {
-            let x = captures[1].parse::<usize>().map_err(|err| {
-                PuzzleError::InvalidContentError(format!(
-                    "Failed to convert '{}' to usize with an error '{}'",
-                    &captures[1], err
-                ))
-            })?;
-
-            let y = captures[2].parse::<usize>().map_err(|err| {
-                PuzzleError::InvalidContentError(format!(
-                    "Failed to convert '{}' to usize with an error '{}'",
-                    &captures[2], err
-                ))
-            })?;
+            let x = Self::parse_number(line, &captures, 1)?;
+            let y = Self::parse_number(line, &captures, 2)?;
 
             return Ok(Prize { x, y });
         }
 
-        Err(PuzzleError::GenericError(format!(
-            "Failed to parse prize, line: {}",
-            line
-        )))
+        Err(ParseError::new("Failed to parse prize").with_whole_line(line))
+    }
+
+    fn parse_number(line: &str, captures: &Captures, group: usize) -> Result<usize, ParseError> {
+        captures[group].parse::<usize>().map_err(|err| {
+            ParseError::new(format!(
+                "Failed to convert '{}' to usize with an error '{}'",
+                &captures[group], err
+            ))
+            .with_span(line, captures.get(group).map_or(0..0, |m| m.range()))
+        })
     }
 }
 
@@ -127,4 +115,23 @@ mod tests {
         assert!(result.is_ok(), "result: {:?}", result);
         assert_eq!(result.unwrap(), Prize { x: 5233, y: 14652 });
     }
+
+    #[test]
+    fn test_parse_lines_error_location() {
+        let result = Parser::parse_lines(&[
+            "Button A: X+94, Y+34",
+            "Button B: X+22, Y+67",
+            "Prize: X=8400, Y=5400",
+            "",
+            "Button A: X+26, Y+66",
+            "Button B: X+67 Y+21",
+            "Prize: X=12748, Y=12176",
+        ]);
+
+        assert!(result.is_err());
+        assert!(
+            result.err().unwrap().to_string().contains("line 6"),
+            "Missing location"
+        );
+    }
 }
@@ -1,20 +1,37 @@
-use std::cell::RefCell;
-
 use advent_of_code::puzzles::{
     puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
     puzzle_solver::PuzzleSolver,
 };
+use aoc_common::{configurable::Configurable, parsable::Parsable, parse_error::ParseError};
 
-use super::{game::Game, parser::Parser};
+use super::{config::Config, game::Game, parser::Parser};
 
 pub struct Solver {
-    game: RefCell<Game>,
+    config: Config,
+    game: Game,
+}
+
+impl Configurable for Solver {
+    type Config = Config;
+
+    fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+}
+
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        self.game = Parser::parse_lines(lines)?;
+        Ok(())
+    }
 }
 
 impl PuzzleSolver for Solver {
     fn new() -> Self {
         Self {
-            game: RefCell::new(Game::default()),
+            config: Config::default(),
+            game: Game::default(),
         }
     }
 
@@ -23,16 +40,16 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        *self.game.borrow_mut() = Parser::parse_lines(lines)?;
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
         let fewest_count = self
             .game
-            .borrow()
-            .count_fewest_tokens_to_win_all_prizes()
-            .expect("No solution found");
+            .count_fewest_tokens_to_win_all_prizes(&self.config)
+            // No prize can be won, so no tokens are spent
+            .unwrap_or_default();
 
         Ok(fewest_count.to_string())
     }
@@ -41,9 +58,8 @@ impl PuzzleSolver for Solver {
         // Find count - use calculation method (fastest)
         let fewest_count = self
             .game
-            .borrow()
-            .calculate_fewest_tokens_to_win_all_prizes()
-            .expect("No solution found");
+            .calculate_fewest_tokens_to_win_all_prizes(&self.config)
+            .unwrap_or_default();
 
         Ok(fewest_count.to_string())
     }
@@ -55,6 +71,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -77,4 +94,9 @@ mod tests {
     fn test_part_2() {
         get_tester().test_part_2();
     }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
 }
@@ -0,0 +1,9 @@
+pub mod puzzle;
+
+pub use advent_of_code::puzzles::puzzle_solver::PuzzleSolver;
+pub use puzzle::{
+    grid::Grid, parser::Parser, position::Position, quadrant::Quadrant, robot::Robot,
+    solver::Solver, velocity::Velocity,
+};
+
+aoc_common::export_day!(14, Solver, configurable, parsable, renderable);
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+/// Puzzle constants, defaults match the real input
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Width of the space, has to be odd
+    pub width: usize,
+    /// Height of the space, has to be odd
+    pub height: usize,
+    /// Seconds robots move before the safety factor is calculated in part 1
+    pub seconds: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            width: 101,
+            height: 103,
+            seconds: 100,
+        }
+    }
+}
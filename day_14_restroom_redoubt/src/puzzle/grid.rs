@@ -1,12 +1,6 @@
-use std::{
-    cell::RefCell,
-    collections::HashMap,
-    fs::{self, File},
-    io::Write,
-    path::Path,
-};
+use std::collections::HashMap;
 
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::render::{frame::Frame, layer::Layer};
 
 use super::{position::Position, quadrant::Quadrant, robot::Robot};
 
@@ -14,7 +8,7 @@ use super::{position::Position, quadrant::Quadrant, robot::Robot};
 pub struct Grid {
     rows: usize,
     cols: usize,
-    robots: RefCell<Vec<Robot>>,
+    robots: Vec<Robot>,
 }
 
 impl Grid {
@@ -23,27 +17,29 @@ impl Grid {
         assert!(rows % 2 == 1, "Number of rows has to be odd [{}]", rows);
         assert!(cols % 2 == 1, "Number of columns has to be odd [{}]", cols);
 
-        Self {
-            rows,
-            cols,
-            robots: RefCell::new(robots),
-        }
+        Self { rows, cols, robots }
     }
 
     pub fn safety_factor(&self, steps: usize) -> usize {
-        // Move all robots
-        self.move_robots(steps);
+        // Calculate safety factor, original positions stay untouched
+        self.moved(steps).calculate_safety_factor()
+    }
+
+    /// Copy of the grid with all robots moved 'steps' times
+    pub fn moved(&self, steps: usize) -> Grid {
+        let mut grid = self.clone();
+        grid.move_robots(steps);
 
-        // Calculate safety factor
-        self.calculate_safety_factor()
+        grid
     }
 
-    fn move_robots(&self, steps: usize) {
+    fn move_robots(&mut self, steps: usize) {
         // Move all robots 'step' times
+        let (rows, cols) = (self.rows, self.cols);
+
         self.robots
-            .borrow_mut()
             .iter_mut()
-            .for_each(|r| r.move_robot(self.rows, self.cols, steps));
+            .for_each(|r| r.move_robot(rows, cols, steps));
     }
 
     fn calculate_safety_factor(&self) -> usize {
@@ -83,7 +79,6 @@ impl Grid {
     // Note: rows and cols ranges are exclusive, i.e. end is not included
     fn count_robots(&self, quadrant: &Quadrant) -> usize {
         self.robots
-            .borrow()
             .iter()
             .filter(|&r| {
                 let pos = r.get_position();
@@ -92,88 +87,57 @@ impl Grid {
             .count()
     }
 
-    #[allow(dead_code)]
-    pub fn print(&self) {
-        for r in 0..self.rows {
-            for c in 0..self.cols {
-                match self.count(r, c) {
-                    0 => print!("."),
-                    n => print!("{}", n),
-                }
+    /// Frame with robots, tiles are `(column, row)`
+    pub fn to_frame(&self, title: &str) -> Frame {
+        Frame::new(title, self.rows, self.cols, |x, y| {
+            match self.count(y as usize, x as usize) {
+                0 => '.',
+                _ => '#',
             }
-            println!();
+        })
+    }
+
+    /// Frame with robots and quadrants which are counted by the safety factor
+    pub fn to_quadrants_frame(&self, title: &str) -> Frame {
+        let mut frame = self.to_frame(title);
+
+        for (index, quadrant) in self.split_to_quadrants().into_iter().enumerate() {
+            let points = quadrant.x.flat_map(|row| {
+                quadrant
+                    .y
+                    .clone()
+                    .map(move |col| (col as isize, row as isize))
+            });
+
+            frame.add_layer(Layer::Region(index), points);
         }
+
+        frame
     }
 
     fn count(&self, row: usize, col: usize) -> usize {
         self.robots
-            .borrow()
             .iter()
             .filter(|&r| r.get_position() == &Position { x: col, y: row })
             .count()
     }
 
-    pub fn print_to_file(&self, path: &Path) -> Result<(), PuzzleError> {
-        // Create file for writing
-        let mut file = File::create(path).map_err(|err| {
-            PuzzleError::GenericError(format!(
-                "Failed to create a file '{:?}' with and error '{}'",
-                path, err
-            ))
-        })?;
-
-        for r in 0..self.rows {
-            for c in 0..self.cols {
-                let character = match self.count(r, c) {
-                    0 => '.',
-                    _ => '#',
-                };
-
-                write!(file, "{}", character).map_err(|err| {
-                    PuzzleError::GenericError(format!(
-                        "Failed to write to file '{:?}' with an error '{}'",
-                        path, err,
-                    ))
-                })?;
-            }
+    /// Grids which could show a christmas tree together with elapsed seconds
+    pub fn find_christmas_tree_candidates(&self, max: usize) -> Vec<(usize, Grid)> {
+        // Robots are moved on a copy of the grid
+        let mut grid = self.clone();
+        let mut candidates = Vec::new();
 
-            writeln!(file).map_err(|err| {
-                PuzzleError::GenericError(format!(
-                    "Failed to write to file '{:?}' with an error '{}'",
-                    path, err,
-                ))
-            })?;
-        }
-
-        Ok(())
-    }
-
-    pub fn find_possible_christmas_trees(
-        &self,
-        path: &Path,
-        max: usize,
-    ) -> Result<(), PuzzleError> {
-        // Create directory structure if it does not exist yet
-        fs::create_dir_all(path).map_err(|err| {
-            PuzzleError::GenericError(format!(
-                "Failed to create directory structure '{:?}' with an error '{}'",
-                path, err
-            ))
-        })?;
-
-        // Create text files with images
         for seconds in 1..max {
             // Move always only by a single step
-            self.move_robots(1);
+            grid.move_robots(1);
 
-            // Print to a file
-            if self.is_possible_christmas_tree() {
-                let file = path.join(format!("{:04}.txt", seconds));
-                self.print_to_file(&file)?;
+            if grid.is_possible_christmas_tree() {
+                candidates.push((seconds, grid.clone()));
             }
         }
 
-        Ok(())
+        candidates
     }
 
     fn is_possible_christmas_tree(&self) -> bool {
@@ -182,7 +146,7 @@ impl Grid {
 
         let mut counts = HashMap::new();
 
-        for robot in self.robots.borrow().iter() {
+        for robot in self.robots.iter() {
             let position = robot.get_position();
 
             let count = counts.entry(position.x).or_insert(0);
@@ -241,5 +205,19 @@ mod tests {
         let grid = create_grid();
 
         assert_eq!(grid.safety_factor(100), 12);
+        assert_eq!(grid.safety_factor(100), 12);
+        assert_eq!(grid, create_grid());
+    }
+
+    #[test]
+    fn test_to_quadrants_frame() {
+        let frame = create_grid().moved(100).to_quadrants_frame("test");
+
+        assert_eq!((frame.get_rows(), frame.get_cols()), (7, 11));
+        assert_eq!(frame.get_tile(6, 0), Some('#'));
+        assert_eq!(frame.get_tile(0, 0), Some('.'));
+        assert_eq!(frame.get_layer(0, 0), Some(Layer::Region(0)));
+        assert_eq!(frame.get_layer(10, 6), Some(Layer::Region(3)));
+        assert_eq!(frame.get_layer(5, 0), None);
     }
 }
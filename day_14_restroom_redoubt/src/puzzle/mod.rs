@@ -1,3 +1,4 @@
+pub mod config;
 pub mod grid;
 pub mod parser;
 pub mod position;
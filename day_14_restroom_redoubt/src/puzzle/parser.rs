@@ -1,59 +1,34 @@
-use std::sync::LazyLock;
+use std::{fmt::Display, str::FromStr, sync::LazyLock};
 
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
-use regex::Regex;
+use aoc_common::parse_error::ParseError;
+use regex::{Captures, Regex};
 
 use crate::puzzle::{position::Position, velocity::Velocity};
 
-use super::{grid::Grid, robot::Robot};
-
-const WIDTH: usize = 101;
-const HEIGHT: usize = 103;
+use super::robot::Robot;
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<Grid, PuzzleError> {
-        let robots: Result<Vec<Robot>, PuzzleError> =
-            lines.iter().map(|line| Self::parse_line(line)).collect();
-
-        Ok(Grid::new(HEIGHT, WIDTH, robots?))
+    pub fn parse_lines(lines: &[&str]) -> Result<Vec<Robot>, ParseError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| Self::parse_line(line).map_err(|err| err.with_line(index)))
+            .collect()
     }
 
-    fn parse_line(line: &str) -> Result<Robot, PuzzleError> {
+    fn parse_line(line: &str) -> Result<Robot, ParseError> {
         static RE: LazyLock<Regex> = LazyLock::new(|| {
             Regex::new(r#"^p=(\d+),(\d+)\s+v=(-?\d+),(-?\d+)"#)
                 .expect("Failed to create 'Robot' regex")
         });
 
         if let Some(captures) = RE.captures(line) {
-            let pos_x = captures[1].parse::<usize>().map_err(|err| {
-                PuzzleError::InvalidContentError(format!(
-                    "Failed to parse position x '{}' to usize with an error '{}'",
-                    &captures[1], err
-                ))
-            })?;
-
-            let pos_y = captures[2].parse::<usize>().map_err(|err| {
-                PuzzleError::InvalidContentError(format!(
-                    "Failed to parse position y '{}' to usize with an error '{}'",
-                    &captures[2], err
-                ))
-            })?;
-
-            let vel_x = captures[3].parse::<isize>().map_err(|err| {
-                PuzzleError::InvalidContentError(format!(
-                    "Failed to parse velocity x '{}' to isize with an error '{}'",
-                    &captures[3], err
-                ))
-            })?;
-
-            let vel_y = captures[4].parse::<isize>().map_err(|err| {
-                PuzzleError::InvalidContentError(format!(
-                    "Failed to parse velocity y '{}' to isize with an error '{}'",
-                    &captures[4], err
-                ))
-            })?;
+            let pos_x = Self::parse_capture::<usize>(line, &captures, 1, "position x")?;
+            let pos_y = Self::parse_capture::<usize>(line, &captures, 2, "position y")?;
+            let vel_x = Self::parse_capture::<isize>(line, &captures, 3, "velocity x")?;
+            let vel_y = Self::parse_capture::<isize>(line, &captures, 4, "velocity y")?;
 
             return Ok(Robot::new(
                 Position { x: pos_x, y: pos_y },
@@ -61,10 +36,29 @@ impl Parser {
             ));
         }
 
-        Err(PuzzleError::InvalidContentError(format!(
-            "Failed to parse robot from the line '{}'",
-            line
-        )))
+        Err(
+            ParseError::new(format!("Failed to parse robot from the line '{}'", line))
+                .with_whole_line(line),
+        )
+    }
+
+    fn parse_capture<T>(
+        line: &str,
+        captures: &Captures,
+        group: usize,
+        name: &str,
+    ) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        captures[group].parse::<T>().map_err(|err| {
+            ParseError::new(format!(
+                "Failed to parse {} '{}' with an error '{}'",
+                name, &captures[group], err
+            ))
+            .with_span(line, captures.get(group).map_or(0..0, |m| m.range()))
+        })
     }
 }
 
@@ -92,24 +86,30 @@ mod tests {
         assert!(result.is_ok(), "result: {:?}", result);
         assert_eq!(
             result.unwrap(),
-            Grid::new(
-                HEIGHT,
-                WIDTH,
-                vec![
-                    Robot::new(Position { x: 0, y: 4 }, Velocity { x: 3, y: -3 }),
-                    Robot::new(Position { x: 6, y: 3 }, Velocity { x: -1, y: -3 }),
-                    Robot::new(Position { x: 10, y: 3 }, Velocity { x: -1, y: 2 }),
-                    Robot::new(Position { x: 2, y: 0 }, Velocity { x: 2, y: -1 }),
-                    Robot::new(Position { x: 0, y: 0 }, Velocity { x: 1, y: 3 }),
-                    Robot::new(Position { x: 3, y: 0 }, Velocity { x: -2, y: -2 }),
-                    Robot::new(Position { x: 7, y: 6 }, Velocity { x: -1, y: -3 }),
-                    Robot::new(Position { x: 3, y: 0 }, Velocity { x: -1, y: -2 }),
-                    Robot::new(Position { x: 9, y: 3 }, Velocity { x: 2, y: 3 }),
-                    Robot::new(Position { x: 7, y: 3 }, Velocity { x: -1, y: 2 }),
-                    Robot::new(Position { x: 2, y: 4 }, Velocity { x: 2, y: -3 }),
-                    Robot::new(Position { x: 9, y: 5 }, Velocity { x: -3, y: -3 }),
-                ]
-            )
+            vec![
+                Robot::new(Position { x: 0, y: 4 }, Velocity { x: 3, y: -3 }),
+                Robot::new(Position { x: 6, y: 3 }, Velocity { x: -1, y: -3 }),
+                Robot::new(Position { x: 10, y: 3 }, Velocity { x: -1, y: 2 }),
+                Robot::new(Position { x: 2, y: 0 }, Velocity { x: 2, y: -1 }),
+                Robot::new(Position { x: 0, y: 0 }, Velocity { x: 1, y: 3 }),
+                Robot::new(Position { x: 3, y: 0 }, Velocity { x: -2, y: -2 }),
+                Robot::new(Position { x: 7, y: 6 }, Velocity { x: -1, y: -3 }),
+                Robot::new(Position { x: 3, y: 0 }, Velocity { x: -1, y: -2 }),
+                Robot::new(Position { x: 9, y: 3 }, Velocity { x: 2, y: 3 }),
+                Robot::new(Position { x: 7, y: 3 }, Velocity { x: -1, y: 2 }),
+                Robot::new(Position { x: 2, y: 4 }, Velocity { x: 2, y: -3 }),
+                Robot::new(Position { x: 9, y: 5 }, Velocity { x: -3, y: -3 }),
+            ]
         )
     }
+
+    #[test]
+    fn test_parse_line_invalid() {
+        let result = Parser::parse_line("p=0,4 v=3,-3x");
+        assert!(result.is_ok(), "result: {:?}", result);
+
+        let result = Parser::parse_line("p=0,4 v=3,+3");
+        assert!(result.is_err(), "result: {:?}", result);
+        assert_eq!(result.unwrap_err().get_columns(), Some(&(1..13)));
+    }
 }
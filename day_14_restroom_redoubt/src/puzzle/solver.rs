@@ -1,22 +1,90 @@
-use std::{cell::RefCell, path::Path};
-
 use advent_of_code::puzzles::{
     puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
     puzzle_solver::PuzzleSolver,
 };
+use aoc_common::{
+    configurable::Configurable, parsable::Parsable, parse_error::ParseError, part::Part,
+    render::frame::Frame, renderable::Renderable,
+};
 
-use super::{grid::Grid, parser::Parser};
+use super::{config::Config, grid::Grid, parser::Parser, robot::Robot};
 
 pub struct Solver {
-    grid: RefCell<Grid>,
-    original: Grid,
+    config: Config,
+    robots: Vec<Robot>,
+}
+
+impl Solver {
+    fn create_grid(&self) -> Result<Grid, PuzzleError> {
+        let Config { width, height, .. } = self.config;
+
+        // In the puzzle description is expected that number of rows and columns is odd
+        if width % 2 == 0 || height % 2 == 0 {
+            return Err(PuzzleError::GenericError(format!(
+                "Width and height have to be odd but {}x{} found",
+                width, height
+            )));
+        }
+
+        if let Some(position) = self
+            .robots
+            .iter()
+            .map(|robot| robot.get_position())
+            .find(|position| position.x >= width || position.y >= height)
+        {
+            return Err(PuzzleError::InvalidContentError(format!(
+                "Robot at {},{} is outside of the {}x{} space",
+                position.x, position.y, width, height
+            )));
+        }
+
+        Ok(Grid::new(height, width, self.robots.clone()))
+    }
+}
+
+impl Configurable for Solver {
+    type Config = Config;
+
+    fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+}
+
+impl Renderable for Solver {
+    type Error = PuzzleError;
+
+    fn frames(&self, part: Part) -> Result<Vec<Frame>, PuzzleError> {
+        let grid = self.create_grid()?;
+
+        match part {
+            Part::Part1 => {
+                let title = format!("Robots after {} seconds", self.config.seconds);
+                Ok(vec![grid
+                    .moved(self.config.seconds)
+                    .to_quadrants_frame(&title)])
+            }
+            Part::Part2 => Ok(grid
+                .find_christmas_tree_candidates(10000)
+                .into_iter()
+                .map(|(seconds, grid)| grid.to_frame(&format!("Robots after {} seconds", seconds)))
+                .collect()),
+        }
+    }
+}
+
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        self.robots = Parser::parse_lines(lines)?;
+        Ok(())
+    }
 }
 
 impl PuzzleSolver for Solver {
     fn new() -> Self {
         Self {
-            grid: RefCell::new(Grid::default()),
-            original: Grid::default(),
+            config: Config::default(),
+            robots: Vec::new(),
         }
     }
 
@@ -25,28 +93,18 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        self.original = Parser::parse_lines(lines)?;
-        self.grid = RefCell::new(self.original.clone());
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
-        let safety_factor = self.grid.borrow().safety_factor(100);
+        let safety_factor = self.create_grid()?.safety_factor(self.config.seconds);
         Ok(safety_factor.to_string())
     }
 
     fn part_2(&self) -> SolutionResult {
-        // Restore grid because part 1 changed it
-        *self.grid.borrow_mut() = self.original.clone();
-
-        self.grid
-            .borrow()
-            .find_possible_christmas_trees(Path::new("/tmp/aoc-2024/day-14"), 10000)?;
-
-        Ok(
-            "Look for candidates under the directory '/tmp/aoc-2024/day-14' and find a solution"
-                .to_string(),
-        )
+        // Candidates have to be checked visually, see `aoc render 14 --part 2`
+        Ok("Not solved".to_string())
     }
 }
 
@@ -56,6 +114,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -74,9 +133,13 @@ mod tests {
         get_tester().test_part_1();
     }
 
-    // Note: Commented out because solution has to be found manually from generated grids
-    // #[test]
-    // fn test_part_2() {
-    //     get_tester().test_part_2();
-    // }
+    #[test]
+    fn test_part_2() {
+        get_tester().test_part_2();
+    }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
 }
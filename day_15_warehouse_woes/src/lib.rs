@@ -0,0 +1,9 @@
+pub mod puzzle;
+
+pub use advent_of_code::puzzles::puzzle_solver::PuzzleSolver;
+pub use puzzle::{
+    movement::Movement, parser::Parser, solver::Solver, tile::Tile, tile_index::TileIndex,
+    warehouse::Warehouse,
+};
+
+aoc_common::export_day!(15, Solver, parsable, renderable);
@@ -1,70 +1,72 @@
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::{grid_lines, parse_error::ParseError};
 
 use super::{movement::Movement, tile::Tile, warehouse::Warehouse};
 
-enum ParserState {
-    Warehouse,
-    Movements,
-}
-
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<(Warehouse, Vec<Movement>), PuzzleError> {
+    pub fn parse_lines(lines: &[&str]) -> Result<(Warehouse, Vec<Movement>), ParseError> {
         // Note: In input file are two parts separated by an empty line:
         // - Warehouse layout
         //   ...
         // - One empty line
         // - Movements
         //   ...
+        let separator = lines
+            .iter()
+            .position(|line| line.is_empty())
+            .unwrap_or(lines.len());
 
-        let mut state = ParserState::Warehouse;
-        let mut warehouse_lines = Vec::with_capacity(lines.len());
-        let mut movements_lines = Vec::with_capacity(lines.len());
+        let warehouse_lines = &lines[..separator];
+        let movements_lines = lines.get(separator + 1..).unwrap_or_default();
 
-        for &line in lines {
-            match state {
-                ParserState::Warehouse => warehouse_lines.push(line),
-                ParserState::Movements => movements_lines.push(line),
-            }
+        let warehouse = Self::decode_warehouse(warehouse_lines, 0)?;
+        let movements = Self::decode_movements(movements_lines, separator + 1)?;
 
-            // Detect an empty line to change parser state
-            if line.is_empty() {
-                state = ParserState::Movements;
-            }
-        }
+        Ok((warehouse, movements))
+    }
 
-        let warehouse = Self::parse_warehouse(&warehouse_lines)?;
-        let movements = Self::parse_movements(&movements_lines)?;
+    pub fn parse_warehouse(lines: &[&str]) -> Result<Warehouse, ParseError> {
+        Self::decode_warehouse(lines, 0)
+    }
 
-        Ok((warehouse, movements))
+    pub fn parse_movements(lines: &[&str]) -> Result<Vec<Movement>, ParseError> {
+        Self::decode_movements(lines, 0)
     }
 
-    pub fn parse_warehouse(lines: &[&str]) -> Result<Warehouse, PuzzleError> {
+    fn decode_warehouse(lines: &[&str], first_index: usize) -> Result<Warehouse, ParseError> {
         // Check for input validity
         if lines.is_empty() {
-            return Err(PuzzleError::InvalidContentError(
-                "Failed to create warehouse, lines are empty".to_string(),
-            ));
+            return Err(
+                ParseError::new("Failed to create warehouse, lines are empty")
+                    .with_line(first_index),
+            );
         }
 
+        grid_lines::check_rectangular(lines).map_err(|err| match err.get_line() {
+            Some(line) => err.with_line(first_index + line - 1),
+            None => err,
+        })?;
+
         let rows = lines.len();
         let cols = lines[0].len();
         let mut tiles = Vec::with_capacity(rows * cols);
 
         // Build vector with values
-        for line in lines {
-            for c in line.chars() {
+        for (index, line) in lines.iter().enumerate() {
+            for (position, c) in line.char_indices() {
                 let tile = match c {
                     '.' => Tile::Empty,
                     '#' => Tile::Wall,
                     'O' => Tile::Box,
                     '@' => Tile::Robot,
                     other => {
-                        return Err(PuzzleError::InvalidContentError(format!(
+                        return Err(ParseError::new(format!(
                             "Failed to create warehouse, invalid character '{}'",
                             other
-                        )))
+                        ))
+                        .with_span(line, position..position + other.len_utf8())
+                        .with_line(first_index + index))
                     }
                 };
 
@@ -75,21 +77,23 @@ impl Parser {
         Ok(Warehouse::new(rows, cols, tiles))
     }
 
-    pub fn parse_movements(lines: &[&str]) -> Result<Vec<Movement>, PuzzleError> {
+    fn decode_movements(lines: &[&str], first_index: usize) -> Result<Vec<Movement>, ParseError> {
         let mut movements = Vec::with_capacity(lines.len() * 100);
 
-        for line in lines {
-            for c in line.chars() {
+        for (index, line) in lines.iter().enumerate() {
+            for (position, c) in line.char_indices() {
                 let movement = match c {
                     '>' => Movement::Right,
                     'v' => Movement::Down,
                     '<' => Movement::Left,
                     '^' => Movement::Up,
                     other => {
-                        return Err(PuzzleError::InvalidContentError(format!(
+                        return Err(ParseError::new(format!(
                             "Failed to create movements, invalid character '{}'",
                             other
-                        )))
+                        ))
+                        .with_span(line, position..position + other.len_utf8())
+                        .with_line(first_index + index))
                     }
                 };
 
@@ -208,4 +212,19 @@ mod tests {
         assert!(result.is_ok(), "result: {:?}", result);
         assert_eq!(result.unwrap(), expected_movements);
     }
+
+    #[test]
+    fn test_parse_lines_error_location() {
+        let result = Parser::parse_lines(&["#####", "#@O.#", "#####", "", "<^^>", ">>x<"]);
+
+        assert!(result.is_err());
+        assert!(
+            result
+                .err()
+                .unwrap()
+                .to_string()
+                .contains("line 6, column 3"),
+            "Missing location"
+        );
+    }
 }
@@ -1,21 +1,52 @@
-use std::cell::RefCell;
-
 use advent_of_code::puzzles::{
     puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
     puzzle_solver::PuzzleSolver,
 };
+use aoc_common::{
+    parsable::Parsable, parse_error::ParseError, part::Part, render::frame::Frame,
+    renderable::Renderable,
+};
 
 use super::{movement::Movement, parser::Parser, warehouse::Warehouse};
 
 pub struct Solver {
-    warehouse: RefCell<Warehouse>,
+    warehouse: Warehouse,
     movements: Vec<Movement>,
 }
 
+impl Renderable for Solver {
+    type Error = PuzzleError;
+
+    fn frames(&self, part: Part) -> Result<Vec<Frame>, PuzzleError> {
+        match part {
+            Part::Part1 => {
+                let mut warehouse = self.warehouse.clone();
+                warehouse.move_boxes(&self.movements);
+
+                Ok(vec![
+                    self.warehouse.to_frame("Warehouse before moves"),
+                    warehouse.to_frame(&format!("Warehouse after {} moves", self.movements.len())),
+                ])
+            }
+            Part::Part2 => Err(PuzzleError::GenericError(String::from(
+                "Part 2 is not solved",
+            ))),
+        }
+    }
+}
+
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        (self.warehouse, self.movements) = Parser::parse_lines(lines)?;
+        Ok(())
+    }
+}
+
 impl PuzzleSolver for Solver {
     fn new() -> Self {
         Self {
-            warehouse: RefCell::default(),
+            warehouse: Warehouse::default(),
             movements: Vec::new(),
         }
     }
@@ -25,16 +56,17 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        (*self.warehouse.borrow_mut(), self.movements) = Parser::parse_lines(lines)?;
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
-        // Move stuff in a warehouse
-        self.warehouse.borrow_mut().move_boxes(&self.movements);
+        // Move stuff in a copy of the warehouse so the parsed state stays untouched
+        let mut warehouse = self.warehouse.clone();
+        warehouse.move_boxes(&self.movements);
 
         // Calculate GPS for all boxes
-        let gps_coordinates = self.warehouse.borrow().gps_coordinates();
+        let gps_coordinates = warehouse.gps_coordinates();
 
         Ok(gps_coordinates.to_string())
     }
@@ -50,6 +82,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -72,4 +105,9 @@ mod tests {
     fn test_part_2() {
         get_tester().test_part_2();
     }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
 }
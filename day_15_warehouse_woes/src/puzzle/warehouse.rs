@@ -1,6 +1,8 @@
+use aoc_common::render::frame::Frame;
+
 use super::{movement::Movement, tile::Tile, tile_index::TileIndex};
 
-#[derive(Debug, Default, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct Warehouse {
     rows: usize,
     cols: usize,
@@ -240,22 +242,16 @@ impl Warehouse {
         }
     }
 
-    #[allow(dead_code)]
-    fn print(&self) {
-        for row in 0..self.rows {
-            for col in 0..self.cols {
-                let tile = match self.tiles[row * self.cols + col] {
-                    Tile::Empty => '.',
-                    Tile::Wall => '#',
-                    Tile::Box => 'O',
-                    Tile::Robot => '@',
-                };
-
-                print!("{}", tile);
+    /// Frame of the warehouse, tiles are `(column, row)`
+    pub fn to_frame(&self, title: &str) -> Frame {
+        Frame::new(title, self.rows, self.cols, |x, y| {
+            match self.tiles[y as usize * self.cols + x as usize] {
+                Tile::Empty => '.',
+                Tile::Wall => '#',
+                Tile::Box => 'O',
+                Tile::Robot => '@',
             }
-
-            println!();
-        }
+        })
     }
 }
 
@@ -506,15 +502,13 @@ mod tests {
         // Compare step by step progress of the movements
         for (step, (movement, final_warehouse)) in input.iter().enumerate() {
             // Move warehouse items by a single movement at a time
-            warehouse.move_boxes(&[movement.clone()]);
+            warehouse.move_boxes(std::slice::from_ref(movement));
 
             assert_eq!(
                 warehouse, *final_warehouse,
                 "Error at step: '{}' movement: {:?}",
                 step, movement
             );
-
-            // warehouse.print();
         }
     }
 
@@ -528,6 +522,18 @@ mod tests {
         assert_eq!(warehouse.gps_coordinates(), 2028);
     }
 
+    #[test]
+    fn test_to_frame() {
+        let (warehouse, _, _) = build_small_warehouse();
+        let frame = warehouse.to_frame("test");
+
+        assert_eq!((frame.get_rows(), frame.get_cols()), (8, 8));
+        assert_eq!(frame.get_tile(2, 2), Some('@'));
+        assert_eq!(frame.get_tile(3, 1), Some('O'));
+        assert_eq!(frame.get_tile(1, 2), Some('#'));
+        assert_eq!(frame.get_tile(1, 1), Some('.'));
+    }
+
     #[test]
     fn test_move_boxes_large() {
         let (mut warehouse, movements, final_warehouse) = build_large_warehouse();
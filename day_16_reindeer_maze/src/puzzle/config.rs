@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+/// Puzzle constants, defaults match the puzzle description
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Score of a single step forward
+    pub forward_score: usize,
+    /// Score of a single rotation by 90 degrees
+    pub rotate_score: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            forward_score: 1,
+            rotate_score: 1000,
+        }
+    }
+}
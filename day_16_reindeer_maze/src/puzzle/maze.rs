@@ -8,10 +8,14 @@ use advent_of_code::{
     grids::{direction::Direction, grid::Grid, point::Point},
     puzzles::puzzle_error::PuzzleError,
 };
+use aoc_common::render::frame::Frame;
 use priority_queue::PriorityQueue;
+use tracing::debug;
 
-const FORWARD_SCORE: usize = 1;
-const ROTATE_SCORE: usize = 1000;
+use super::config::Config;
+
+// Lowest score of every state together with parents which reach it with that score
+type Nodes = HashMap<(Point, Direction), (usize, Vec<(Point, Direction)>)>;
 
 #[derive(Default)]
 pub struct Maze {
@@ -23,13 +27,58 @@ impl Maze {
         Self { grid }
     }
 
-    pub fn find_lowest_score(&self) -> Result<usize, PuzzleError> {
-        let (lowest_score, _path) = self.dijkstra_lowest_score()?;
-        // self.grid.print();
+    pub fn get_grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    pub fn to_frame(&self, title: &str) -> Frame {
+        Frame::new(title, self.grid.rows(), self.grid.cols(), |x, y| {
+            self.grid[Point { x, y }]
+        })
+    }
+
+    /// Points of a path with the lowest score from the start to the end
+    pub fn find_best_path(&self, config: &Config) -> Result<Vec<Point>, PuzzleError> {
+        let (end_states, nodes) = self.dijkstra_best_states(config)?;
+
+        let mut state = end_states.first().copied().ok_or_else(|| {
+            PuzzleError::GenericError("Failed to find the shortest path".to_string())
+        })?;
+
+        // Follow the first parent back to the start, zero scores could make a cycle
+        let mut points = vec![state.0];
+        let mut seen = HashSet::from([state]);
+
+        while let Some(parent) = nodes.get(&state).and_then(|(_, parents)| parents.first()) {
+            if !seen.insert(*parent) {
+                break;
+            }
+
+            points.push(parent.0);
+            state = *parent;
+        }
+
+        // Rotations stay on the same point
+        points.reverse();
+        points.dedup();
+
+        Ok(points)
+    }
+
+    /// Tiles which are part of at least one path with the lowest score
+    pub fn find_best_path_tiles(&self, config: &Config) -> Result<Vec<Point>, PuzzleError> {
+        self.dijkstra_all_paths(config)
+    }
+
+    pub fn find_lowest_score(&self, config: &Config) -> Result<usize, PuzzleError> {
+        let (lowest_score, _path) = self.dijkstra_lowest_score(config)?;
         Ok(lowest_score)
     }
 
-    fn dijkstra_lowest_score(&self) -> Result<(usize, Vec<(Point, Direction)>), PuzzleError> {
+    fn dijkstra_lowest_score(
+        &self,
+        config: &Config,
+    ) -> Result<(usize, Vec<(Point, Direction)>), PuzzleError> {
         // Queue with next states to analyze
         let mut queue = PriorityQueue::new();
 
@@ -57,6 +106,7 @@ impl Maze {
 
             // Fetch score for current node
             let score = priority.0;
+            debug!(?point, ?direction, score, "Pop state");
 
             visited.insert((point, direction));
 
@@ -86,13 +136,17 @@ impl Maze {
             }
 
             // Spawn next possible states:
-            // - in the neighbor cell in the same direction with the cost 1
-            // - in the same cell rotated in clock direction with the cost 1000
-            // - in the same cell rotated in clockwise direction with the cost 1000
+            // - in the neighbor cell in the same direction with the forward score
+            // - in the same cell rotated in clock direction with the rotate score
+            // - in the same cell rotated in clockwise direction with the rotate score
             let next_states = [
-                (point.neighbor(direction), direction, score + FORWARD_SCORE),
-                (point, direction.left(), score + ROTATE_SCORE),
-                (point, direction.right(), score + ROTATE_SCORE),
+                (
+                    point.neighbor(direction),
+                    direction,
+                    score + config.forward_score,
+                ),
+                (point, direction.left(), score + config.rotate_score),
+                (point, direction.right(), score + config.rotate_score),
             ];
 
             // Update states which are valid:
@@ -144,15 +198,57 @@ impl Maze {
         Ok((start[0], end[0]))
     }
 
-    pub fn find_all_paths(&self) -> Result<usize, PuzzleError> {
-        let solution = self.dijkstra_all_paths()?;
-        // self.grid.print_with_visited(&solution);
+    pub fn find_all_paths(&self, config: &Config) -> Result<usize, PuzzleError> {
+        let solution = self.dijkstra_all_paths(config)?;
         // Note: Returned vector already contains unique items
         Ok(solution.len())
     }
 
     // Run Modified Dijkstra algorithm to find all paths from Start to End
-    fn dijkstra_all_paths(&self) -> Result<Vec<Point>, PuzzleError> {
+    fn dijkstra_all_paths(&self, config: &Config) -> Result<Vec<Point>, PuzzleError> {
+        let (end_states, nodes) = self.dijkstra_best_states(config)?;
+
+        // Backtrack - Go from End node and collect back all parent nodes
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::new();
+
+        for state in end_states {
+            for (_, parent_direction) in &nodes[&state].1 {
+                queue.push_back((state.0, *parent_direction));
+            }
+        }
+
+        while let Some((point, direction)) = queue.pop_front() {
+            if seen.contains(&(point, direction)) {
+                continue;
+            }
+
+            seen.insert((point, direction));
+
+            // Fetch node parents
+            let (_, parents) = nodes
+                .get(&(point, direction))
+                .unwrap_or_else(|| panic!("Failed to find '{:?}:{:?}' in nodes", point, direction));
+
+            for (parent_point, parent_direction) in parents {
+                queue.push_back((*parent_point, *parent_direction))
+            }
+        }
+
+        // Collect only unique point, i.e. ignore direction
+        let unique_points = seen
+            .into_iter()
+            .map(|(point, _)| point)
+            .collect::<HashSet<_>>();
+
+        Ok(unique_points.into_iter().collect::<Vec<_>>())
+    }
+
+    // Find states at the End with the lowest score together with parents of all states
+    fn dijkstra_best_states(
+        &self,
+        config: &Config,
+    ) -> Result<(Vec<(Point, Direction)>, Nodes), PuzzleError> {
         let mut lowest_score = usize::MAX;
         let (start, end) = self.get_start_and_end()?;
 
@@ -168,6 +264,8 @@ impl Maze {
                 continue;
             }
 
+            debug!(?point, ?direction, score, "Pop state");
+
             visited.insert((point, direction));
 
             // Check for a solution
@@ -188,9 +286,13 @@ impl Maze {
 
             // Analyze neighbors
             let next_states = [
-                (point.neighbor(direction), direction, score + FORWARD_SCORE),
-                (point, direction.left(), score + ROTATE_SCORE),
-                (point, direction.right(), score + ROTATE_SCORE),
+                (
+                    point.neighbor(direction),
+                    direction,
+                    score + config.forward_score,
+                ),
+                (point, direction.left(), score + config.rotate_score),
+                (point, direction.right(), score + config.rotate_score),
             ];
 
             // Update states which are valid:
@@ -241,51 +343,13 @@ impl Maze {
             }
         }
 
-        // Backtrack - Go from End node and collect back all parent nodes
-        let minimal_costs = nodes
+        let end_states = nodes
             .iter()
-            .filter_map(|((point, direction), (cost, parents))| {
-                if point == &end && cost == &lowest_score {
-                    Some((*point, *direction, parents.clone()))
-                } else {
-                    None
-                }
-            })
+            .filter(|((point, _), (cost, _))| point == &end && cost == &lowest_score)
+            .map(|(state, _)| *state)
             .collect::<Vec<_>>();
 
-        let mut seen = HashSet::new();
-        let mut queue = VecDeque::new();
-
-        for (point, _, parents) in minimal_costs {
-            for (_, parent_direction) in parents {
-                queue.push_back((point, parent_direction));
-            }
-        }
-
-        while let Some((point, direction)) = queue.pop_front() {
-            if seen.contains(&(point, direction)) {
-                continue;
-            }
-
-            seen.insert((point, direction));
-
-            // Fetch node parents
-            let (_, parents) = nodes
-                .get(&(point, direction))
-                .unwrap_or_else(|| panic!("Failed to find '{:?}:{:?}' in nodes", point, direction));
-
-            for (parent_point, parent_direction) in parents {
-                queue.push_back((*parent_point, *parent_direction))
-            }
-        }
-
-        // Collect only unique point, i.e. ignore direction
-        let unique_points = seen
-            .into_iter()
-            .map(|(point, _)| point)
-            .collect::<HashSet<_>>();
-
-        Ok(unique_points.into_iter().collect::<Vec<_>>())
+        Ok((end_states, nodes))
     }
 }
 
@@ -344,7 +408,7 @@ mod tests {
     #[test]
     fn test_find_lowest_score_small_maze() {
         let maze = build_small_maze();
-        let result = maze.find_lowest_score();
+        let result = maze.find_lowest_score(&Config::default());
 
         assert!(result.is_ok(), "result: {:?}", result);
         assert_eq!(result.unwrap(), 7036);
@@ -353,7 +417,7 @@ mod tests {
     #[test]
     fn test_find_lowest_score_large_maze() {
         let maze = build_large_maze();
-        let result = maze.find_lowest_score();
+        let result = maze.find_lowest_score(&Config::default());
 
         assert!(result.is_ok(), "result: {:?}", result);
         assert_eq!(result.unwrap(), 11048);
@@ -362,7 +426,7 @@ mod tests {
     #[test]
     fn test_find_all_paths_small_maze() {
         let maze = build_small_maze();
-        let result = maze.find_all_paths();
+        let result = maze.find_all_paths(&Config::default());
 
         assert!(result.is_ok(), "result: {:?}", result);
         assert_eq!(result.unwrap(), 45);
@@ -371,7 +435,7 @@ mod tests {
     #[test]
     fn test_find_all_paths_large_maze() {
         let maze = build_large_maze();
-        let result = maze.find_all_paths();
+        let result = maze.find_all_paths(&Config::default());
 
         assert!(result.is_ok(), "result: {:?}", result);
         assert_eq!(result.unwrap(), 64);
@@ -1,3 +1,5 @@
+pub mod config;
 pub mod maze;
 pub mod parser;
 pub mod solver;
+pub mod witness;
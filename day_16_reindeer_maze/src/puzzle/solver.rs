@@ -1,17 +1,95 @@
-use advent_of_code::puzzles::{
-    puzzle::{PuzzleResult, SolutionResult},
-    puzzle_solver::PuzzleSolver,
+use advent_of_code::{
+    grids::point::Point,
+    puzzles::{
+        puzzle::{PuzzleResult, SolutionResult},
+        puzzle_error::PuzzleError,
+        puzzle_solver::PuzzleSolver,
+    },
+};
+use aoc_common::{
+    configurable::Configurable,
+    explainable::Explainable,
+    parsable::Parsable,
+    parse_error::ParseError,
+    part::Part,
+    render::{frame::Frame, layer::Layer},
+    renderable::Renderable,
 };
 
-use super::{maze::Maze, parser::Parser};
+use super::{config::Config, maze::Maze, parser::Parser, witness::Witness};
 
 pub struct Solver {
+    config: Config,
     maze: Maze,
 }
 
+impl Configurable for Solver {
+    type Config = Config;
+
+    fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+}
+
+impl Explainable for Solver {
+    type Witness = Witness;
+    type Error = PuzzleError;
+
+    fn witness(&self, part: Part) -> Result<Witness, PuzzleError> {
+        let to_pairs = |points: Vec<Point>| points.iter().map(|point| (point.x, point.y)).collect();
+
+        match part {
+            Part::Part1 => Ok(Witness::BestPath {
+                path: to_pairs(self.maze.find_best_path(&self.config)?),
+            }),
+            Part::Part2 => {
+                let mut tiles = to_pairs(self.maze.find_best_path_tiles(&self.config)?);
+                tiles.sort();
+
+                Ok(Witness::BestPathTiles { tiles })
+            }
+        }
+    }
+
+    fn check_witness(&self, _part: Part, witness: &Witness) -> Result<String, PuzzleError> {
+        witness
+            .check(self.maze.get_grid(), &self.config)
+            .map_err(PuzzleError::GenericError)
+    }
+}
+
+impl Renderable for Solver {
+    type Error = PuzzleError;
+
+    fn frames(&self, part: Part) -> Result<Vec<Frame>, PuzzleError> {
+        let (mut frame, points) = match part {
+            Part::Part1 => (
+                self.maze.to_frame("Path with the lowest score"),
+                self.maze.find_best_path(&self.config)?,
+            ),
+            Part::Part2 => (
+                self.maze
+                    .to_frame("Tiles of all paths with the lowest score"),
+                self.maze.find_best_path_tiles(&self.config)?,
+            ),
+        };
+        frame.add_layer(Layer::Path, points.iter().map(|point| (point.x, point.y)));
+
+        Ok(vec![frame])
+    }
+}
+
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        self.maze = Parser::parse_lines(lines)?;
+        Ok(())
+    }
+}
+
 impl PuzzleSolver for Solver {
     fn new() -> Self {
         Self {
+            config: Config::default(),
             maze: Maze::default(),
         }
     }
@@ -21,17 +99,17 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        self.maze = Parser::parse_lines(lines)?;
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
-        let fewest_score = self.maze.find_lowest_score()?;
+        let fewest_score = self.maze.find_lowest_score(&self.config)?;
         Ok(fewest_score.to_string())
     }
 
     fn part_2(&self) -> SolutionResult {
-        let tiles_count = self.maze.find_all_paths()?;
+        let tiles_count = self.maze.find_all_paths(&self.config)?;
         Ok(tiles_count.to_string())
     }
 }
@@ -42,6 +120,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -64,4 +143,14 @@ mod tests {
     fn test_part_2() {
         get_tester().test_part_2();
     }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
+
+    #[test]
+    fn test_explain_examples() {
+        ExampleTester::new(crate::day()).test_explain();
+    }
 }
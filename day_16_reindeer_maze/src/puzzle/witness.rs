@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+
+use advent_of_code::grids::{grid::Grid, point::Point};
+use serde::Serialize;
+
+use super::config::Config;
+
+/// Evidence of an answer, coordinates are `(x, y)` from the top left corner of the maze
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Witness {
+    /// Tiles of a path with the lowest score from the start to the end
+    BestPath { path: Vec<(isize, isize)> },
+    /// Tiles which are part of at least one best path
+    BestPathTiles { tiles: Vec<(isize, isize)> },
+}
+
+impl Witness {
+    /// Compute the answer from the witness, only the maze and scores are trusted
+    pub fn check(&self, grid: &Grid, config: &Config) -> Result<String, String> {
+        let start = Self::find_single(grid, 'S')?;
+        let end = Self::find_single(grid, 'E')?;
+
+        match self {
+            Witness::BestPath { path } => {
+                if path.first() != Some(&start) || path.last() != Some(&end) {
+                    return Err("Path has to go from the start to the end".to_string());
+                }
+
+                Self::check_tiles(grid, path)?;
+
+                // Reindeer starts facing East
+                let mut direction = (1, 0);
+                let mut score = 0;
+
+                for step in path.windows(2) {
+                    let next = (step[1].0 - step[0].0, step[1].1 - step[0].1);
+                    if next.0.abs() + next.1.abs() != 1 {
+                        return Err(format!("Path jumps from {:?} to {:?}", step[0], step[1]));
+                    }
+
+                    // Opposite direction needs two rotations
+                    let rotations = match next {
+                        _ if next == direction => 0,
+                        _ if next == (-direction.0, -direction.1) => 2,
+                        _ => 1,
+                    };
+
+                    score += rotations * config.rotate_score + config.forward_score;
+                    direction = next;
+                }
+
+                Ok(score.to_string())
+            }
+            Witness::BestPathTiles { tiles } => {
+                if !tiles.contains(&start) || !tiles.contains(&end) {
+                    return Err("Tiles have to contain the start and the end".to_string());
+                }
+
+                Self::check_tiles(grid, tiles)?;
+
+                let unique = tiles.iter().collect::<HashSet<_>>();
+                if unique.len() != tiles.len() {
+                    return Err("Tiles are repeated".to_string());
+                }
+
+                Ok(tiles.len().to_string())
+            }
+        }
+    }
+
+    fn find_single(grid: &Grid, value: char) -> Result<(isize, isize), String> {
+        match grid.get_all_values(value).as_slice() {
+            [point] => Ok((point.x, point.y)),
+            _ => Err(format!("Exactly one '{}' expected in the maze", value)),
+        }
+    }
+
+    fn check_tiles(grid: &Grid, tiles: &[(isize, isize)]) -> Result<(), String> {
+        match tiles.iter().find(|&&(x, y)| {
+            let point = Point { x, y };
+            !grid.is_point_in_grid(&point) || grid[point] == '#'
+        }) {
+            Some(tile) => Err(format!("Tile {:?} is not free", tile)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_grid() -> Grid {
+        Grid::new_from_lines(&["#####", "#..E#", "#.#.#", "#S..#", "#####"])
+            .expect("Failed to create grid")
+    }
+
+    #[test]
+    fn test_check_best_path() {
+        let grid = build_grid();
+        let config = Config::default();
+
+        let witness = Witness::BestPath {
+            path: vec![(1, 3), (2, 3), (3, 3), (3, 2), (3, 1)],
+        };
+        assert_eq!(witness.check(&grid, &config), Ok("1004".to_string()));
+
+        let witness = Witness::BestPath {
+            path: vec![(1, 3), (1, 2), (1, 1), (2, 1), (3, 1)],
+        };
+        assert_eq!(witness.check(&grid, &config), Ok("2004".to_string()));
+
+        for path in [
+            vec![(1, 3), (2, 3), (3, 3), (3, 1)],
+            vec![(1, 3), (2, 3), (2, 2), (3, 2), (3, 1)],
+            vec![(2, 3), (3, 3), (3, 2), (3, 1)],
+        ] {
+            let witness = Witness::BestPath { path };
+            assert!(witness.check(&grid, &config).is_err());
+        }
+    }
+
+    #[test]
+    fn test_check_best_path_tiles() {
+        let grid = build_grid();
+        let config = Config::default();
+
+        let witness = Witness::BestPathTiles {
+            tiles: vec![(1, 3), (2, 3), (3, 3), (3, 2), (3, 1)],
+        };
+        assert_eq!(witness.check(&grid, &config), Ok("5".to_string()));
+
+        for tiles in [
+            vec![(1, 3), (2, 3), (3, 3), (3, 2)],
+            vec![(1, 3), (1, 3), (3, 1)],
+            vec![(1, 3), (2, 2), (3, 1)],
+        ] {
+            let witness = Witness::BestPathTiles { tiles };
+            assert!(witness.check(&grid, &config).is_err());
+        }
+    }
+}
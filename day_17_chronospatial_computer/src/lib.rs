@@ -0,0 +1,12 @@
+pub mod puzzle;
+
+pub use advent_of_code::puzzles::puzzle_solver::PuzzleSolver;
+pub use puzzle::{
+    computer::{Computer, ComputerOutput},
+    parser::Parser,
+    registers::Registers,
+    registers_builder::RegistersBuilder,
+    solver::Solver,
+};
+
+aoc_common::export_day!(17, Solver, parsable);
@@ -1,4 +1,5 @@
 use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use tracing::debug;
 
 use crate::puzzle::instructions::instruction_decoder::InstructionDecoder;
 
@@ -6,7 +7,7 @@ use super::registers::Registers;
 
 pub type ComputerOutput = Vec<usize>;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct Computer {
     registers: Registers,
     instruction_pointer: usize,
@@ -35,6 +36,14 @@ impl Computer {
             let opcode = instructions[self.instruction_pointer];
             let operand = instructions[self.instruction_pointer + 1];
 
+            debug!(
+                pointer = self.instruction_pointer,
+                opcode,
+                operand,
+                registers = ?self.registers,
+                "Execute instruction"
+            );
+
             let mut instruction = InstructionDecoder::decode(opcode)?;
             instruction.execute(operand, &mut self.registers, &mut self.output)?;
 
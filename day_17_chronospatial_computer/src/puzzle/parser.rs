@@ -1,23 +1,26 @@
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::parse_error::ParseError;
 
 use super::{computer::Computer, registers::Registers};
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<(Computer, String), PuzzleError> {
+    pub fn parse_lines(lines: &[&str]) -> Result<(Computer, String), ParseError> {
         // Check file size
         if lines.len() < 5 {
-            return Err(PuzzleError::InvalidContentError(format!(
+            return Err(ParseError::new(format!(
                 "Input file should contain at least 5 lines, but {} found",
                 lines.len()
             )));
         }
 
-        let register_a = Self::parse_register(lines[0], "Register A:")?;
-        let register_b = Self::parse_register(lines[1], "Register B:")?;
-        let register_c = Self::parse_register(lines[2], "Register C:")?;
-        let program = Self::parse_program(lines[4])?;
+        let register_a =
+            Self::parse_register(lines[0], "Register A:").map_err(|err| err.with_line(0))?;
+        let register_b =
+            Self::parse_register(lines[1], "Register B:").map_err(|err| err.with_line(1))?;
+        let register_c =
+            Self::parse_register(lines[2], "Register C:").map_err(|err| err.with_line(2))?;
+        let program = Self::parse_program(lines[4]).map_err(|err| err.with_line(4))?;
 
         let computer = Computer::new(Registers {
             a: register_a,
@@ -27,35 +30,49 @@ impl Parser {
         Ok((computer, program))
     }
 
-    fn parse_register(line: &str, prefix: &str) -> Result<usize, PuzzleError> {
+    fn parse_register(line: &str, prefix: &str) -> Result<usize, ParseError> {
         match line.find(prefix) {
             Some(index) => {
-                let value = &line[index + prefix.len()..].trim();
+                let value = line[index + prefix.len()..].trim();
                 let number = value.parse::<usize>().map_err(|err| {
-                    PuzzleError::InvalidContentError(format!(
-                        "Failed to parse '{}' to usize [{}]",
-                        value, err
-                    ))
+                    ParseError::new(format!("Failed to parse '{}' to usize [{}]", value, err))
+                        .with_substring(line, value)
                 })?;
 
                 Ok(number)
             }
-            None => Err(PuzzleError::InvalidContentError(format!(
-                "Failed to find '{}' in '{}'",
-                prefix, line
-            ))),
+            None => Err(
+                ParseError::new(format!("Failed to find '{}' in '{}'", prefix, line))
+                    .with_whole_line(line),
+            ),
         }
     }
 
-    fn parse_program(line: &str) -> Result<String, PuzzleError> {
+    fn parse_program(line: &str) -> Result<String, ParseError> {
         const PROGRAM: &str = "Program:";
 
         match line.find(PROGRAM) {
-            Some(index) => Ok(line[index + PROGRAM.len()..].trim().to_string()),
-            None => Err(PuzzleError::InvalidContentError(format!(
-                "Failed to find '{}' in '{}'",
-                PROGRAM, line
-            ))),
+            Some(index) => {
+                let program = line[index + PROGRAM.len()..].trim();
+
+                // Program consists of 3-bit numbers only
+                if let Some(invalid) = program
+                    .split(",")
+                    .find(|code| !matches!(code.as_bytes(), [b'0'..=b'7']))
+                {
+                    return Err(ParseError::new(format!(
+                        "Invalid 3-bit number '{}' in program",
+                        invalid
+                    ))
+                    .with_substring(line, invalid));
+                }
+
+                Ok(program.to_string())
+            }
+            None => Err(
+                ParseError::new(format!("Failed to find '{}' in '{}'", PROGRAM, line))
+                    .with_whole_line(line),
+            ),
         }
     }
 }
@@ -82,4 +99,12 @@ mod tests {
         assert_eq!(computer.get_registers(), &Registers { a: 729, b: 0, c: 0 });
         assert_eq!(program, "0,1,5,4,3,0");
     }
+
+    #[test]
+    fn test_parse_program_invalid() {
+        let result = Parser::parse_program("Program: 0,1,8,4");
+
+        assert!(result.is_err(), "result: {:?}", result);
+        assert_eq!(result.unwrap_err().get_columns(), Some(&(14..15)));
+    }
 }
@@ -1,21 +1,28 @@
-use std::cell::RefCell;
-
 use advent_of_code::puzzles::{
     puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
     puzzle_solver::PuzzleSolver,
 };
+use aoc_common::{parsable::Parsable, parse_error::ParseError};
 
 use super::{computer::Computer, parser::Parser};
 
 pub struct Solver {
-    computer: RefCell<Computer>,
+    computer: Computer,
     program: String,
 }
 
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        (self.computer, self.program) = Parser::parse_lines(lines)?;
+        Ok(())
+    }
+}
+
 impl PuzzleSolver for Solver {
     fn new() -> Self {
         Self {
-            computer: RefCell::new(Computer::default()),
+            computer: Computer::default(),
             program: String::new(),
         }
     }
@@ -25,12 +32,13 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        (*self.computer.borrow_mut(), self.program) = Parser::parse_lines(lines)?;
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
-        let program_output = self.computer.borrow_mut().run_program(&self.program)?;
+        // Run the program on a fresh copy of the computer, registers are changed during execution
+        let program_output = self.computer.clone().run_program(&self.program)?;
         Ok(program_output)
     }
 
@@ -45,6 +53,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -67,4 +76,9 @@ mod tests {
     fn test_part_2() {
         get_tester().test_part_2();
     }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
 }
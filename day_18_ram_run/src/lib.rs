@@ -0,0 +1,6 @@
+pub mod puzzle;
+
+pub use advent_of_code::puzzles::puzzle_solver::PuzzleSolver;
+pub use puzzle::{parser::Parser, ram::Ram, solver::Solver};
+
+aoc_common::export_day!(18, Solver, configurable, parsable, explainable, renderable);
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+/// Puzzle constants, defaults match the real input
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Rows of the memory space
+    pub rows: usize,
+    /// Columns of the memory space
+    pub cols: usize,
+    /// Count of bytes which have already fallen before searching the path in part 1
+    pub corrupted_bytes: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            rows: 71,
+            cols: 71,
+            corrupted_bytes: 1024,
+        }
+    }
+}
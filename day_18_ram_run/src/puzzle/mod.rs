@@ -1,3 +1,5 @@
+pub mod config;
 pub mod parser;
 pub mod ram;
 pub mod solver;
+pub mod witness;
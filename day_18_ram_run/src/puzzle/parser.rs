@@ -1,34 +1,42 @@
-use advent_of_code::{grids::point::Point, puzzles::puzzle_error::PuzzleError};
+use advent_of_code::grids::point::Point;
+use aoc_common::parse_error::ParseError;
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<Vec<Point>, PuzzleError> {
-        lines.iter().map(|line| Parser::parse_point(line)).collect()
+    pub fn parse_lines(lines: &[&str]) -> Result<Vec<Point>, ParseError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| Parser::parse_point(line).map_err(|err| err.with_line(index)))
+            .collect()
     }
 
-    fn parse_point(line: &str) -> Result<Point, PuzzleError> {
+    fn parse_point(line: &str) -> Result<Point, ParseError> {
         let splitted = line.trim().split(",").collect::<Vec<_>>();
 
         if splitted.len() != 2 {
-            return Err(PuzzleError::InvalidContentError(format!(
+            return Err(ParseError::new(format!(
                 "Invalid format of corrupted RAM point '{}'",
                 line
-            )));
+            ))
+            .with_whole_line(line));
         }
 
         let x = splitted[0].trim().parse::<usize>().map_err(|err| {
-            PuzzleError::InvalidContentError(format!(
+            ParseError::new(format!(
                 "Failed to parse position x '{}' to usize [{:?}]",
                 splitted[0], err
             ))
+            .with_substring(line, splitted[0])
         })?;
 
         let y = splitted[1].trim().parse::<usize>().map_err(|err| {
-            PuzzleError::InvalidContentError(format!(
+            ParseError::new(format!(
                 "Failed to parse position y '{}' to usize [{:?}]",
                 splitted[1], err
             ))
+            .with_substring(line, splitted[1])
         })?;
 
         Ok(Point {
@@ -62,5 +70,6 @@ mod tests {
         let result = Parser::parse_point("1,4a");
 
         assert!(result.is_err(), "result: {:?}", result);
+        assert_eq!(result.unwrap_err().get_columns(), Some(&(3..5)));
     }
 }
@@ -7,7 +7,9 @@ use advent_of_code::{
     grids::{direction::Direction, grid::Grid, point::Point},
     puzzles::puzzle_error::PuzzleError,
 };
+use aoc_common::render::frame::Frame;
 use priority_queue::PriorityQueue;
+use tracing::debug;
 
 #[derive(Default)]
 pub struct Ram {
@@ -24,6 +26,14 @@ impl Ram {
         corrupted: &[Point],
         corrupted_bytes_count: usize,
     ) -> Result<usize, PuzzleError> {
+        if corrupted_bytes_count > corrupted.len() {
+            return Err(PuzzleError::GenericError(format!(
+                "Expected at least {} corrupted bytes but {} found",
+                corrupted_bytes_count,
+                corrupted.len()
+            )));
+        }
+
         // Prepare grid with corrupted bytes '#'
         let grid = self.corrupt_ram(&corrupted[0..corrupted_bytes_count])?;
 
@@ -37,10 +47,7 @@ impl Ram {
         );
 
         match minimum_path {
-            Some((shortest_steps, _path)) => {
-                // grid.print_with_visited(&_path);
-                Ok(shortest_steps)
-            }
+            Some((shortest_steps, _path)) => Ok(shortest_steps),
 
             None => Err(PuzzleError::GenericError(
                 "Failed to find shortest path".to_string(),
@@ -48,6 +55,15 @@ impl Ram {
         }
     }
 
+    /// Frame of the memory space after the corrupted bytes have fallen
+    pub fn to_frame(&self, title: &str, corrupted: &[Point]) -> Result<Frame, PuzzleError> {
+        let grid = self.corrupt_ram(corrupted)?;
+
+        Ok(Frame::new(title, grid.rows(), grid.cols(), |x, y| {
+            grid[Point { x, y }]
+        }))
+    }
+
     fn corrupt_ram(&self, corrupted: &[Point]) -> Result<Grid, PuzzleError> {
         // Prepare grid with corrupted bytes, take into account number of 'fallen_bytes'
         // Fallen point is marked with '#'
@@ -94,6 +110,7 @@ impl Ram {
                 .get(&point)
                 .cloned()
                 .unwrap_or_else(|| panic!("Failed to find point '{:?}' in nodes", point));
+            debug!(?point, score, "Pop point");
 
             // Check for a solution
             if point == end {
@@ -159,11 +176,58 @@ impl Ram {
         path
     }
 
+    /// Shortest path from the top left to the bottom right corner, including both corners
+    pub fn find_shortest_path(
+        &self,
+        corrupted: &[Point],
+        corrupted_bytes_count: usize,
+    ) -> Result<Vec<Point>, PuzzleError> {
+        let fallen = corrupted.get(0..corrupted_bytes_count).ok_or_else(|| {
+            PuzzleError::GenericError(format!(
+                "Expected at least {} corrupted bytes but {} found",
+                corrupted_bytes_count,
+                corrupted.len()
+            ))
+        })?;
+
+        let grid = self.corrupt_ram(fallen)?;
+
+        let minimum_path = Self::dijkstra_minimum_path(
+            &grid,
+            Point { x: 0, y: 0 },
+            Point {
+                x: grid.cols() as isize - 1,
+                y: grid.rows() as isize - 1,
+            },
+        );
+
+        match minimum_path {
+            // Path is backtracked from the end
+            Some((_, mut path)) => {
+                path.reverse();
+                Ok(path)
+            }
+            None => Err(PuzzleError::GenericError(
+                "Failed to find shortest path".to_string(),
+            )),
+        }
+    }
+
     pub fn find_first_falling_byte(
         &self,
         corrupted: &[Point],
         corrupted_bytes: usize,
     ) -> Result<Point, PuzzleError> {
+        self.find_first_falling_byte_index(corrupted, corrupted_bytes)
+            .map(|index| corrupted[index])
+    }
+
+    /// Index of the first byte which blocks the path to the exit
+    pub fn find_first_falling_byte_index(
+        &self,
+        corrupted: &[Point],
+        corrupted_bytes: usize,
+    ) -> Result<usize, PuzzleError> {
         // Use binary algorithm to speed up
         let mut falling_byte: Option<usize> = None;
         let mut left = corrupted_bytes; // Include already corrupted bytes
@@ -190,9 +254,7 @@ impl Ram {
             }
         }
 
-        falling_byte
-            .map(|index| corrupted[index])
-            .ok_or_else(|| PuzzleError::GenericError("Solution not found".to_string()))
+        falling_byte.ok_or_else(|| PuzzleError::GenericError("Solution not found".to_string()))
     }
 
     fn dijkstra_path_found(grid: &Grid) -> bool {
@@ -252,6 +314,9 @@ mod tests {
 
         assert!(result.is_ok(), "result: {:?}", result);
         assert_eq!(result.unwrap(), 22);
+
+        let result = ram.count_minimum_steps(&corrupted, corrupted.len() + 1);
+        assert!(result.is_err(), "result: {:?}", result);
     }
 
     #[test]
@@ -262,5 +327,34 @@ mod tests {
 
         assert!(result.is_ok(), "result: {:?}", result);
         assert_eq!(result.unwrap(), Point { x: 6, y: 1 });
+
+        let result = ram.find_first_falling_byte_index(&corrupted, 12);
+        assert_eq!(result.ok(), Some(20));
+    }
+
+    #[test]
+    fn test_find_shortest_path() {
+        let (ram, corrupted) = build_ram();
+
+        let result = ram.find_shortest_path(&corrupted, 12);
+
+        assert!(result.is_ok(), "result: {:?}", result);
+        let path = result.unwrap();
+        assert_eq!(path.len(), 23);
+        assert_eq!(path.first(), Some(&Point { x: 0, y: 0 }));
+        assert_eq!(path.last(), Some(&Point { x: 6, y: 6 }));
+    }
+
+    #[test]
+    fn test_to_frame() {
+        let (ram, corrupted) = build_ram();
+
+        let result = ram.to_frame("test", &corrupted[0..12]);
+        assert!(result.is_ok(), "result: {:?}", result);
+
+        let frame = result.unwrap();
+        assert_eq!((frame.get_rows(), frame.get_cols()), (7, 7));
+        assert_eq!(frame.get_tile(5, 4), Some('#'));
+        assert_eq!(frame.get_tile(1, 2), Some('.'));
     }
 }
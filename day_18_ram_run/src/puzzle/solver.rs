@@ -2,29 +2,132 @@ use advent_of_code::{
     grids::{grid::Grid, point::Point},
     puzzles::{
         puzzle::{PuzzleResult, SolutionResult},
+        puzzle_error::PuzzleError,
         puzzle_solver::PuzzleSolver,
     },
 };
+use aoc_common::{
+    configurable::Configurable,
+    explainable::Explainable,
+    parsable::Parsable,
+    parse_error::ParseError,
+    part::Part,
+    render::{frame::Frame, layer::Layer},
+    renderable::Renderable,
+};
 
-use super::{parser::Parser, ram::Ram};
-
-const CORRUPTED_BYTES: usize = 1024;
-const RAM_ROWS: usize = 71;
-const RAM_COLS: usize = 71;
+use super::{config::Config, parser::Parser, ram::Ram, witness::Witness};
 
 pub struct Solver {
-    ram: Ram,
+    config: Config,
     corrupted: Vec<Point>,
 }
 
-impl PuzzleSolver for Solver {
-    fn new() -> Self {
+impl Solver {
+    fn create_ram(&self) -> Result<Ram, PuzzleError> {
         // Create grid with all bytes allowed
-        let grid = Grid::new_with(RAM_ROWS, RAM_COLS, |_| '.')
-            .unwrap_or_else(|err| panic!("Failed to create grid [{:?}]", err));
+        let grid = Grid::new_with(self.config.rows, self.config.cols, |_| '.')?;
+
+        Ok(Ram::new(grid))
+    }
+}
+
+impl Configurable for Solver {
+    type Config = Config;
+
+    fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+}
+
+impl Explainable for Solver {
+    type Witness = Witness;
+    type Error = PuzzleError;
+
+    fn witness(&self, part: Part) -> Result<Witness, PuzzleError> {
+        let to_pairs = |path: Vec<Point>| path.iter().map(|point| (point.x, point.y)).collect();
+        let ram = self.create_ram()?;
+
+        match part {
+            Part::Part1 => {
+                let path = ram.find_shortest_path(&self.corrupted, self.config.corrupted_bytes)?;
+                Ok(Witness::ShortestPath {
+                    path: to_pairs(path),
+                })
+            }
+            Part::Part2 => {
+                let index = ram
+                    .find_first_falling_byte_index(&self.corrupted, self.config.corrupted_bytes)?;
+                let path_before = ram.find_shortest_path(&self.corrupted, index)?;
+
+                Ok(Witness::FirstBlockingByte {
+                    index,
+                    byte: (self.corrupted[index].x, self.corrupted[index].y),
+                    path_before: to_pairs(path_before),
+                })
+            }
+        }
+    }
+
+    fn check_witness(&self, _part: Part, witness: &Witness) -> Result<String, PuzzleError> {
+        witness
+            .check(
+                self.config.rows,
+                self.config.cols,
+                &self.corrupted,
+                self.config.corrupted_bytes,
+            )
+            .map_err(PuzzleError::GenericError)
+    }
+}
+
+impl Renderable for Solver {
+    type Error = PuzzleError;
+
+    fn frames(&self, part: Part) -> Result<Vec<Frame>, PuzzleError> {
+        let to_pairs = |path: Vec<Point>| path.into_iter().map(|point| (point.x, point.y));
+        let ram = self.create_ram()?;
+        let count = self.config.corrupted_bytes;
+
+        match part {
+            Part::Part1 => {
+                // Path is found first, it reports too few corrupted bytes
+                let path = ram.find_shortest_path(&self.corrupted, count)?;
+
+                let title = format!("Shortest path after {} bytes", count);
+                let mut frame = ram.to_frame(&title, &self.corrupted[0..count])?;
+                frame.add_layer(Layer::Path, to_pairs(path));
+
+                Ok(vec![frame])
+            }
+            Part::Part2 => {
+                let index = ram.find_first_falling_byte_index(&self.corrupted, count)?;
+                let byte = self.corrupted[index];
+                let path = ram.find_shortest_path(&self.corrupted, index)?;
+
+                // Path which existed just before the blocking byte fell
+                let title = format!("Byte {},{} blocks the last path", byte.x, byte.y);
+                let mut frame = ram.to_frame(&title, &self.corrupted[0..index])?;
+                frame.add_layer(Layer::Path, to_pairs(path));
+                frame.add_layer(Layer::Marker, [(byte.x, byte.y)]);
+
+                Ok(vec![frame])
+            }
+        }
+    }
+}
 
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        self.corrupted = Parser::parse_lines(lines)?;
+        Ok(())
+    }
+}
+
+impl PuzzleSolver for Solver {
+    fn new() -> Self {
         Self {
-            ram: Ram::new(grid),
+            config: Config::default(),
             corrupted: Vec::new(),
         }
     }
@@ -34,22 +137,22 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        self.corrupted = Parser::parse_lines(lines)?;
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
         let minimum_steps = self
-            .ram
-            .count_minimum_steps(&self.corrupted, CORRUPTED_BYTES)?;
+            .create_ram()?
+            .count_minimum_steps(&self.corrupted, self.config.corrupted_bytes)?;
 
         Ok(minimum_steps.to_string())
     }
 
     fn part_2(&self) -> SolutionResult {
         let falling_byte = self
-            .ram
-            .find_first_falling_byte(&self.corrupted, CORRUPTED_BYTES)?;
+            .create_ram()?
+            .find_first_falling_byte(&self.corrupted, self.config.corrupted_bytes)?;
 
         Ok(format!("{},{}", falling_byte.x, falling_byte.y))
     }
@@ -61,6 +164,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -83,4 +187,14 @@ mod tests {
     fn test_part_2() {
         get_tester().test_part_2();
     }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
+
+    #[test]
+    fn test_explain_examples() {
+        ExampleTester::new(crate::day()).test_explain();
+    }
 }
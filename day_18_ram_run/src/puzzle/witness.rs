@@ -0,0 +1,189 @@
+use std::collections::{HashSet, VecDeque};
+
+use advent_of_code::grids::point::Point;
+use serde::Serialize;
+
+/// Evidence of an answer, coordinates are `(x, y)` like in the input
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Witness {
+    /// Path from the top left to the bottom right corner avoiding fallen bytes
+    ShortestPath { path: Vec<(isize, isize)> },
+    /// First byte which blocks the exit together with a path which existed before it fell
+    FirstBlockingByte {
+        index: usize,
+        byte: (isize, isize),
+        path_before: Vec<(isize, isize)>,
+    },
+}
+
+impl Witness {
+    /// Compute the answer from the witness, only dimensions and input bytes are trusted
+    pub fn check(
+        &self,
+        rows: usize,
+        cols: usize,
+        corrupted: &[Point],
+        corrupted_bytes: usize,
+    ) -> Result<String, String> {
+        let checker = Checker {
+            rows: rows as isize,
+            cols: cols as isize,
+            corrupted,
+        };
+
+        match self {
+            Witness::ShortestPath { path } => {
+                checker.check_path(path, corrupted_bytes)?;
+                Ok((path.len() - 1).to_string())
+            }
+            Witness::FirstBlockingByte {
+                index,
+                byte,
+                path_before,
+            } => {
+                match corrupted.get(*index) {
+                    Some(point) if (point.x, point.y) == *byte => {}
+                    _ => return Err(format!("Byte {} of the input is not {:?}", index, byte)),
+                }
+
+                checker.check_path(path_before, *index)?;
+
+                if checker.is_exit_reachable(*index + 1) {
+                    return Err(format!("Exit is still reachable after byte {}", index));
+                }
+
+                Ok(format!("{},{}", byte.0, byte.1))
+            }
+        }
+    }
+}
+
+struct Checker<'a> {
+    rows: isize,
+    cols: isize,
+    corrupted: &'a [Point],
+}
+
+impl Checker<'_> {
+    fn fallen(&self, count: usize) -> HashSet<(isize, isize)> {
+        self.corrupted
+            .iter()
+            .take(count)
+            .map(|point| (point.x, point.y))
+            .collect()
+    }
+
+    fn check_path(&self, path: &[(isize, isize)], fallen_count: usize) -> Result<(), String> {
+        let fallen = self.fallen(fallen_count);
+
+        if path.first() != Some(&(0, 0)) || path.last() != Some(&(self.cols - 1, self.rows - 1)) {
+            return Err("Path has to go from the top left to the bottom right corner".to_string());
+        }
+
+        if let Some(position) = path.iter().find(|&&(x, y)| {
+            x < 0 || y < 0 || x >= self.cols || y >= self.rows || fallen.contains(&(x, y))
+        }) {
+            return Err(format!("Path goes through {:?}", position));
+        }
+
+        if let Some(step) = path
+            .windows(2)
+            .find(|step| (step[0].0 - step[1].0).abs() + (step[0].1 - step[1].1).abs() != 1)
+        {
+            return Err(format!("Path jumps from {:?} to {:?}", step[0], step[1]));
+        }
+
+        Ok(())
+    }
+
+    fn is_exit_reachable(&self, fallen_count: usize) -> bool {
+        let fallen = self.fallen(fallen_count);
+        let exit = (self.cols - 1, self.rows - 1);
+
+        let mut seen = HashSet::from([(0, 0)]);
+        let mut queue = VecDeque::from([(0, 0)]);
+
+        while let Some((x, y)) = queue.pop_front() {
+            if (x, y) == exit {
+                return true;
+            }
+
+            for next in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+                if next.0 >= 0
+                    && next.1 >= 0
+                    && next.0 < self.cols
+                    && next.1 < self.rows
+                    && !fallen.contains(&next)
+                    && seen.insert(next)
+                {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn corrupted() -> Vec<Point> {
+        [(1, 0), (1, 1), (1, 2), (2, 1)]
+            .into_iter()
+            .map(|(x, y)| Point { x, y })
+            .collect()
+    }
+
+    #[test]
+    fn test_check_shortest_path() {
+        let corrupted = corrupted();
+        let path = vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)];
+
+        let witness = Witness::ShortestPath { path: path.clone() };
+        assert_eq!(witness.check(3, 3, &corrupted, 2), Ok("4".to_string()));
+
+        // Byte (1, 2) has already fallen
+        assert!(witness.check(3, 3, &corrupted, 3).is_err());
+
+        for path in [
+            vec![(0, 0), (0, 1), (0, 2), (2, 2)],
+            vec![(0, 1), (0, 2), (1, 2), (2, 2)],
+            vec![(0, 0), (0, 1), (0, 2), (1, 2)],
+        ] {
+            let witness = Witness::ShortestPath { path };
+            assert!(witness.check(3, 3, &corrupted, 2).is_err());
+        }
+    }
+
+    #[test]
+    fn test_check_first_blocking_byte() {
+        let corrupted = corrupted();
+        let path_before = vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)];
+
+        let witness = Witness::FirstBlockingByte {
+            index: 2,
+            byte: (1, 2),
+            path_before: path_before.clone(),
+        };
+        assert_eq!(witness.check(3, 3, &corrupted, 0), Ok("1,2".to_string()));
+
+        // Exit is still reachable after byte (1, 1)
+        let witness = Witness::FirstBlockingByte {
+            index: 1,
+            byte: (1, 1),
+            path_before: path_before.clone(),
+        };
+        assert!(witness.check(3, 3, &corrupted, 0).is_err());
+
+        // Byte does not match the input
+        let witness = Witness::FirstBlockingByte {
+            index: 2,
+            byte: (2, 1),
+            path_before,
+        };
+        assert!(witness.check(3, 3, &corrupted, 0).is_err());
+    }
+}
@@ -1,19 +1,34 @@
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use aoc_common::parse_error::ParseError;
 
 use super::designer::Designer;
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<Designer, PuzzleError> {
+    pub fn parse_lines(lines: &[&str]) -> Result<Designer, ParseError> {
         // Check file length
         if lines.len() < 3 {
-            return Err(PuzzleError::InvalidContentError(format!(
+            return Err(ParseError::new(format!(
                 "Input file should contain at least 3 lines [{} found]",
                 lines.len()
             )));
         }
 
+        // Towels and designs consist of stripe colors only
+        Self::check_colors(lines[0], ", ").map_err(|err| err.with_line(0))?;
+
+        if !lines[1].is_empty() {
+            return Err(
+                ParseError::new("Expected an empty line after towel patterns")
+                    .with_whole_line(lines[1])
+                    .with_line(1),
+            );
+        }
+
+        for (index, line) in lines.iter().enumerate().skip(2) {
+            Self::check_colors(line, "").map_err(|err| err.with_line(index))?;
+        }
+
         // First line contains towel patterns
         let towel_patterns = lines[0]
             .split(",")
@@ -25,4 +40,32 @@ impl Parser {
 
         Ok(Designer::new(towel_patterns, designs))
     }
+
+    fn check_colors(line: &str, separators: &str) -> Result<(), ParseError> {
+        const COLORS: &str = "wubrg";
+
+        match line
+            .char_indices()
+            .find(|(_, c)| !COLORS.contains(*c) && !separators.contains(*c))
+        {
+            Some((position, c)) => Err(ParseError::new(format!("Invalid stripe color '{}'", c))
+                .with_span(line, position..position + c.len_utf8())),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_colors() {
+        assert!(Parser::check_colors("r, wr, b", ", ").is_ok());
+        assert!(Parser::check_colors("brwrr", "").is_ok());
+
+        let result = Parser::check_colors("brwxr", "");
+        assert!(result.is_err(), "result: {:?}", result);
+        assert_eq!(result.unwrap_err().get_columns(), Some(&(4..5)));
+    }
 }
@@ -0,0 +1,6 @@
+pub mod puzzle;
+
+pub use advent_of_code::puzzles::puzzle_solver::PuzzleSolver;
+pub use puzzle::{cheat::Cheat, parser::Parser, path::Path, race::Race, solver::Solver};
+
+aoc_common::export_day!(20, Solver, configurable, parsable, explainable, renderable);
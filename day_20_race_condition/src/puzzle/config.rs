@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+/// Puzzle constants, defaults match the real input
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Picoseconds a cheat has to save at least to be counted
+    pub min_saving: usize,
+    /// Longest cheat in picoseconds in part 1
+    pub cheat_part_1: usize,
+    /// Longest cheat in picoseconds in part 2
+    pub cheat_part_2: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            min_saving: 100,
+            cheat_part_1: 2,
+            cheat_part_2: 20,
+        }
+    }
+}
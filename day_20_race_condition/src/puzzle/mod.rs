@@ -1,5 +1,7 @@
 pub mod cheat;
+pub mod config;
 pub mod parser;
 pub mod path;
 pub mod race;
 pub mod solver;
+pub mod witness;
@@ -1,12 +1,16 @@
-use advent_of_code::{grids::grid::Grid, puzzles::puzzle_error::PuzzleError};
+use advent_of_code::grids::grid::Grid;
+use aoc_common::{grid_lines, parse_error::ParseError};
 
 use super::race::Race;
 
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<Race, PuzzleError> {
-        let grid = Grid::new_from_lines(lines)?;
+    pub fn parse_lines(lines: &[&str]) -> Result<Race, ParseError> {
+        grid_lines::check_rectangular(lines)
+            .and_then(|_| grid_lines::check_characters(lines, |c| "#.SE".contains(c)))?;
+
+        let grid = Grid::new_from_lines(lines).map_err(|err| ParseError::new(err.to_string()))?;
         Ok(Race::new(grid))
     }
 }
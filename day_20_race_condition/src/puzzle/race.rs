@@ -5,6 +5,8 @@ use advent_of_code::{
     puzzles::puzzle_error::PuzzleError,
 };
 
+use aoc_common::render::frame::Frame;
+
 use super::{cheat::Cheat, path::Path};
 
 #[derive(Default)]
@@ -17,6 +19,10 @@ impl Race {
         Self { grid }
     }
 
+    pub fn get_grid(&self) -> &Grid {
+        &self.grid
+    }
+
     pub fn count_cheats(
         &self,
         picoseconds: usize,
@@ -43,6 +49,38 @@ impl Race {
         Ok(count)
     }
 
+    /// Cheats which save at least `picoseconds` together with the saved picoseconds
+    pub fn find_cheats(
+        &self,
+        picoseconds: usize,
+        cheat_steps: isize,
+    ) -> Result<Vec<(Cheat, isize)>, PuzzleError> {
+        let (start, end) = self.get_start_end()?;
+        let path = self.get_path(&start, &end)?;
+
+        let cheats = self.collect_cheats(&path, cheat_steps)?;
+
+        Ok(cheats
+            .into_iter()
+            .filter(|(saved, _)| *saved >= picoseconds as isize)
+            .flat_map(|(saved, cheats)| cheats.into_iter().map(move |cheat| (cheat, saved)))
+            .collect())
+    }
+
+    /// Points of the race track in the order from the start to the end
+    pub fn find_track(&self) -> Result<Vec<Point>, PuzzleError> {
+        let (start, end) = self.get_start_end()?;
+        let path = self.get_path(&start, &end)?;
+
+        Ok(path.iter().map(|(point, _)| *point).collect())
+    }
+
+    pub fn to_frame(&self, title: &str) -> Frame {
+        Frame::new(title, self.grid.rows(), self.grid.cols(), |x, y| {
+            self.grid[Point { x, y }]
+        })
+    }
+
     fn get_path(&self, start: &Point, end: &Point) -> Result<Path, PuzzleError> {
         let mut path = Path::new();
         let mut visited = HashSet::new(); // Do not return back in the path
@@ -278,4 +316,33 @@ mod tests {
         assert_eq!(cheats.get(&74).unwrap().len(), 4);
         assert_eq!(cheats.get(&76).unwrap().len(), 3);
     }
+
+    #[test]
+    fn test_find_track() {
+        let race = build_race();
+
+        let result = race.find_track();
+        assert!(result.is_ok(), "result: {:?}", result);
+
+        let track = result.unwrap();
+        assert_eq!(track.len(), 85);
+        assert_eq!(track.first(), Some(&Point { x: 1, y: 3 }));
+        assert_eq!(track.last(), Some(&Point { x: 5, y: 7 }));
+    }
+
+    #[test]
+    fn test_find_cheats() {
+        let race = build_race();
+
+        let result = race.find_cheats(64, 2);
+        assert!(result.is_ok(), "result: {:?}", result);
+
+        let cheats = result.unwrap();
+        assert_eq!(cheats.len(), 1);
+        assert_eq!(cheats[0].1, 64);
+
+        let result = race.find_cheats(76, 20);
+        assert!(result.is_ok(), "result: {:?}", result);
+        assert_eq!(result.unwrap().len(), 3);
+    }
 }
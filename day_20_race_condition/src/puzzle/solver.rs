@@ -1,17 +1,133 @@
+use std::cmp::Reverse;
+
 use advent_of_code::puzzles::{
     puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
     puzzle_solver::PuzzleSolver,
 };
+use aoc_common::{
+    configurable::Configurable,
+    explainable::Explainable,
+    parsable::Parsable,
+    parse_error::ParseError,
+    part::Part,
+    render::{frame::Frame, layer::Layer},
+    renderable::Renderable,
+};
 
-use super::{parser::Parser, race::Race};
+use super::{
+    config::Config,
+    parser::Parser,
+    race::Race,
+    witness::{CheatWitness, Witness},
+};
 
 pub struct Solver {
+    config: Config,
     race: Race,
 }
 
+impl Configurable for Solver {
+    type Config = Config;
+
+    fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+}
+
+impl Solver {
+    fn get_cheat_steps(&self, part: Part) -> usize {
+        match part {
+            Part::Part1 => self.config.cheat_part_1,
+            Part::Part2 => self.config.cheat_part_2,
+        }
+    }
+}
+
+impl Explainable for Solver {
+    type Witness = Witness;
+    type Error = PuzzleError;
+
+    fn witness(&self, part: Part) -> Result<Witness, PuzzleError> {
+        let mut cheats = self
+            .race
+            .find_cheats(self.config.min_saving, self.get_cheat_steps(part) as isize)?
+            .into_iter()
+            .map(|(cheat, saving)| CheatWitness {
+                start: (cheat.start.x, cheat.start.y),
+                end: (cheat.end.x, cheat.end.y),
+                saving: saving as usize,
+            })
+            .collect::<Vec<_>>();
+
+        // Cheats are collected from a map, sort them to get a stable witness
+        cheats.sort_by_key(|cheat| (cheat.start.1, cheat.start.0, cheat.end.1, cheat.end.0));
+
+        Ok(Witness { cheats })
+    }
+
+    fn check_witness(&self, part: Part, witness: &Witness) -> Result<String, PuzzleError> {
+        witness
+            .check(
+                self.race.get_grid(),
+                self.config.min_saving,
+                self.get_cheat_steps(part),
+            )
+            .map_err(PuzzleError::GenericError)
+    }
+}
+
+impl Renderable for Solver {
+    type Error = PuzzleError;
+
+    fn frames(&self, part: Part) -> Result<Vec<Frame>, PuzzleError> {
+        let cheats = self
+            .race
+            .find_cheats(self.config.min_saving, self.get_cheat_steps(part) as isize)?;
+
+        // Cheat with the biggest saving, ties are broken by positions to get a stable frame
+        let best = cheats.iter().max_by_key(|(cheat, saving)| {
+            let (start, end) = (cheat.start, cheat.end);
+            (*saving, Reverse((start.y, start.x, end.y, end.x)))
+        });
+
+        let Some((cheat, saving)) = best else {
+            let title = format!(
+                "No cheat saves at least {} picoseconds",
+                self.config.min_saving
+            );
+            return Ok(vec![self.race.to_frame(&title)]);
+        };
+
+        // Tiles of the track which are skipped by the cheat
+        let track = self.race.find_track()?;
+        let index = |point| track.iter().position(|p| *p == point).unwrap_or_default();
+        let skipped = track[index(cheat.start) + 1..index(cheat.end)].iter();
+
+        let mut frame = self
+            .race
+            .to_frame(&format!("Cheat saving {} picoseconds", saving));
+        frame.add_layer(Layer::Path, skipped.map(|point| (point.x, point.y)));
+        frame.add_layer(
+            Layer::Marker,
+            [(cheat.start.x, cheat.start.y), (cheat.end.x, cheat.end.y)],
+        );
+
+        Ok(vec![frame])
+    }
+}
+
+impl Parsable for Solver {
+    fn parse_lines(&mut self, lines: &[&str]) -> Result<(), ParseError> {
+        self.race = Parser::parse_lines(lines)?;
+        Ok(())
+    }
+}
+
 impl PuzzleSolver for Solver {
     fn new() -> Self {
         Self {
+            config: Config::default(),
             race: Race::default(),
         }
     }
@@ -21,17 +137,21 @@ impl PuzzleSolver for Solver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        self.race = Parser::parse_lines(lines)?;
-        Ok(())
+        self.parse_lines(lines)
+            .map_err(|err| PuzzleError::InvalidContentError(err.to_string()))
     }
 
     fn part_1(&self) -> SolutionResult {
-        let count = self.race.count_cheats(100, 2)?;
+        let count = self
+            .race
+            .count_cheats(self.config.min_saving, self.config.cheat_part_1 as isize)?;
         Ok(count.to_string())
     }
 
     fn part_2(&self) -> SolutionResult {
-        let count = self.race.count_cheats(100, 20)?;
+        let count = self
+            .race
+            .count_cheats(self.config.min_saving, self.config.cheat_part_2 as isize)?;
         Ok(count.to_string())
     }
 }
@@ -42,6 +162,7 @@ mod tests {
     use std::sync::LazyLock;
 
     use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
+    use aoc_common::example_tester::ExampleTester;
 
     use super::*;
 
@@ -64,4 +185,14 @@ mod tests {
     fn test_part_2() {
         get_tester().test_part_2();
     }
+
+    #[test]
+    fn test_examples() {
+        ExampleTester::new(crate::day()).test_examples();
+    }
+
+    #[test]
+    fn test_explain_examples() {
+        ExampleTester::new(crate::day()).test_explain();
+    }
 }
@@ -0,0 +1,133 @@
+use std::collections::{HashMap, HashSet};
+
+use advent_of_code::grids::{grid::Grid, point::Point};
+use serde::Serialize;
+
+/// Cheat which saves at least the minimal picoseconds, coordinates are `(x, y)`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CheatWitness {
+    pub start: (isize, isize),
+    pub end: (isize, isize),
+    pub saving: usize,
+}
+
+/// Evidence of an answer, all cheats which save at least the minimal picoseconds
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Witness {
+    pub cheats: Vec<CheatWitness>,
+}
+
+impl Witness {
+    /// Compute the count of cheats from the witness, only the race track is trusted
+    pub fn check(
+        &self,
+        grid: &Grid,
+        min_saving: usize,
+        cheat_steps: usize,
+    ) -> Result<String, String> {
+        let track = walk_track(grid)?;
+        let mut seen = HashSet::new();
+
+        for cheat in &self.cheats {
+            let (Some(start), Some(end)) = (track.get(&cheat.start), track.get(&cheat.end)) else {
+                return Err(format!(
+                    "Cheat {:?} does not start and end on the track",
+                    cheat
+                ));
+            };
+
+            let distance =
+                cheat.start.0.abs_diff(cheat.end.0) + cheat.start.1.abs_diff(cheat.end.1);
+            if distance > cheat_steps {
+                return Err(format!("Cheat {:?} is longer than {}", cheat, cheat_steps));
+            }
+
+            if *end < start + distance || end - start - distance != cheat.saving {
+                return Err(format!("Cheat {:?} does not save the picoseconds", cheat));
+            }
+
+            if cheat.saving < min_saving {
+                return Err(format!("Cheat {:?} saves less than {}", cheat, min_saving));
+            }
+
+            if !seen.insert((cheat.start, cheat.end)) {
+                return Err(format!("Cheat {:?} is repeated", cheat));
+            }
+        }
+
+        Ok(self.cheats.len().to_string())
+    }
+}
+
+// Picoseconds from the start to every tile of the single track
+fn walk_track(grid: &Grid) -> Result<HashMap<(isize, isize), usize>, String> {
+    let find = |value: char| match grid.get_value(value).as_slice() {
+        [point] => Ok((point.x, point.y)),
+        _ => Err(format!("Exactly one '{}' expected on the track", value)),
+    };
+
+    let (start, end) = (find('S')?, find('E')?);
+    let mut track = HashMap::from([(start, 0)]);
+    let mut current = start;
+
+    while current != end {
+        let (x, y) = current;
+        let next = [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| {
+                let point = Point { x, y };
+                grid.is_point_in_grid(&point) && grid[point] != '#' && !track.contains_key(&(x, y))
+            })
+            .collect::<Vec<_>>();
+
+        let [next] = next.as_slice() else {
+            return Err(format!("Track does not continue from {:?}", current));
+        };
+
+        track.insert(*next, track.len());
+        current = *next;
+    }
+
+    Ok(track)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_grid() -> Grid {
+        Grid::new_from_lines(&["#####", "#S#E#", "#.#.#", "#...#", "#####"])
+            .expect("Failed to create grid")
+    }
+
+    #[test]
+    fn test_check_cheats() {
+        let grid = build_grid();
+        let cheat = |start, end, saving| CheatWitness { start, end, saving };
+
+        let witness = Witness {
+            cheats: vec![cheat((1, 1), (3, 1), 4), cheat((1, 2), (3, 2), 2)],
+        };
+        assert_eq!(witness.check(&grid, 2, 2), Ok("2".to_string()));
+
+        for cheats in [
+            // Saving is lower than the minimum
+            vec![cheat((1, 2), (3, 2), 2)],
+            // Saving does not match the track
+            vec![cheat((1, 1), (3, 1), 6)],
+            // End is not on the track
+            vec![cheat((1, 1), (2, 1), 4)],
+            // Cheat is too long
+            vec![cheat((1, 1), (3, 2), 3)],
+            // Cheat is repeated
+            vec![cheat((1, 1), (3, 1), 4), cheat((1, 1), (3, 1), 4)],
+        ] {
+            let witness = Witness { cheats };
+            assert!(
+                witness.check(&grid, 3, 2).is_err(),
+                "witness: {:?}",
+                witness
+            );
+        }
+    }
+}